@@ -0,0 +1,574 @@
+//! The channel module provides the message queue shared by a `Signal<T>` and its `Slot<T>`.
+//!
+//! It mirrors the `std::sync::mpsc` API (multi-producer, single-consumer, blocking
+//! receive, disconnect detection) and reuses its error types, but the queue keeps
+//! two lanes:
+//!
+//! - a **priority lane**, filled by `Sender::send_priority`, which is always drained first
+//! - the regular **FIFO lane**, filled by `Sender::send`
+//!
+//! Messages within each lane keep their send order, so regular sends stay FIFO relative
+//! to each other while urgent messages (such as a shutdown command) jump the queue.
+//!
+//! A channel is either unbounded (`channel`) or holds at most a fixed number of
+//! queued messages across both lanes (`bounded`), in which case `send` blocks
+//! while the queue is full, like `std::sync::mpsc::sync_channel`. A `latest`
+//! channel holds at most one message and never blocks: each send replaces the
+//! message still waiting, so the receiver only ever sees the most recent one.
+//!
+//! A message sent with `Sender::send_ack` carries a completion token. A started
+//! `Slot` confirms it once its handler has returned for that message; taking the
+//! message directly from the `Receiver` confirms it on receipt.
+//!
+//! # Example
+//! ```rust
+//! use egui_mobius::channel;
+//!
+//! let (tx, rx) = channel::channel::<&str>();
+//! tx.send("routine").unwrap();
+//! tx.send_priority("urgent").unwrap();
+//!
+//! assert_eq!(rx.recv().unwrap(), "urgent");
+//! assert_eq!(rx.recv().unwrap(), "routine");
+//! ```
+
+use std::collections::VecDeque;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::mpsc::{RecvError, SendError, TryRecvError, TrySendError};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// Creates a new unbounded channel, returning the sender/receiver halves.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    with_capacity(None, false)
+}
+
+/// Creates a new channel holding at most `capacity` queued messages.
+///
+/// # Panics
+/// Panics if `capacity` is zero; rendezvous channels are not supported.
+pub fn bounded<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity > 0, "channel capacity must be at least 1");
+    with_capacity(Some(capacity), false)
+}
+
+/// Creates a new channel buffering only the most recent message.
+///
+/// Sends never block: a message still waiting when the next one arrives is
+/// discarded, whichever lane it was sent on.
+pub fn latest<T>() -> (Sender<T>, Receiver<T>) {
+    with_capacity(Some(1), true)
+}
+
+fn with_capacity<T>(capacity: Option<usize>, overwrite: bool) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            priority: VecDeque::new(),
+            queue: VecDeque::new(),
+            capacity,
+            overwrite,
+            senders: 1,
+            receiver_alive: true,
+        }),
+        available: Condvar::new(),
+        space: Condvar::new(),
+    });
+    (
+        Sender {
+            shared: Arc::clone(&shared),
+        },
+        Receiver { shared },
+    )
+}
+
+/// State shared by both halves of a channel.
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    /// Signalled whenever a message is queued or the last sender goes away.
+    available: Condvar,
+    /// Signalled whenever a message is taken or the receiver goes away.
+    space: Condvar,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        // The queue holds plain data, so a panic elsewhere cannot leave it inconsistent.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Completion token travelling with a message sent by `Sender::send_ack`.
+///
+/// With the `timed` feature it also records when the message was queued.
+pub(crate) struct Ack {
+    done: Option<oneshot::Sender<()>>,
+    #[cfg(all(feature = "timed", not(target_arch = "wasm32")))]
+    queued_at: Instant,
+}
+
+impl Ack {
+    fn new(done: Option<oneshot::Sender<()>>) -> Self {
+        Self {
+            done,
+            #[cfg(all(feature = "timed", not(target_arch = "wasm32")))]
+            queued_at: Instant::now(),
+        }
+    }
+
+    /// Reports the message as processed. Dropping the token instead makes the
+    /// waiting receiver fail.
+    pub(crate) fn confirm(self) {
+        if let Some(done) = self.done {
+            let _ = done.send(());
+        }
+    }
+
+    /// How long the message has been waiting since it was queued.
+    #[cfg(all(feature = "timed", not(target_arch = "wasm32")))]
+    pub(crate) fn waited(&self) -> Duration {
+        self.queued_at.elapsed()
+    }
+}
+
+struct State<T> {
+    priority: VecDeque<(T, Ack)>,
+    queue: VecDeque<(T, Ack)>,
+    capacity: Option<usize>,
+    /// Replace the waiting message instead of queueing behind it, see `latest`.
+    overwrite: bool,
+    senders: usize,
+    receiver_alive: bool,
+}
+
+impl<T> State<T> {
+    fn pop(&mut self) -> Option<(T, Ack)> {
+        self.priority.pop_front().or_else(|| self.queue.pop_front())
+    }
+
+    /// Number of further messages that fit, `usize::MAX` when unbounded or
+    /// overwriting.
+    fn room(&self) -> usize {
+        match self.capacity {
+            Some(capacity) if !self.overwrite => {
+                capacity.saturating_sub(self.priority.len() + self.queue.len())
+            }
+            _ => usize::MAX,
+        }
+    }
+
+    fn push(&mut self, msg: T, ack: Ack, priority: bool) {
+        if self.overwrite {
+            self.priority.clear();
+            self.queue.clear();
+        }
+        if priority {
+            self.priority.push_back((msg, ack));
+        } else {
+            self.queue.push_back((msg, ack));
+        }
+    }
+}
+
+/// The sending half of a channel, cloned freely across threads.
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Queue a message at the back of the FIFO lane, blocking while a bounded
+    /// channel is full.
+    pub fn send(&self, msg: T) -> Result<(), SendError<T>> {
+        self.push(msg, Ack::new(None), false)
+    }
+
+    /// Queue a message in the priority lane, ahead of every regular message.
+    pub fn send_priority(&self, msg: T) -> Result<(), SendError<T>> {
+        self.push(msg, Ack::new(None), true)
+    }
+
+    /// Queue a message like `send`, returning a receiver that resolves once the
+    /// message has been processed.
+    ///
+    /// The receiver fails instead if the message is never processed, e.g. because
+    /// the handler panicked or the message was discarded by a `latest` channel.
+    pub fn send_ack(&self, msg: T) -> Result<oneshot::Receiver<()>, SendError<T>> {
+        let (done, processed) = oneshot::channel();
+        self.push(msg, Ack::new(Some(done)), false)?;
+        Ok(processed)
+    }
+
+    /// Queue a message without blocking, failing if a bounded channel is full.
+    pub fn try_send(&self, msg: T) -> Result<(), TrySendError<T>> {
+        let mut state = self.shared.lock();
+        if !state.receiver_alive {
+            return Err(TrySendError::Disconnected(msg));
+        }
+        if state.room() == 0 {
+            return Err(TrySendError::Full(msg));
+        }
+        state.push(msg, Ack::new(None), false);
+        drop(state);
+        self.shared.available.notify_one();
+        Ok(())
+    }
+
+    /// Queue clones of all `msgs` atomically, or none of them.
+    ///
+    /// Never blocks. When a bounded channel cannot take the whole batch, nothing
+    /// is queued and `TrySendError::Full` carries the number of free slots.
+    pub fn try_send_slice(&self, msgs: &[T]) -> Result<(), TrySendError<usize>>
+    where
+        T: Clone,
+    {
+        let mut state = self.shared.lock();
+        if !state.receiver_alive {
+            return Err(TrySendError::Disconnected(0));
+        }
+        let room = state.room();
+        if room < msgs.len() {
+            return Err(TrySendError::Full(room));
+        }
+        if state.overwrite {
+            if let Some(last) = msgs.last() {
+                state.push(last.clone(), Ack::new(None), false);
+            }
+        } else {
+            state
+                .queue
+                .extend(msgs.iter().map(|msg| (msg.clone(), Ack::new(None))));
+        }
+        drop(state);
+        self.shared.available.notify_all();
+        Ok(())
+    }
+
+    /// Returns `true` while the receiving half still exists.
+    pub fn is_connected(&self) -> bool {
+        self.shared.lock().receiver_alive
+    }
+
+    /// Maximum number of queued messages, `None` when unbounded.
+    pub fn capacity(&self) -> Option<usize> {
+        self.shared.lock().capacity
+    }
+
+    /// Change the capacity of a bounded channel, returning `false` for unbounded and
+    /// `latest` channels, whose capacity is fixed.
+    ///
+    /// Messages already queued are kept even if they exceed a smaller capacity;
+    /// senders blocked on a full queue resume once the new capacity has room.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    pub fn resize(&self, capacity: usize) -> bool {
+        assert!(capacity > 0, "channel capacity must be at least 1");
+        let mut state = self.shared.lock();
+        if state.capacity.is_none() || state.overwrite {
+            return false;
+        }
+        state.capacity = Some(capacity);
+        drop(state);
+        self.shared.space.notify_all();
+        true
+    }
+
+    fn push(&self, msg: T, ack: Ack, priority: bool) -> Result<(), SendError<T>> {
+        let mut state = self.shared.lock();
+        loop {
+            if !state.receiver_alive {
+                return Err(SendError(msg));
+            }
+            if state.room() > 0 {
+                break;
+            }
+            state = self
+                .shared
+                .space
+                .wait(state)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+        state.push(msg, ack, priority);
+        drop(state);
+        self.shared.available.notify_one();
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            drop(state);
+            self.shared.available.notify_all();
+        }
+    }
+}
+
+/// Creates new senders for a channel without counting as one, so holding it does
+/// not keep a receiving loop waiting for messages.
+pub(crate) struct SenderSource<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> SenderSource<T> {
+    pub(crate) fn sender(&self) -> Sender<T> {
+        self.shared.lock().senders += 1;
+        Sender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+/// The receiving half of a channel.
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// A handle for connecting further senders to this channel later on.
+    pub(crate) fn sender_source(&self) -> SenderSource<T> {
+        SenderSource {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+
+    /// Block until a message is available, draining the priority lane first.
+    ///
+    /// Returns an error once the channel is empty and every sender has been dropped.
+    pub fn recv(&self) -> Result<T, RecvError> {
+        self.recv_acked().map(confirmed)
+    }
+
+    /// Like `recv`, leaving the message's completion token to the caller.
+    pub(crate) fn recv_acked(&self) -> Result<(T, Ack), RecvError> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(msg) = state.pop() {
+                drop(state);
+                self.shared.space.notify_one();
+                return Ok(msg);
+            }
+            if state.senders == 0 {
+                return Err(RecvError);
+            }
+            state = self
+                .shared
+                .available
+                .wait(state)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+    }
+
+    /// Block until a message is available or `timeout` has elapsed.
+    ///
+    /// Not available on `wasm32`, which has no clock to measure the timeout against.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        self.recv_timeout_acked(timeout).map(confirmed)
+    }
+
+    /// Like `recv_timeout`, leaving the message's completion token to the caller.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn recv_timeout_acked(
+        &self,
+        timeout: Duration,
+    ) -> Result<(T, Ack), RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(msg) = state.pop() {
+                drop(state);
+                self.shared.space.notify_one();
+                return Ok(msg);
+            }
+            if state.senders == 0 {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(RecvTimeoutError::Timeout);
+            }
+            state = self
+                .shared
+                .available
+                .wait_timeout(state, remaining)
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .0;
+        }
+    }
+
+    /// Take a pending message without blocking.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        self.try_recv_acked().map(confirmed)
+    }
+
+    /// Like `try_recv`, leaving the message's completion token to the caller.
+    pub(crate) fn try_recv_acked(&self) -> Result<(T, Ack), TryRecvError> {
+        let mut state = self.shared.lock();
+        match state.pop() {
+            Some(msg) => {
+                drop(state);
+                self.shared.space.notify_one();
+                Ok(msg)
+            }
+            None if state.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// Number of messages waiting, across both lanes.
+    pub fn len(&self) -> usize {
+        let state = self.shared.lock();
+        state.priority.len() + state.queue.len()
+    }
+
+    /// Returns `true` when no message is waiting.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Blocking iterator over incoming messages, ending when all senders are gone.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { receiver: self }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.lock().receiver_alive = false;
+        // Wake senders blocked on a full channel so they can fail
+        self.shared.space.notify_all();
+    }
+}
+
+/// Confirms a message taken without a slot handler, on receipt.
+fn confirmed<T>((msg, ack): (T, Ack)) -> T {
+    ack.confirm();
+    msg
+}
+
+/// Iterator returned by `Receiver::iter`.
+pub struct Iter<'a, T> {
+    receiver: &'a Receiver<T>,
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.recv().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn latest_channel_keeps_only_newest_message() {
+        let (tx, rx) = latest();
+        tx.send(1).unwrap();
+        tx.send_priority(2).unwrap();
+        tx.try_send(3).unwrap();
+        assert_eq!(rx.try_recv(), Ok(3));
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+
+        tx.try_send_slice(&[4, 5, 6]).unwrap();
+        assert_eq!(rx.recv(), Ok(6));
+        assert_eq!(tx.capacity(), Some(1));
+    }
+
+    #[test]
+    fn priority_lane_is_drained_first() {
+        let (tx, rx) = channel();
+        tx.send(1).unwrap();
+        tx.send_priority(10).unwrap();
+        tx.send(2).unwrap();
+        tx.send_priority(20).unwrap();
+
+        assert_eq!(rx.try_iter_all(), vec![10, 20, 1, 2]);
+    }
+
+    #[test]
+    fn disconnect_is_reported_on_both_ends() {
+        let (tx, rx) = channel::<u8>();
+        let tx2 = tx.clone();
+        drop(tx);
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+        drop(tx2);
+        assert_eq!(rx.recv(), Err(RecvError));
+
+        let (tx, rx) = channel();
+        drop(rx);
+        assert_eq!(tx.send(5), Err(SendError(5)));
+    }
+
+    #[test]
+    fn recv_blocks_until_a_message_arrives() {
+        let (tx, rx) = channel();
+        let handle = thread::spawn(move || rx.recv());
+        thread::sleep(std::time::Duration::from_millis(20));
+        tx.send_priority("wake").unwrap();
+        assert_eq!(handle.join().unwrap(), Ok("wake"));
+    }
+
+    #[test]
+    fn recv_timeout_gives_up_after_the_deadline() {
+        let (tx, rx) = channel();
+        let timeout = std::time::Duration::from_millis(20);
+        assert_eq!(rx.recv_timeout(timeout), Err(RecvTimeoutError::Timeout));
+        tx.send(1).unwrap();
+        assert_eq!(rx.recv_timeout(timeout), Ok(1));
+        drop(tx);
+        assert_eq!(
+            rx.recv_timeout(timeout),
+            Err(RecvTimeoutError::Disconnected)
+        );
+    }
+
+    #[test]
+    fn bounded_send_blocks_until_room() {
+        let (tx, rx) = bounded(2);
+        tx.send(1).unwrap();
+        tx.send_priority(2).unwrap();
+        assert_eq!(tx.try_send(3), Err(TrySendError::Full(3)));
+        assert_eq!(tx.capacity(), Some(2));
+
+        let blocked = thread::spawn(move || tx.send(3));
+        thread::sleep(std::time::Duration::from_millis(20));
+        assert_eq!(rx.recv(), Ok(2));
+        assert_eq!(blocked.join().unwrap(), Ok(()));
+        assert_eq!(rx.try_iter_all(), vec![1, 3]);
+    }
+
+    #[test]
+    fn try_send_slice_is_all_or_nothing() {
+        let (tx, rx) = bounded(3);
+        tx.send(0).unwrap();
+        assert_eq!(tx.try_send_slice(&[1, 2, 3]), Err(TrySendError::Full(2)));
+        assert_eq!(tx.try_send_slice(&[1, 2]), Ok(()));
+        assert_eq!(rx.try_iter_all(), vec![0, 1, 2]);
+    }
+
+    impl<T> Receiver<T> {
+        fn try_iter_all(&self) -> Vec<T> {
+            std::iter::from_fn(|| self.try_recv().ok()).collect()
+        }
+    }
+}
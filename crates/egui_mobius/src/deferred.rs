@@ -0,0 +1,182 @@
+//! Deferred signal sends, flushed once every lock has been released.
+//!
+//! Sending from inside a critical section (while a `Value` guard is held) can hand
+//! work to a slot whose handler immediately needs the same lock, inviting lock-order
+//! trouble. A `DeferredDispatch` collects such sends instead and performs them, in
+//! order, when `flush` is called after the guards are gone, typically at the end of
+//! the frame. `egui_mobius_widgets` wires this into egui's pass lifecycle.
+//!
+//! Flushing never blocks: a send whose slot queue is full stays queued for the
+//! next flush instead of waiting for room, so a backed-up slot cannot freeze the
+//! frame.
+//!
+//! # Example
+//! ```rust
+//! use egui_mobius::deferred::DeferredDispatch;
+//! use egui_mobius::factory::create_signal_slot;
+//! use egui_mobius::types::Value;
+//!
+//! let state = Value::new(0);
+//! let (signal, slot) = create_signal_slot::<i32>();
+//! let deferred = DeferredDispatch::new();
+//!
+//! {
+//!     let mut count = state.lock().unwrap();
+//!     *count += 1;
+//!     deferred.defer(&signal, *count); // queued, not sent under the lock
+//! }
+//! assert_eq!(deferred.flush(), 1);
+//! assert_eq!(slot.receiver.lock().unwrap().try_recv(), Ok(1));
+//! ```
+
+use crate::signals::Signal;
+use std::sync::{Arc, Mutex};
+
+/// A queued send, attempted by `flush`.
+type PendingSend = Box<dyn FnMut() -> Attempt + Send>;
+
+/// Outcome of one attempt at a queued send.
+enum Attempt {
+    Delivered,
+    /// The slot is gone; the message was reported like a failed `Signal::send`.
+    Failed,
+    /// The slot's queue is full; the send stays queued.
+    Full,
+}
+
+/// Collects signal sends and performs them later, in the order they were deferred.
+///
+/// Clones share the same queue, so a clone can be handed to code that defers
+/// while the owner flushes.
+#[derive(Clone, Default)]
+pub struct DeferredDispatch {
+    queue: Arc<Mutex<Vec<PendingSend>>>,
+}
+
+impl DeferredDispatch {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `msg` to be sent through `signal` on the next `flush`.
+    pub fn defer<T: Send + 'static>(&self, signal: &Signal<T>, msg: T) {
+        let signal = signal.clone();
+        let mut msg = Some(msg);
+        self.queue.lock().unwrap().push(Box::new(move || {
+            let Some(pending) = msg.take() else {
+                return Attempt::Failed;
+            };
+            match signal.try_send_or_keep(pending) {
+                Ok(()) => Attempt::Delivered,
+                Err(Some(kept)) => {
+                    msg = Some(kept);
+                    Attempt::Full
+                }
+                Err(None) => Attempt::Failed,
+            }
+        }));
+    }
+
+    /// Number of sends waiting for the next `flush`.
+    pub fn len(&self) -> usize {
+        self.queue.lock().unwrap().len()
+    }
+
+    /// Returns `true` if nothing is waiting to be sent.
+    pub fn is_empty(&self) -> bool {
+        self.queue.lock().unwrap().is_empty()
+    }
+
+    /// Perform the queued sends, in order, and return how many were delivered.
+    ///
+    /// Never blocks: the first send that finds its slot's queue full stops the
+    /// flush, and it stays queued together with every send deferred after it, so
+    /// the order is kept for the next flush. Sends to a slot that is gone are
+    /// reported like a failed `Signal::send` and dropped. The queue is released
+    /// before sending, so handlers may defer further sends; those wait for the
+    /// next flush.
+    pub fn flush(&self) -> usize {
+        let mut pending = std::mem::take(&mut *self.queue.lock().unwrap()).into_iter();
+        let mut delivered = 0;
+        while let Some(mut send) = pending.next() {
+            match send() {
+                Attempt::Delivered => delivered += 1,
+                Attempt::Failed => {}
+                Attempt::Full => {
+                    // Ahead of anything deferred while flushing
+                    let mut queue = self.queue.lock().unwrap();
+                    let deferred_meanwhile = std::mem::take(&mut *queue);
+                    queue.push(send);
+                    queue.extend(pending);
+                    queue.extend(deferred_meanwhile);
+                    break;
+                }
+            }
+        }
+        delivered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::{create_signal_slot, create_signal_slot_with_capacity};
+    use crate::types::Value;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_sends_deferred_under_lock_arrive_after_release() {
+        let state = Value::new(Vec::<u32>::new());
+        let deferred = DeferredDispatch::new();
+        let (signal, mut slot) = create_signal_slot::<u32>();
+
+        // The handler needs the same lock the sender holds while deferring
+        let (tx, rx) = mpsc::channel();
+        let handler_state = state.clone();
+        slot.start(move |n| {
+            handler_state.lock().unwrap().push(n);
+            tx.send(n).unwrap();
+        })
+        .unwrap();
+
+        {
+            let guard = state.lock().unwrap();
+            for n in 1..=3 {
+                deferred.defer(&signal, n + guard.len() as u32);
+            }
+            assert_eq!(deferred.len(), 3);
+            assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+        }
+
+        assert_eq!(deferred.flush(), 3);
+        assert!(deferred.is_empty());
+        let received: Vec<u32> = (0..3)
+            .map(|_| rx.recv_timeout(Duration::from_secs(1)).unwrap())
+            .collect();
+        assert_eq!(received, vec![1, 2, 3]);
+        assert_eq!(*state.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_flush_keeps_sends_that_do_not_fit() {
+        let (signal, slot) = create_signal_slot_with_capacity::<u32>(2);
+        let deferred = DeferredDispatch::new();
+        for n in 1..=3 {
+            deferred.defer(&signal, n);
+        }
+
+        // Nothing drains the slot, yet the flush returns
+        assert_eq!(deferred.flush(), 2);
+        assert_eq!(deferred.len(), 1);
+        assert_eq!(deferred.flush(), 0);
+
+        let receiver = slot.receiver.lock().unwrap();
+        assert_eq!(receiver.try_recv(), Ok(1));
+        assert_eq!(deferred.flush(), 1);
+        assert!(deferred.is_empty());
+        assert_eq!(receiver.try_recv(), Ok(2));
+        assert_eq!(receiver.try_recv(), Ok(3));
+    }
+}
@@ -27,13 +27,32 @@
 //! dispatcher.send("greet", Event::Text("hi from egui_mobius".into()));
 //! ```
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::MobiusError;
+#[cfg(not(target_arch = "wasm32"))]
 use crate::signals::Signal;
-use crate::slot::Slot;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::slot::{Slot, SlotWorker};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::trace;
 use crate::types::Value;
+#[cfg(not(target_arch = "wasm32"))]
+use futures::FutureExt;
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
 use std::future::Future;
+use std::hash::Hash;
+#[cfg(not(target_arch = "wasm32"))]
+use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
-use tokio::runtime::Runtime;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Mutex;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::runtime::{Handle, Runtime};
 
 /// Type alias for a handler function that can process events.
 type HandlerFn<E> = dyn Fn(E) + Send + Sync;
@@ -41,6 +60,17 @@ type HandlerFn<E> = dyn Fn(E) + Send + Sync;
 /// Type alias for a collection of event handlers.
 type HandlerMap<E> = HashMap<String, Vec<Arc<HandlerFn<E>>>>;
 
+/// Type alias for a collection of event handlers keyed by a typed channel.
+type TypedHandlerMap<E, C> = HashMap<C, Vec<Arc<HandlerFn<E>>>>;
+
+/// Type alias for an async handler registered with `AsyncDispatcher::register_async_slot`.
+#[cfg(not(target_arch = "wasm32"))]
+type AsyncHandlerFn<E> = dyn Fn(E) -> futures::future::BoxFuture<'static, ()> + Send + Sync;
+
+/// Type alias for a collection of async handlers keyed by channel name.
+#[cfg(not(target_arch = "wasm32"))]
+type AsyncHandlerMap<E> = HashMap<String, Vec<Arc<AsyncHandlerFn<E>>>>;
+
 /// The `SignalDispatcher` trait provides a generic interface
 /// for sending and receiving typed events across named channels.
 /// A trait representing a generic dispatcher capable of sending events to
@@ -138,6 +168,92 @@ impl<E: Clone + Send + 'static> SignalDispatcher<E> for Dispatcher<E> {
     }
 }
 
+/// A dispatcher whose channels are the variants of a user-defined type `C`
+/// instead of strings.
+///
+/// `TypedDispatcher` is the compile-time checked counterpart of `Dispatcher`:
+/// a misspelled channel is a compile error rather than a silently dropped event.
+///
+/// # Type Parameters
+/// - `E`: The event type this dispatcher works with.
+/// - `C`: The channel type, typically a field-less enum.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius::dispatching::TypedDispatcher;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// enum Channel {
+///     ExternalLog,
+///     Status,
+/// }
+///
+/// let dispatcher = TypedDispatcher::<String, Channel>::new();
+/// dispatcher.register_slot(Channel::ExternalLog, |line| println!("log: {line}"));
+/// dispatcher.send(Channel::ExternalLog, "started".to_string());
+/// dispatcher.send(Channel::Status, "ignored, no slot".to_string());
+/// ```
+///
+/// Sending on a channel of the wrong type does not compile:
+/// ```rust,compile_fail
+/// use egui_mobius::dispatching::TypedDispatcher;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// enum Channel { Log }
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// enum Other { Log }
+///
+/// let dispatcher = TypedDispatcher::<u32, Channel>::new();
+/// dispatcher.send(Other::Log, 1);
+/// ```
+pub struct TypedDispatcher<E, C> {
+    handlers: Value<TypedHandlerMap<E, C>>,
+}
+
+impl<E, C> Clone for TypedDispatcher<E, C> {
+    fn clone(&self) -> Self {
+        Self {
+            handlers: self.handlers.clone(),
+        }
+    }
+}
+
+impl<E: Clone + Send + 'static, C: Hash + Eq> Default for TypedDispatcher<E, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Clone + Send + 'static, C: Hash + Eq> TypedDispatcher<E, C> {
+    /// Create a new, empty `TypedDispatcher` instance.
+    pub fn new() -> Self {
+        Self {
+            handlers: Value::new(HashMap::new()),
+        }
+    }
+
+    /// Send an event to all handlers registered for the given channel.
+    ///
+    /// If no slots are registered on the channel, this is a no-op.
+    pub fn send(&self, channel: C, event: E) {
+        // Clone the handler list so slots may register or send while running.
+        let slots = self.handlers.lock().unwrap().get(&channel).cloned();
+        for handler in slots.into_iter().flatten() {
+            handler(event.clone());
+        }
+    }
+
+    /// Register a slot (event handler) for a specific channel.
+    /// Multiple slots can be registered per channel.
+    pub fn register_slot<F>(&self, channel: C, f: F)
+    where
+        F: Fn(E) + Send + Sync + 'static,
+    {
+        let mut map = self.handlers.lock().unwrap();
+        map.entry(channel).or_default().push(Arc::new(f));
+    }
+}
+
 /// An asynchronous dispatcher that processes events in a dedicated thread pool and
 /// supports non-blocking operations with proper error handling and timeouts.
 ///
@@ -147,6 +263,9 @@ impl<E: Clone + Send + 'static> SignalDispatcher<E> for Dispatcher<E> {
 /// - Parallel processing of computationally intensive tasks
 /// - Operations that require timeouts or cancellation
 ///
+/// Not available on `wasm32` targets, which have no thread pool; use
+/// `Slot::start_async` there instead.
+///
 /// # Type Parameters
 /// - `E`: The event type this dispatcher processes
 /// - `R`: The result type returned after processing
@@ -169,7 +288,7 @@ impl<E: Clone + Send + 'static> SignalDispatcher<E> for Dispatcher<E> {
 /// dispatcher.attach_async(slot, result_signal, |image_data| async move {
 ///     // Simulate image processing
 ///     format!("Processed {} bytes of image data", image_data.len())
-/// });
+/// }).unwrap();
 /// ```
 ///
 /// ## With Timeouts and Error Handling
@@ -198,7 +317,7 @@ impl<E: Clone + Send + 'static> SignalDispatcher<E> for Dispatcher<E> {
 ///         Ok(result) => result,
 ///         Err(_) => Err(ProcessError::Timeout),
 ///     }
-/// });
+/// }).unwrap();
 /// ```
 ///
 /// ## Parallel Processing
@@ -216,29 +335,136 @@ impl<E: Clone + Send + 'static> SignalDispatcher<E> for Dispatcher<E> {
 ///         .map(|n| async move { n * n })
 ///         .collect();
 ///     join_all(tasks).await
-/// });
+/// }).unwrap();
 /// ```
+#[cfg(not(target_arch = "wasm32"))]
 pub struct AsyncDispatcher<E, R> {
-    runtime: Arc<Runtime>,
+    handle: Handle,
+    /// Keeps an owned or shared runtime alive; `None` when built from a bare `Handle`.
+    _runtime: Option<Arc<Runtime>>,
+    /// Async slots registered on named channels, see `register_async_slot`.
+    channels: Value<AsyncHandlerMap<E>>,
     _phantom: std::marker::PhantomData<(E, R)>,
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<E: Send + 'static, R: Send + 'static> Default for AsyncDispatcher<E, R> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<E: Send + 'static, R: Send + 'static> AsyncDispatcher<E, R> {
     /// Creates a new `AsyncDispatcher` with its own Tokio runtime.
+    ///
+    /// Must not be called from within a Tokio runtime; use `with_handle` there.
     pub fn new() -> Self {
         let runtime = Runtime::new().expect("Failed to build Tokio runtime");
+        Self::with_runtime(Arc::new(runtime))
+    }
+
+    /// Creates an `AsyncDispatcher` running its handlers on a runtime the
+    /// application already owns, instead of starting a second one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius::dispatching::AsyncDispatcher;
+    /// use std::sync::Arc;
+    /// use tokio::runtime::Runtime;
+    ///
+    /// let runtime = Arc::new(Runtime::new().unwrap());
+    /// let dispatcher = AsyncDispatcher::<u32, u32>::with_runtime(runtime.clone());
+    /// ```
+    pub fn with_runtime(runtime: Arc<Runtime>) -> Self {
         Self {
-            runtime: Arc::new(runtime),
+            handle: runtime.handle().clone(),
+            _runtime: Some(runtime),
+            channels: Value::new(HashMap::new()),
             _phantom: std::marker::PhantomData,
         }
     }
 
+    /// Creates an `AsyncDispatcher` spawning its handlers through `handle`.
+    ///
+    /// This is the way to build a dispatcher from inside async code, where
+    /// creating another runtime would panic with "Cannot start a runtime from
+    /// within a runtime". The dispatcher does not keep the runtime alive.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius::dispatching::AsyncDispatcher;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let dispatcher = AsyncDispatcher::<u32, u32>::with_handle(tokio::runtime::Handle::current());
+    /// }
+    /// ```
+    pub fn with_handle(handle: Handle) -> Self {
+        Self {
+            handle,
+            _runtime: None,
+            channels: Value::new(HashMap::new()),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Register an async slot on the named `channel`, the async counterpart of
+    /// `SignalDispatcher::register_slot`.
+    ///
+    /// Every `send` on `channel` spawns `handler` with a clone of the event, and its
+    /// result is emitted on `signal`. Multiple slots can be registered per channel,
+    /// so one dispatcher can host several differently-named async processors.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius::dispatching::AsyncDispatcher;
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let dispatcher = AsyncDispatcher::<String, String>::new();
+    /// let (result_signal, result_slot) = create_signal_slot::<String>();
+    ///
+    /// dispatcher.register_async_slot("prices", result_signal.clone(), |coin| async move {
+    ///     format!("price of {coin}")
+    /// });
+    /// dispatcher.register_async_slot("news", result_signal, |topic| async move {
+    ///     format!("headlines about {topic}")
+    /// });
+    /// dispatcher.send("prices", "BTC".to_string());
+    /// ```
+    pub fn register_async_slot<F, Fut>(&self, channel: &str, signal: Signal<R>, handler: F)
+    where
+        F: Fn(E) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        let handler: Arc<AsyncHandlerFn<E>> = Arc::new(move |event| {
+            let fut = handler(event);
+            let signal = signal.clone();
+            async move {
+                let _ = signal.try_send(fut.await);
+            }
+            .boxed()
+        });
+        let mut map = self.channels.lock().unwrap();
+        map.entry(channel.to_string()).or_default().push(handler);
+    }
+
+    /// Spawn every async slot registered on `channel` with a clone of `event`.
+    ///
+    /// If no slots are registered on the channel, this is a no-op.
+    pub fn send(&self, channel: &str, event: E)
+    where
+        E: Clone,
+    {
+        // Clone the handler list so slots may register while others are spawned
+        let slots = self.channels.lock().unwrap().get(channel).cloned();
+        for handler in slots.into_iter().flatten() {
+            let span = trace::task_span::<E>(Some(channel));
+            self.handle
+                .spawn(trace::instrument(handler(event.clone()), span));
+        }
+    }
+
     /// Attaches an async handler to the given `Slot<E>`, processing events asynchronously
     /// and sending results via `Signal<R>`. The handler runs in a dedicated thread pool
     /// managed by Tokio, ensuring non-blocking operation.
@@ -252,11 +478,18 @@ impl<E: Send + 'static, R: Send + 'static> AsyncDispatcher<E, R> {
     /// * `F` - The handler function type that takes an event and returns a Future
     /// * `Fut` - The Future type returned by the handler
     ///
+    /// # Returns
+    /// The `SlotWorker` of `slot`, or `MobiusError::AlreadyStarted` when `slot` was
+    /// already started, by another `attach_async*` call or one of its `start` methods
+    ///
     /// # Notes
     /// - This can be called only once per Slot
     /// - The handler runs in a Tokio runtime with work-stealing scheduler
-    /// - Results are sent asynchronously through the signal
-    /// - If the signal send fails (e.g., no receivers), the error is silently ignored
+    /// - Results are sent asynchronously through the signal, with `Signal::try_send`
+    ///   so that a full result queue never blocks a runtime thread. A result that
+    ///   cannot be queued is dropped, or goes to the signal's dead-letter slot (see
+    ///   `Signal::with_dead_letter`); use `create_unbounded_signal_slot` for the
+    ///   results if none may be dropped
     ///
     /// # Example
     /// ```rust
@@ -276,16 +509,22 @@ impl<E: Send + 'static, R: Send + 'static> AsyncDispatcher<E, R> {
     ///
     /// dispatcher.attach_async(slot, result_signal, |input| async move {
     ///     process_data(input).await
-    /// });
+    /// }).unwrap();
     /// ```
-    pub fn attach_async<F, Fut>(&self, mut slot: Slot<E>, signal: Signal<R>, handler: F)
+    pub fn attach_async<F, Fut>(
+        &self,
+        mut slot: Slot<E>,
+        signal: Signal<R>,
+        handler: F,
+    ) -> Result<SlotWorker, MobiusError>
     where
         E: Clone + Send + 'static,
         R: Send + 'static,
         F: Fn(E) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = R> + Send + 'static,
     {
-        let runtime = self.runtime.clone();
+        let handle = self.handle.clone();
+        let name = slot.name().map(str::to_string);
         let handler = Arc::new(handler); // satisfy Fn(E) + Send + Sync
 
         slot.start({
@@ -293,12 +532,255 @@ impl<E: Send + 'static, R: Send + 'static> AsyncDispatcher<E, R> {
             move |event| {
                 let fut = handler(event);
                 let signal = signal.clone();
-                runtime.spawn(async move {
-                    let result = fut.await;
-                    let _ = signal.send(result);
-                });
+                let span = trace::task_span::<E>(name.as_deref());
+                handle.spawn(trace::instrument(
+                    async move {
+                        let result = fut.await;
+                        let _ = signal.try_send(result);
+                    },
+                    span,
+                ));
             }
-        });
+        })
+    }
+
+    /// Like `attach_async`, but results are emitted on `signal` in the same order
+    /// the events were received, even when later handlers finish first.
+    ///
+    /// Each event is tagged with a sequence number; finished results are held back
+    /// until every earlier result has been sent. A handler that panics is skipped
+    /// so it cannot stall the results behind it.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot that will receive events to process
+    /// * `signal` - The signal used to send processed results, in submission order
+    /// * `handler` - An async closure that processes events and returns results
+    ///
+    /// # Returns
+    /// The `SlotWorker` of `slot`, or `MobiusError::AlreadyStarted` if it was already started
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius::dispatching::AsyncDispatcher;
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let dispatcher = AsyncDispatcher::<String, String>::new();
+    /// let (signal, slot) = create_signal_slot::<String>();
+    /// let (result_signal, result_slot) = create_signal_slot::<String>();
+    ///
+    /// dispatcher.attach_async_ordered(slot, result_signal, |coin| async move {
+    ///     format!("price of {coin}")
+    /// }).unwrap();
+    /// ```
+    pub fn attach_async_ordered<F, Fut>(
+        &self,
+        mut slot: Slot<E>,
+        signal: Signal<R>,
+        handler: F,
+    ) -> Result<SlotWorker, MobiusError>
+    where
+        E: Clone + Send + 'static,
+        R: Send + 'static,
+        F: Fn(E) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        let handle = self.handle.clone();
+        let name = slot.name().map(str::to_string);
+        let reorder = Arc::new(Mutex::new(ReorderBuffer::default()));
+        let mut next_seq = 0u64;
+
+        slot.start(move |event| {
+            let seq = next_seq;
+            next_seq += 1;
+
+            let fut = handler(event);
+            let signal = signal.clone();
+            let reorder = reorder.clone();
+            let span = trace::task_span::<E>(name.as_deref());
+            handle.spawn(trace::instrument(
+                async move {
+                    let result = AssertUnwindSafe(fut).catch_unwind().await.ok();
+                    let mut guard = reorder.lock().unwrap_or_else(|e| e.into_inner());
+                    let buffer = &mut *guard;
+                    buffer.pending.insert(seq, result);
+                    // Emitting under the lock keeps the order strict across tasks;
+                    // `try_send` never blocks, so the lock is only held briefly
+                    while let Some(ready) = buffer.pending.remove(&buffer.next) {
+                        buffer.next += 1;
+                        if let Some(result) = ready {
+                            let _ = signal.try_send(result);
+                        }
+                    }
+                },
+                span,
+            ));
+        })
+    }
+
+    /// Like `attach_async`, but a handler that runs longer than `timeout` is cancelled
+    /// and its originating event is emitted on `timeout_signal` instead of a result.
+    ///
+    /// Cancellation drops the handler's future at its next `.await`, so work done
+    /// between await points is not interrupted.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot that will receive events to process
+    /// * `signal` - The signal used to send results of handlers that finish in time
+    /// * `timeout_signal` - The signal that receives the events whose handler timed out
+    /// * `timeout` - How long each handler may run
+    /// * `handler` - An async closure that processes events and returns results
+    ///
+    /// # Returns
+    /// The `SlotWorker` of `slot`, or `MobiusError::AlreadyStarted` if it was already started
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius::dispatching::AsyncDispatcher;
+    /// use egui_mobius::factory::create_signal_slot;
+    /// use std::time::Duration;
+    ///
+    /// let dispatcher = AsyncDispatcher::<String, f64>::new();
+    /// let (signal, slot) = create_signal_slot::<String>();
+    /// let (result_signal, result_slot) = create_signal_slot::<f64>();
+    /// let (timeout_signal, timeout_slot) = create_signal_slot::<String>();
+    ///
+    /// dispatcher.attach_async_timeout(
+    ///     slot,
+    ///     result_signal,
+    ///     timeout_signal,
+    ///     Duration::from_secs(5),
+    ///     |coin| async move { if coin == "BTC" { 65_000.0 } else { 1.0 } },
+    /// ).unwrap();
+    /// ```
+    pub fn attach_async_timeout<F, Fut>(
+        &self,
+        mut slot: Slot<E>,
+        signal: Signal<R>,
+        timeout_signal: Signal<E>,
+        timeout: Duration,
+        handler: F,
+    ) -> Result<SlotWorker, MobiusError>
+    where
+        E: Clone + Send + 'static,
+        R: Send + 'static,
+        F: Fn(E) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+    {
+        let handle = self.handle.clone();
+        let name = slot.name().map(str::to_string);
+
+        slot.start(move |event: E| {
+            let fut = handler(event.clone());
+            let signal = signal.clone();
+            let timeout_signal = timeout_signal.clone();
+            let span = trace::task_span::<E>(name.as_deref());
+            handle.spawn(trace::instrument(
+                async move {
+                    match tokio::time::timeout(timeout, fut).await {
+                        Ok(result) => {
+                            let _ = signal.try_send(result);
+                        }
+                        Err(_) => {
+                            let _ = timeout_signal.try_send(event);
+                        }
+                    }
+                },
+                span,
+            ));
+        })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<E: Send + 'static, T: Send + 'static, Er: Send + 'static> AsyncDispatcher<E, Result<T, Er>> {
+    /// Attaches a fallible async handler that is retried with exponential backoff.
+    ///
+    /// When the handler returns `Err`, it is called again with a clone of the same
+    /// event after `base_delay`, then `2 * base_delay`, `4 * base_delay` and so on,
+    /// up to `max_retries` extra attempts. Exactly one result is emitted per event:
+    /// the first success, or the last error once the retries are exhausted.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot that will receive events to process
+    /// * `signal` - The signal used to send the final result of each event
+    /// * `max_retries` - Number of retries after the first failed attempt
+    /// * `base_delay` - Delay before the first retry, doubled for each subsequent one
+    /// * `handler` - An async closure returning `Result<T, Er>`
+    ///
+    /// # Returns
+    /// The `SlotWorker` of `slot`, or `MobiusError::AlreadyStarted` if it was already started
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius::dispatching::AsyncDispatcher;
+    /// use egui_mobius::factory::create_signal_slot;
+    /// use std::time::Duration;
+    ///
+    /// let dispatcher = AsyncDispatcher::<String, Result<f64, String>>::new();
+    /// let (signal, slot) = create_signal_slot::<String>();
+    /// let (result_signal, result_slot) = create_signal_slot::<Result<f64, String>>();
+    ///
+    /// dispatcher.attach_async_retry(slot, result_signal, 3, Duration::from_millis(250), |pair| async move {
+    ///     if pair.is_empty() { Err("no pair".to_string()) } else { Ok(42.0) }
+    /// }).unwrap();
+    /// ```
+    pub fn attach_async_retry<F, Fut>(
+        &self,
+        mut slot: Slot<E>,
+        signal: Signal<Result<T, Er>>,
+        max_retries: u32,
+        base_delay: Duration,
+        handler: F,
+    ) -> Result<SlotWorker, MobiusError>
+    where
+        E: Clone,
+        F: Fn(E) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, Er>> + Send + 'static,
+    {
+        let handle = self.handle.clone();
+        let name = slot.name().map(str::to_string);
+        let handler = Arc::new(handler);
+
+        slot.start(move |event| {
+            let handler = handler.clone();
+            let signal = signal.clone();
+            let span = trace::task_span::<E>(name.as_deref());
+            handle.spawn(trace::instrument(
+                async move {
+                    let mut attempt = 0;
+                    let result = loop {
+                        match handler(event.clone()).await {
+                            Err(_) if attempt < max_retries => {
+                                tokio::time::sleep(base_delay.saturating_mul(1 << attempt.min(31)))
+                                    .await;
+                                attempt += 1;
+                            }
+                            result => break result,
+                        }
+                    };
+                    let _ = signal.try_send(result);
+                },
+                span,
+            ));
+        })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Results waiting for earlier sequence numbers, used by `attach_async_ordered`.
+/// A `None` entry marks a handler that panicked.
+struct ReorderBuffer<R> {
+    next: u64,
+    pending: BTreeMap<u64, Option<R>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R> Default for ReorderBuffer<R> {
+    fn default() -> Self {
+        Self {
+            next: 0,
+            pending: BTreeMap::new(),
+        }
     }
 }
 
@@ -390,6 +872,237 @@ mod tests {
         assert!(*beta_flag.lock().unwrap());
     }
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestChannel {
+        Alpha,
+        Beta,
+    }
+
+    #[test]
+    fn typed_dispatcher_routes_by_channel_variant() {
+        let dispatcher = TypedDispatcher::<TestEvent, TestChannel>::new();
+        let received = Arc::new(std::sync::Mutex::new(vec![]));
+
+        let received_clone = received.clone();
+        dispatcher.register_slot(TestChannel::Alpha, move |event| {
+            received_clone.lock().unwrap().push(event);
+        });
+
+        dispatcher.send(TestChannel::Alpha, TestEvent::Message("alpha".into()));
+        dispatcher.send(TestChannel::Beta, TestEvent::Ping);
+        dispatcher.clone().send(TestChannel::Alpha, TestEvent::Ping);
+
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![TestEvent::Message("alpha".into()), TestEvent::Ping]
+        );
+    }
+
+    #[test]
+    fn async_dispatcher_routes_named_channels_to_their_slots() {
+        let dispatcher = AsyncDispatcher::<u32, String>::new();
+        let (result_signal, result_slot) = crate::factory::create_signal_slot::<String>();
+
+        dispatcher.register_async_slot("double", result_signal.clone(), |n| async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            format!("double {}", n * 2)
+        });
+        dispatcher.register_async_slot("square", result_signal, |n| async move {
+            format!("square {}", n * n)
+        });
+
+        dispatcher.send("double", 3);
+        dispatcher.send("square", 3);
+        dispatcher.send("unknown", 3);
+
+        let results = result_slot.receiver.lock().unwrap();
+        let mut received: Vec<String> = (0..2)
+            .map(|_| results.recv_timeout(Duration::from_secs(1)).unwrap())
+            .collect();
+        received.sort();
+        assert_eq!(received, ["double 6", "square 9"]);
+        assert!(results.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[test]
+    fn attach_async_ordered_preserves_submission_order() {
+        let dispatcher = AsyncDispatcher::<u64, u64>::new();
+        let (signal, slot) = crate::factory::create_signal_slot::<u64>();
+        let (result_signal, mut result_slot) = crate::factory::create_signal_slot::<u64>();
+
+        dispatcher
+            .attach_async_ordered(slot, result_signal, |id| async move {
+                // Earlier events sleep longer, so they finish last
+                tokio::time::sleep(std::time::Duration::from_millis(80 - id * 20)).await;
+                id
+            })
+            .unwrap();
+
+        let received = Arc::new(std::sync::Mutex::new(vec![]));
+        let received_clone = received.clone();
+        result_slot
+            .start(move |id| received_clone.lock().unwrap().push(id))
+            .unwrap();
+
+        for id in 0..4 {
+            signal.send(id).unwrap();
+        }
+        std::thread::sleep(std::time::Duration::from_millis(300));
+
+        assert_eq!(*received.lock().unwrap(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn attach_async_retry_backs_off_until_success() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::time::Instant;
+
+        let dispatcher = AsyncDispatcher::<u32, Result<u32, String>>::new();
+        let (signal, slot) = crate::factory::create_signal_slot::<u32>();
+        let (result_signal, mut result_slot) =
+            crate::factory::create_signal_slot::<Result<u32, String>>();
+
+        let attempts = Arc::new(AtomicU32::new(0));
+        let attempts_clone = attempts.clone();
+        dispatcher
+            .attach_async_retry(
+                slot,
+                result_signal,
+                3,
+                Duration::from_millis(20),
+                move |value| {
+                    let attempt = attempts_clone.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        if attempt < 2 {
+                            Err(format!("attempt {attempt} failed"))
+                        } else {
+                            Ok(value * 2)
+                        }
+                    }
+                },
+            )
+            .unwrap();
+
+        let received = Arc::new(std::sync::Mutex::new(vec![]));
+        let received_clone = received.clone();
+        result_slot
+            .start(move |result| {
+                received_clone
+                    .lock()
+                    .unwrap()
+                    .push((result, Instant::now()))
+            })
+            .unwrap();
+
+        let sent_at = Instant::now();
+        signal.send(21).unwrap();
+        std::thread::sleep(Duration::from_millis(300));
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].0, Ok(42));
+        // Two retries: 20ms + 40ms of backoff
+        assert!(received[0].1 - sent_at >= Duration::from_millis(60));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn attach_async_timeout_emits_slow_events_on_timeout_signal() {
+        let dispatcher = AsyncDispatcher::<u64, u64>::new();
+        let (signal, slot) = crate::factory::create_signal_slot::<u64>();
+        let (result_signal, result_slot) = crate::factory::create_signal_slot::<u64>();
+        let (timeout_signal, timeout_slot) = crate::factory::create_signal_slot::<u64>();
+
+        dispatcher
+            .attach_async_timeout(
+                slot,
+                result_signal,
+                timeout_signal,
+                Duration::from_millis(50),
+                |delay_ms| async move {
+                    tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                    delay_ms
+                },
+            )
+            .unwrap();
+
+        signal.send(500).unwrap();
+        signal.send(5).unwrap();
+
+        let results = result_slot.receiver.lock().unwrap();
+        let timeouts = timeout_slot.receiver.lock().unwrap();
+        std::thread::sleep(Duration::from_millis(150));
+        assert_eq!(results.try_recv(), Ok(5));
+        assert_eq!(timeouts.try_recv(), Ok(500));
+        // The cancelled handler never produces a result
+        std::thread::sleep(Duration::from_millis(500));
+        assert!(results.try_recv().is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn async_dispatcher_reuses_current_runtime() {
+        // `AsyncDispatcher::new` would panic here with a nested runtime
+        let dispatcher =
+            AsyncDispatcher::<u32, u32>::with_handle(tokio::runtime::Handle::current());
+        let (signal, slot) = crate::factory::create_signal_slot::<u32>();
+        let (result_signal, result_slot) = crate::factory::create_signal_slot::<u32>();
+        dispatcher
+            .attach_async(slot, result_signal, |value| async move { value + 1 })
+            .unwrap();
+
+        signal.send(41).unwrap();
+        let receiver = result_slot.receiver.clone();
+        let result = tokio::task::spawn_blocking(move || receiver.lock().unwrap().recv())
+            .await
+            .unwrap();
+        assert_eq!(result, Ok(42));
+    }
+
+    #[test]
+    fn attach_async_rejects_a_started_slot() {
+        let dispatcher = AsyncDispatcher::<u32, u32>::new();
+        let (result_signal, _result_slot) = crate::factory::create_signal_slot::<u32>();
+
+        let (_signal, mut slot) = crate::factory::create_signal_slot::<u32>();
+        let _worker = slot.start(|_| {}).unwrap();
+        let result = dispatcher.attach_async(slot, result_signal.clone(), |v| async move { v });
+        assert!(matches!(result, Err(MobiusError::AlreadyStarted(None))));
+
+        let (_signal, mut slot) = crate::factory::create_signal_slot::<u32>();
+        let _worker = slot.start(|_| {}).unwrap();
+        let result = dispatcher.attach_async_ordered(slot, result_signal, |v| async move { v });
+        assert!(matches!(result, Err(MobiusError::AlreadyStarted(None))));
+    }
+
+    #[test]
+    fn async_results_never_block_on_full_queue() {
+        // A single worker thread would be stuck in a blocking send
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+        let dispatcher = AsyncDispatcher::<u32, u32>::with_runtime(Arc::new(runtime));
+        let (_, dropped) = crate::factory::create_unbounded_signal_slot::<u32>();
+        let (signal, slot) = crate::factory::create_signal_slot::<u32>();
+        let (result_signal, result_slot) =
+            crate::factory::create_signal_slot_with_capacity::<u32>(1);
+        let result_signal = result_signal.with_dead_letter(&dropped);
+        dispatcher
+            .attach_async(slot, result_signal, |value| async move { value })
+            .unwrap();
+
+        for value in [1, 2, 3] {
+            signal.send(value).unwrap();
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        assert_eq!(result_slot.receiver.lock().unwrap().try_recv(), Ok(1));
+        let dropped = dropped.receiver.lock().unwrap();
+        let dropped: Vec<_> = std::iter::from_fn(|| dropped.try_recv().ok()).collect();
+        assert_eq!(dropped, vec![2, 3]);
+    }
+
     #[test]
     fn dispatcher_send_to_unregistered_channel_does_nothing() {
         let dispatcher = Dispatcher::<TestEvent>::new();
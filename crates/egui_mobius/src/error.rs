@@ -0,0 +1,137 @@
+//! The error type shared by the signal, slot and dispatcher APIs.
+//!
+//! Every fallible operation of the signal layer returns `MobiusError`, so
+//! application code can propagate failures with `?` without caring which part of
+//! the transport reported them.
+//!
+//! # Example
+//! ```rust
+//! use egui_mobius::MobiusError;
+//! use egui_mobius::factory::create_signal_slot;
+//!
+//! fn wire_up() -> Result<(), MobiusError> {
+//!     let (signal, mut slot) = create_signal_slot::<u32>();
+//!     slot.start(|n| println!("got {n}"))?;
+//!     signal.send(1)?;
+//!     Ok(())
+//! }
+//!
+//! wire_up().unwrap();
+//! ```
+
+use std::fmt;
+use std::sync::mpsc::{RecvError, RecvTimeoutError, SendError, TrySendError};
+
+/// Errors raised by signals, slots and dispatchers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MobiusError {
+    /// The other end of the channel is gone, e.g. the slot was dropped.
+    Disconnected,
+    /// A bounded channel had no room for the message(s).
+    Full,
+    /// A bounded channel had no room for a whole batch, so none of it was sent.
+    BatchFull {
+        /// Messages of the batch that were queued, always 0 for `Signal::send_slice`.
+        delivered: usize,
+        /// Size of the batch.
+        requested: usize,
+        /// Free places the channel had left.
+        room: usize,
+    },
+    /// Nothing arrived before the deadline.
+    Timeout,
+    /// The slot was already started; carries its name, if it has one.
+    AlreadyStarted(Option<String>),
+    /// The channel is unbounded or latest-only, so its capacity cannot change.
+    FixedCapacity,
+    /// `Slot::start_pool` was asked for a pool of zero workers.
+    NoWorkers,
+}
+
+impl fmt::Display for MobiusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MobiusError::Disconnected => write!(f, "the other end of the channel is disconnected"),
+            MobiusError::Full => write!(f, "the channel is full"),
+            MobiusError::BatchFull {
+                delivered,
+                requested,
+                room,
+            } => write!(
+                f,
+                "{delivered} of {requested} messages delivered, the channel has room for {room}"
+            ),
+            MobiusError::Timeout => write!(f, "timed out waiting on the channel"),
+            MobiusError::AlreadyStarted(Some(name)) => {
+                write!(f, "slot '{name}' has already been started")
+            }
+            MobiusError::AlreadyStarted(None) => write!(f, "slot has already been started"),
+            MobiusError::FixedCapacity => write!(f, "the channel's capacity cannot be changed"),
+            MobiusError::NoWorkers => write!(f, "a slot pool needs at least one worker"),
+        }
+    }
+}
+
+impl std::error::Error for MobiusError {}
+
+impl<T> From<SendError<T>> for MobiusError {
+    fn from(_: SendError<T>) -> Self {
+        MobiusError::Disconnected
+    }
+}
+
+impl<T> From<TrySendError<T>> for MobiusError {
+    fn from(e: TrySendError<T>) -> Self {
+        match e {
+            TrySendError::Full(_) => MobiusError::Full,
+            TrySendError::Disconnected(_) => MobiusError::Disconnected,
+        }
+    }
+}
+
+impl From<RecvError> for MobiusError {
+    fn from(_: RecvError) -> Self {
+        MobiusError::Disconnected
+    }
+}
+
+impl From<RecvTimeoutError> for MobiusError {
+    fn from(e: RecvTimeoutError) -> Self {
+        match e {
+            RecvTimeoutError::Timeout => MobiusError::Timeout,
+            RecvTimeoutError::Disconnected => MobiusError::Disconnected,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channel;
+    use std::time::Duration;
+
+    #[test]
+    fn test_channel_errors_map_to_variants() {
+        let (tx, rx) = channel::bounded::<u32>(1);
+        tx.send(1).unwrap();
+        assert_eq!(
+            MobiusError::from(tx.try_send(2).unwrap_err()),
+            MobiusError::Full
+        );
+        rx.recv().unwrap();
+        assert_eq!(
+            MobiusError::from(rx.recv_timeout(Duration::from_millis(1)).unwrap_err()),
+            MobiusError::Timeout
+        );
+
+        drop(rx);
+        assert_eq!(
+            MobiusError::from(tx.send(3).unwrap_err()),
+            MobiusError::Disconnected
+        );
+        assert_eq!(
+            MobiusError::AlreadyStarted(Some("ui".to_string())).to_string(),
+            "slot 'ui' has already been started"
+        );
+    }
+}
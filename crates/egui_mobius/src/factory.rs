@@ -15,17 +15,50 @@
 //! // Set up the receiving end
 //! slot.start(|value| {
 //!     println!("Received value: {}", value);
-//! });
+//! }).unwrap();
 //!
 //! // Send a value through the signal
 //! signal.send(42).unwrap();
 //! ```
+//!
+//! # Capacity and backpressure
+//!
+//! - `create_signal_slot` queues at most [`DEFAULT_CAPACITY`] messages. Once the
+//!   queue is full, `Signal::send` blocks until the slot catches up, so a fast
+//!   producer is slowed down instead of growing memory without limit.
+//! - `create_signal_slot_with_capacity` picks the bound explicitly. A small bound
+//!   keeps latency low for "latest value" streams; keep in mind that a send from
+//!   the UI thread into a full queue stalls the frame.
+//! - `create_unbounded_signal_slot` never blocks the sender, at the cost of an
+//!   unbounded queue when the slot falls behind.
+//! - `create_latest_only_signal_slot` never blocks either and buffers a single
+//!   message: while the handler is busy, each send replaces the pending one.
+//!
+//! The async dispatchers never block on a full queue: they emit results with
+//! `Signal::try_send`, which fails with `MobiusError::Full` instead of waiting.
+//!
+//! On `wasm32`, slots are drained by the same thread that sends, so a blocking
+//! send could never complete; `create_signal_slot` is unbounded there.
 
+use crate::channel::{self, Receiver, Sender};
+use crate::error::MobiusError;
+use crate::replay::ReplaySignal;
 use crate::signals::Signal;
-use crate::slot::Slot;
-use std::sync::mpsc::{self, Receiver, Sender};
+use crate::slot::{Slot, SlotWorker};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::timer::TimerHandle;
+use crate::topology::{Connection, TopologyRegistry};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
 
-/// Creates a new signal-slot pair.
+/// Queue bound used by `create_signal_slot`.
+pub const DEFAULT_CAPACITY: usize = 1024;
+
+/// Creates a new signal-slot pair with the default capacity.
+///
+/// At most [`DEFAULT_CAPACITY`] messages are queued; `Signal::send` blocks while
+/// the queue is full (see the module docs). On `wasm32` the queue is unbounded.
 ///
 /// This is a utility function that creates a new signal-slot pair for type-safe
 /// message passing between components. The signal can be used to send messages,
@@ -49,7 +82,7 @@ use std::sync::mpsc::{self, Receiver, Sender};
 /// // Set up a handler for the slot
 /// slot.start(|message| {
 ///     println!("Received: {}", message);
-/// });
+/// }).unwrap();
 ///
 /// // Send a message through the signal
 /// signal.send("Hello!".to_string()).unwrap();
@@ -58,8 +91,359 @@ pub fn create_signal_slot<T>() -> (Signal<T>, Slot<T>)
 where
     T: Send + Clone + 'static,
 {
-    let (tx, rx): (Sender<T>, Receiver<T>) = mpsc::channel();
-    let signal = Signal::new(tx);
-    let slot = Slot::new(rx);
-    (signal, slot)
+    #[cfg(not(target_arch = "wasm32"))]
+    let pair = create_signal_slot_with_capacity(DEFAULT_CAPACITY);
+    #[cfg(target_arch = "wasm32")]
+    let pair = create_unbounded_signal_slot();
+    pair
+}
+
+/// Creates a signal-slot pair like `create_signal_slot` and records it in
+/// `registry`.
+///
+/// The slot is named `name`, and the registry keeps `description` and the
+/// message type so the wiring can be exported with `registry.export_dot()`.
+/// There is no process-wide registry: pass the same one to every call whose
+/// pair should appear in the export.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius::factory::create_named_signal_slot;
+/// use egui_mobius::topology::TopologyRegistry;
+///
+/// let topology = TopologyRegistry::new();
+/// let (signal, mut slot) = create_named_signal_slot::<u32>(
+///     &topology,
+///     "counter",
+///     "Counter value from the backend to the UI",
+/// );
+/// slot.start(|count| println!("count is now {count}")).unwrap();
+/// signal.send(1).unwrap();
+/// ```
+pub fn create_named_signal_slot<T>(
+    registry: &TopologyRegistry,
+    name: &str,
+    description: &str,
+) -> (Signal<T>, Slot<T>)
+where
+    T: Send + Clone + 'static,
+{
+    registry.register(Connection {
+        name: name.to_string(),
+        description: description.to_string(),
+        message_type: std::any::type_name::<T>(),
+    });
+    let (signal, slot) = create_signal_slot();
+    (signal, slot.named(name))
+}
+
+/// Creates a new signal-slot pair queueing at most `capacity` messages.
+///
+/// `Signal::send` blocks while the queue is full; `Sender::try_send` on the
+/// underlying channel fails instead.
+///
+/// # Panics
+/// Panics if `capacity` is zero.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius::factory::create_signal_slot_with_capacity;
+///
+/// // Only the freshest few samples matter to the plot
+/// let (signal, mut slot) = create_signal_slot_with_capacity::<f64>(8);
+/// slot.start(|sample| println!("sample: {sample}")).unwrap();
+/// signal.send(0.5).unwrap();
+/// ```
+pub fn create_signal_slot_with_capacity<T>(capacity: usize) -> (Signal<T>, Slot<T>)
+where
+    T: Send + Clone + 'static,
+{
+    let (tx, rx): (Sender<T>, Receiver<T>) = channel::bounded(capacity);
+    (Signal::new(tx), Slot::new(rx))
+}
+
+/// Creates a new signal-slot pair whose queue grows without limit.
+///
+/// `Signal::send` never blocks, so use this when the sender must not stall and
+/// the slot is known to keep up on average.
+pub fn create_unbounded_signal_slot<T>() -> (Signal<T>, Slot<T>)
+where
+    T: Send + Clone + 'static,
+{
+    let (tx, rx): (Sender<T>, Receiver<T>) = channel::channel();
+    (Signal::new(tx), Slot::new(rx))
+}
+
+/// Creates a new signal-slot pair that keeps only the latest unhandled message.
+///
+/// At most one message is buffered and `Signal::send` never blocks: a message
+/// still waiting while the handler is busy is replaced by the next send. Use it
+/// for high-frequency state, like slider positions, where only the newest value
+/// matters and a backlog of stale ones would only delay it.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius::factory::create_latest_only_signal_slot;
+///
+/// let (signal, mut slot) = create_latest_only_signal_slot::<f32>();
+/// slot.start(|position| println!("slider at {position}")).unwrap();
+/// for step in 0..100 {
+///     signal.send(step as f32 / 100.0).unwrap();
+/// }
+/// ```
+pub fn create_latest_only_signal_slot<T>() -> (Signal<T>, Slot<T>)
+where
+    T: Send + Clone + 'static,
+{
+    let (tx, rx): (Sender<T>, Receiver<T>) = channel::latest();
+    (Signal::new(tx), Slot::new(rx))
+}
+
+/// Creates a signal-slot pair that carries large payloads behind an `Arc`.
+///
+/// Sending moves a pointer instead of the payload, and broadcasting one payload to
+/// several slots only clones the `Arc`, so every subscriber shares the same
+/// allocation. `T` itself does not need to be `Clone`. The queue uses the default
+/// capacity, like `create_signal_slot`.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius::factory::create_signal_slot_arc;
+/// use std::sync::Arc;
+///
+/// let (signal, mut slot) = create_signal_slot_arc::<Vec<u8>>();
+/// slot.start(|frame| println!("frame of {} bytes", frame.len())).unwrap();
+///
+/// let frame = Arc::new(vec![0u8; 4 * 1024 * 1024]);
+/// signal.send(Arc::clone(&frame)).unwrap();
+/// ```
+pub fn create_signal_slot_arc<T>() -> (Signal<Arc<T>>, Slot<Arc<T>>)
+where
+    T: Send + Sync + 'static,
+{
+    create_signal_slot()
+}
+
+/// Creates a broadcast signal that replays its last `buffer` messages to new subscribers.
+///
+/// Slots are created with `ReplaySignal::subscribe` and receive the recorded
+/// messages before any live ones. With a `buffer` of zero nothing is recorded and
+/// the signal is a plain broadcast.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius::factory::create_replay_signal;
+///
+/// let status = create_replay_signal::<&'static str>(10);
+/// status.send("booting");
+///
+/// let mut late = status.subscribe();
+/// late.start(|line| println!("{line}")).unwrap(); // prints "booting" first
+/// ```
+pub fn create_replay_signal<T>(buffer: usize) -> ReplaySignal<T>
+where
+    T: Send + Clone + 'static,
+{
+    ReplaySignal::new(buffer)
+}
+
+/// Creates a signal-slot pair driven by a timer that sends `Instant::now()` every `interval`.
+///
+/// The ticks come from a background thread owned by the returned `TimerHandle`,
+/// which can `stop` and `restart` them; dropping the handle ends the thread. The
+/// signal can still be used to send extra ticks, e.g. to refresh right away. Like
+/// `create_latest_only_signal_slot`, a slow handler only ever sees the latest
+/// tick instead of a backlog. Not available on `wasm32`, which has no threads.
+///
+/// # Panics
+/// If `interval` is zero.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius::factory::create_interval_signal;
+/// use std::time::Duration;
+///
+/// let (_signal, mut slot, timer) = create_interval_signal(Duration::from_secs(1));
+/// slot.start(|now| println!("tick at {now:?}")).unwrap();
+///
+/// // Pause the clock, e.g. while the window is hidden
+/// timer.stop();
+/// timer.restart();
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn create_interval_signal(interval: Duration) -> (Signal<Instant>, Slot<Instant>, TimerHandle) {
+    let (signal, slot) = create_latest_only_signal_slot();
+    let timer = TimerHandle::spawn(signal.clone(), interval);
+    (signal, slot, timer)
+}
+
+/// Both directions of a request/response flow, as created by `create_duplex`.
+///
+/// The fields are public so the pair can be split up and handed to the UI and
+/// the backend separately, typically by destructuring.
+pub struct Duplex<Req, Resp> {
+    /// Sends requests, usually from the UI.
+    pub request_signal: Signal<Req>,
+    /// Receives requests, usually in the backend.
+    pub request_slot: Slot<Req>,
+    /// Sends responses, usually from the backend.
+    pub response_signal: Signal<Resp>,
+    /// Receives responses, usually in the UI.
+    pub response_slot: Slot<Resp>,
+}
+
+impl<Req, Resp> Duplex<Req, Resp>
+where
+    Req: Send + Clone + 'static,
+    Resp: Send + Clone + 'static,
+{
+    /// Start the request slot with `handler`, sending every value it returns
+    /// back through the response signal.
+    ///
+    /// A response that cannot be delivered because the response slot has been
+    /// dropped goes to the dead-letter slot of `response_signal`, if one is set
+    /// (see `Signal::with_dead_letter`).
+    pub fn attach<F>(&mut self, mut handler: F) -> Result<SlotWorker, MobiusError>
+    where
+        F: FnMut(Req) -> Resp + Send + 'static,
+    {
+        let response_signal = self.response_signal.clone();
+        self.request_slot.start(move |request| {
+            let _ = response_signal.send(handler(request));
+        })
+    }
+}
+
+/// Creates the two signal-slot pairs of a request/response flow in one call.
+///
+/// # Type Parameters
+/// * `Req` - The type of requests
+/// * `Resp` - The type of responses
+///
+/// # Example
+/// ```rust
+/// use egui_mobius::factory::create_duplex;
+///
+/// let mut duplex = create_duplex::<u32, String>();
+/// duplex.attach(|n| format!("processed {n}")).unwrap();
+/// duplex.response_slot.start(|response| println!("{response}")).unwrap();
+///
+/// duplex.request_signal.send(7).unwrap();
+/// ```
+pub fn create_duplex<Req, Resp>() -> Duplex<Req, Resp>
+where
+    Req: Send + Clone + 'static,
+    Resp: Send + Clone + 'static,
+{
+    let (request_signal, request_slot) = create_signal_slot();
+    let (response_signal, response_slot) = create_signal_slot();
+    Duplex {
+        request_signal,
+        request_slot,
+        response_signal,
+        response_slot,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_signal_slot_capacities() {
+        let (signal, slot) = create_signal_slot::<u8>();
+        assert_eq!(signal.sender.capacity(), Some(DEFAULT_CAPACITY));
+        drop(slot);
+
+        let (signal, _slot) = create_signal_slot_with_capacity::<u8>(2);
+        assert_eq!(signal.sender.capacity(), Some(2));
+        signal.sender.try_send(1).unwrap();
+        signal.sender.try_send(2).unwrap();
+        assert!(signal.sender.try_send(3).is_err());
+
+        let (signal, _slot) = create_unbounded_signal_slot::<u8>();
+        assert_eq!(signal.sender.capacity(), None);
+        for n in 0..=u8::MAX {
+            signal.send(n).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_latest_only_delivers_final_value_after_flood() {
+        let (signal, mut slot) = create_latest_only_signal_slot::<u32>();
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (tx, rx) = mpsc::channel();
+        slot.start(move |n| {
+            if n == 0 {
+                // Keep the handler busy while the signal is flooded
+                started_tx.send(()).unwrap();
+                release_rx.recv().unwrap();
+            }
+            tx.send(n).unwrap();
+        })
+        .unwrap();
+
+        signal.send(0).unwrap();
+        started_rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        for n in 1..=100 {
+            signal.send(n).unwrap();
+        }
+        release_tx.send(()).unwrap();
+
+        let received: Vec<u32> = (0..2)
+            .map(|_| rx.recv_timeout(Duration::from_secs(1)).unwrap())
+            .collect();
+        assert_eq!(received, vec![0, 100]);
+        assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[test]
+    fn test_duplex_attach_round_trip() {
+        let mut duplex = create_duplex::<u32, u32>();
+        duplex.attach(|n| n * 2).unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        duplex
+            .response_slot
+            .start(move |response| tx.send(response).unwrap())
+            .unwrap();
+
+        for n in 1..=3 {
+            duplex.request_signal.send(n).unwrap();
+        }
+        let responses: Vec<u32> = (0..3)
+            .map(|_| rx.recv_timeout(Duration::from_secs(1)).unwrap())
+            .collect();
+        assert_eq!(responses, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_arc_broadcast_shares_one_allocation() {
+        // Not `Clone`, so the payload itself cannot be copied along the way
+        struct Frame(Vec<u8>);
+
+        let frame = Arc::new(Frame(vec![7; 1 << 20]));
+        let (tx, rx) = mpsc::channel();
+        let signals: Vec<_> = (0..4)
+            .map(|_| {
+                let (signal, mut slot) = create_signal_slot_arc::<Frame>();
+                let tx = tx.clone();
+                slot.start(move |frame| tx.send(frame).unwrap()).unwrap();
+                signal
+            })
+            .collect();
+
+        for signal in &signals {
+            signal.send(Arc::clone(&frame)).unwrap();
+        }
+        let received: Vec<Arc<Frame>> = (0..4)
+            .map(|_| rx.recv_timeout(Duration::from_secs(1)).unwrap())
+            .collect();
+        assert!(received.iter().all(|r| Arc::ptr_eq(r, &frame)));
+        assert_eq!(received[0].0.len(), 1 << 20);
+        assert_eq!(Arc::strong_count(&frame), 5);
+    }
 }
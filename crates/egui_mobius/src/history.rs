@@ -0,0 +1,144 @@
+//! Fixed-capacity sample history for plots and logs.
+//!
+//! A `HistoryBuffer<T>` keeps the most recent `capacity` values pushed into it,
+//! dropping the oldest one once it is full. This is the FIFO a real-time plot needs
+//! for its trace, and `export_csv` writes the current contents out for offline
+//! analysis.
+//!
+//! # Example
+//! ```rust
+//! use egui_mobius::history::HistoryBuffer;
+//!
+//! let mut temperatures = HistoryBuffer::new(3);
+//! for sample in [21.0, 21.5, 22.0, 22.5] {
+//!     temperatures.push(sample);
+//! }
+//! assert_eq!(temperatures.to_vec(), vec![21.5, 22.0, 22.5]);
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Keeps the last `capacity` values pushed into it, oldest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryBuffer<T> {
+    values: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> HistoryBuffer<T> {
+    /// Creates an empty buffer holding at most `capacity` values.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "history capacity must be at least 1");
+        Self {
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Appends `value`, dropping the oldest value once the buffer is full.
+    pub fn push(&mut self, value: T) {
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+    }
+
+    /// Maximum number of values kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of values currently held.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if nothing has been pushed since creation or `clear`.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The most recently pushed value.
+    pub fn latest(&self) -> Option<&T> {
+        self.values.back()
+    }
+
+    /// Iterates over the values, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.values.iter()
+    }
+
+    /// Removes every value.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    /// Copies the values into a `Vec`, oldest first.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.values.iter().cloned().collect()
+    }
+
+    /// Writes the values as a single CSV column, one value per line, oldest first.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()>
+    where
+        T: Display,
+    {
+        for value in &self.values {
+            writeln!(writer, "{value}")?;
+        }
+        writer.flush()
+    }
+
+    /// Writes the values to the file at `path` as a single CSV column, replacing
+    /// any existing file.
+    pub fn export_csv(&self, path: impl AsRef<Path>) -> io::Result<()>
+    where
+        T: Display,
+    {
+        self.write_csv(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<T> Extend<T> for HistoryBuffer<T> {
+    /// Pushes every value in turn, so only the last `capacity` of them are kept.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capped_buffer_keeps_last_values_and_writes_csv() {
+        let mut history = HistoryBuffer::new(3);
+        assert!(history.is_empty());
+        history.extend([1.5, 2.0, 2.5, 3.0, 3.5]);
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.latest(), Some(&3.5));
+        assert_eq!(history.to_vec(), vec![2.5, 3.0, 3.5]);
+
+        let mut csv = Vec::new();
+        history.write_csv(&mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "2.5\n3\n3.5\n");
+
+        let path = std::env::temp_dir().join(format!("history-{}.csv", std::process::id()));
+        history.export_csv(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "2.5\n3\n3.5\n");
+        std::fs::remove_file(path).unwrap();
+    }
+}
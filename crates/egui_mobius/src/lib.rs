@@ -71,7 +71,7 @@
 //!                     println!("UI: Task {} completed", id);
 //!                 }
 //!             }
-//!         });
+//!         }).unwrap();
 //!
 //!         Self {
 //!             tasks,
@@ -112,7 +112,7 @@
 //!                 }
 //!             }
 //!         }
-//!     });
+//!     }).unwrap();
 //! }
 //!
 //! fn main() {
@@ -183,7 +183,7 @@
 //!             }
 //!         }
 //!     }
-//! });
+//! }).unwrap();
 //!
 //! // UI can send requests
 //! signal_to_dispatcher.send(Request::FetchWeather("London".to_string())).unwrap();
@@ -198,7 +198,7 @@
 //!             println!("Time in {}: {}", zone, time);
 //!         }
 //!     }
-//! });
+//! }).unwrap();
 //! ```
 //!
 //! ## Reactive System
@@ -220,24 +220,64 @@
 //!
 //! - [`signals`]: Signal type for sending messages
 //! - [`slot`]: Slot type for receiving and processing messages
+//! - [`channel`]: Message queue behind each signal-slot pair, with a priority lane
 //! - [`factory`]: Utilities for creating signal-slot pairs
 //! - [`types`]: Core types like `Value<T>` for state management
 //! - [`dispatching`]: Signal dispatching and routing system
+//! - [`error`]: `MobiusError`, the error type of every signal/slot operation
+//! - [`prelude`]: The commonly used items, for `use egui_mobius::prelude::*;`
+//! - [`deferred`]: Signal sends queued during a critical section and flushed afterwards
+//! - [`shutdown`]: Coordinated shutdown of slots and background threads
+//! - [`history`]: Fixed-capacity sample history with CSV export
+//! - [`replay`]: Broadcast signals that replay recent messages to late subscribers
+//! - [`topology`]: Named signal/slot connections, exportable as a Graphviz diagram
+//! - `timer`: Interval signals with a stoppable `TimerHandle` (native targets only)
+//! - `recording`: Record-and-replay of slot messages and state history (requires the `record` feature)
 //!
 //! The reactive system functionality is available in the separate `egui_mobius_reactive` crate.
 
 // Declare modules
+pub mod channel;
+pub mod deferred;
 pub mod dispatching;
+pub mod error;
 pub mod factory;
+pub mod history;
+pub mod prelude;
+#[cfg(feature = "record")]
+pub mod recording;
+pub mod replay;
 pub mod runtime;
+pub mod shutdown;
 pub mod signals;
 pub mod slot;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod timer;
+pub mod topology;
+mod trace;
 pub mod types;
 
 // Re-export commonly used items
-pub use dispatching::{AsyncDispatcher, Dispatcher, SignalDispatcher};
-pub use factory::create_signal_slot;
+pub use deferred::DeferredDispatch;
+#[cfg(not(target_arch = "wasm32"))]
+pub use dispatching::AsyncDispatcher;
+pub use dispatching::{Dispatcher, SignalDispatcher, TypedDispatcher};
+pub use error::MobiusError;
+#[cfg(not(target_arch = "wasm32"))]
+pub use factory::create_interval_signal;
+pub use factory::{
+    Duplex, create_duplex, create_latest_only_signal_slot, create_named_signal_slot,
+    create_replay_signal, create_signal_slot, create_signal_slot_arc,
+    create_signal_slot_with_capacity, create_unbounded_signal_slot,
+};
+pub use history::HistoryBuffer;
+pub use replay::ReplaySignal;
 pub use runtime::{EventRoute, MobiusHandle, MobiusRuntime};
+pub use shutdown::{ShutdownCoordinator, ShutdownToken};
 pub use signals::Signal;
-pub use slot::Slot;
+#[cfg(feature = "metrics")]
+pub use signals::SignalMetrics;
+pub use slot::{SelfSignal, Slot, SlotWorker};
+#[cfg(not(target_arch = "wasm32"))]
+pub use timer::TimerHandle;
 pub use types::{Edge, Value};
@@ -0,0 +1,49 @@
+//! Ergonomic re-exports for `egui_mobius`
+//!
+//! Bring this into scope via:
+//! ```rust
+//! use egui_mobius::prelude::*;
+//!
+//! let (signal, mut slot) = create_signal_slot::<u32>();
+//! let total = Value::new(0);
+//! let sum = total.clone();
+//! slot.start(move |n| *sum.lock().unwrap() += n).unwrap();
+//! signal.send(2).unwrap();
+//! ```
+//!
+//! Covers the signal/slot pairs and their factories, `Value` state, dispatchers
+//! and the runtime. Less common items, such as channel internals or
+//! `types::ProjectedGuard`, stay in their modules.
+
+// Signals, slots and the functions creating them
+pub use crate::factory::{
+    self, Duplex, create_duplex, create_latest_only_signal_slot, create_named_signal_slot,
+    create_replay_signal, create_signal_slot, create_signal_slot_arc,
+    create_signal_slot_with_capacity, create_unbounded_signal_slot,
+};
+pub use crate::replay::ReplaySignal;
+pub use crate::signals::Signal;
+pub use crate::slot::{SelfSignal, Slot, SlotWorker};
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::{factory::create_interval_signal, timer::TimerHandle};
+
+// State
+pub use crate::history::HistoryBuffer;
+pub use crate::types::{Edge, Value, ValueGuard};
+
+// Dispatching and the runtime
+pub use crate::deferred::DeferredDispatch;
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::dispatching::AsyncDispatcher;
+pub use crate::dispatching::{Dispatcher, SignalDispatcher, TypedDispatcher};
+pub use crate::runtime::{EventRoute, MobiusHandle, MobiusRuntime};
+pub use crate::shutdown::{ShutdownCoordinator, ShutdownToken};
+pub use crate::topology::TopologyRegistry;
+
+pub use crate::error::MobiusError;
+
+#[cfg(feature = "record")]
+pub use crate::recording::{RecordingSlot, StateRecorder, replay_into};
+
+// Useful shared types
+pub use std::sync::{Arc, Mutex};
@@ -0,0 +1,350 @@
+//! Record-and-replay of the messages flowing through a `Slot<T>`.
+//!
+//! A `RecordingSlot<T>` wraps a slot and tees every message it receives to a log file
+//! (one JSON document per line) before handing it to the handler. `replay_into` reads
+//! such a log back and sends each message, in order, through a `Signal<T>`. Together
+//! they allow deterministic regression tests of backend event processing without a
+//! live UI: record a session once, then replay it into a fresh slot and compare the
+//! handler's side effects.
+//!
+//! A `StateRecorder<S>` does the same for application state: every mutation made
+//! through it is snapshotted with a timestamp, so a debug panel can show how the
+//! state evolved and `rewind_to` can travel back to an earlier snapshot.
+//!
+//! Requires the `record` feature.
+//!
+//! # Example
+//! ```rust,no_run
+//! use egui_mobius::factory::create_signal_slot;
+//! use egui_mobius::recording::{RecordingSlot, replay_into};
+//!
+//! // Record a live session
+//! let (signal, slot) = create_signal_slot::<String>();
+//! let mut recorder = RecordingSlot::new(slot, "session.jsonl").unwrap();
+//! recorder.start(|cmd| println!("live: {cmd}")).unwrap();
+//! signal.send("refresh".to_string()).unwrap();
+//!
+//! // Later, in a test
+//! let (signal, mut slot) = create_signal_slot::<String>();
+//! slot.start(|cmd| println!("replayed: {cmd}")).unwrap();
+//! let count = replay_into(&signal, "session.jsonl").unwrap();
+//! ```
+
+use crate::error::MobiusError;
+use crate::signals::Signal;
+use crate::slot::{Slot, SlotWorker};
+use crate::types::Value;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A `Slot<T>` that writes every received message to a log before handling it.
+pub struct RecordingSlot<T> {
+    slot: Slot<T>,
+    log: Arc<Mutex<File>>,
+}
+
+impl<T> RecordingSlot<T>
+where
+    T: Serialize + Send + Clone + 'static,
+{
+    /// Wrap `slot`, recording its messages to a new log at `path`.
+    ///
+    /// An existing file at `path` is truncated.
+    pub fn new(slot: Slot<T>, path: impl AsRef<Path>) -> io::Result<Self> {
+        let log = File::create(path)?;
+        Ok(Self {
+            slot,
+            log: Arc::new(Mutex::new(log)),
+        })
+    }
+
+    /// See `Slot::on_handler_panic`.
+    pub fn on_handler_panic<F>(&self, callback: F)
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        self.slot.on_handler_panic(callback);
+    }
+
+    /// Start the slot on its own thread, recording each message before `handler` runs.
+    ///
+    /// Every message is flushed to the log as it arrives, so the log is complete even
+    /// if the application exits abruptly. A message that fails to serialize or write
+    /// is reported on stderr and still handled.
+    pub fn start<F>(&mut self, mut handler: F) -> Result<SlotWorker, MobiusError>
+    where
+        F: FnMut(T) + Send + 'static,
+    {
+        let log = Arc::clone(&self.log);
+        self.slot.start(move |msg: T| {
+            if let Err(e) = write_entry(&log, &msg) {
+                eprintln!("⚠️  failed to record slot message: {e}");
+            }
+            handler(msg);
+        })
+    }
+}
+
+fn write_entry<T: Serialize>(log: &Mutex<File>, msg: &T) -> io::Result<()> {
+    let mut line = serde_json::to_vec(msg)?;
+    line.push(b'\n');
+    let mut file = log.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    file.write_all(&line)?;
+    file.flush()
+}
+
+/// Send every message recorded in the log at `path` through `signal`, in order.
+///
+/// Returns the number of messages sent. Blank lines are skipped; a line that does
+/// not deserialize to `T` stops the replay with an `InvalidData` error, and a
+/// disconnected slot stops it with a `BrokenPipe` error.
+pub fn replay_into<T>(signal: &Signal<T>, path: impl AsRef<Path>) -> io::Result<usize>
+where
+    T: DeserializeOwned + Send + Clone + 'static,
+{
+    let reader = BufReader::new(File::open(path)?);
+    let mut sent = 0;
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let msg: T = serde_json::from_str(&line).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid entry on line {}: {e}", index + 1),
+            )
+        })?;
+        signal
+            .send(msg)
+            .map_err(|e| io::Error::new(io::ErrorKind::BrokenPipe, e))?;
+        sent += 1;
+    }
+    Ok(sent)
+}
+
+/// A copy of the recorded state, taken right after a mutation.
+#[derive(Debug, Clone)]
+pub struct Snapshot<S> {
+    /// When the mutation happened.
+    pub at: SystemTime,
+    /// The state after the mutation.
+    pub state: S,
+}
+
+/// Records a snapshot of a `Value<S>` every time it is mutated through the recorder.
+///
+/// Mutations made by locking the `Value` directly are not seen, so route all writes
+/// to the state through `mutate`. Clones share the same history.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius::recording::StateRecorder;
+/// use egui_mobius::types::Value;
+///
+/// let recorder = StateRecorder::new(Value::new(0u32));
+/// recorder.mutate(|counter| *counter += 1);
+/// recorder.mutate(|counter| *counter *= 10);
+/// assert_eq!(recorder.get(), 10);
+///
+/// recorder.rewind_to(1);
+/// assert_eq!(recorder.get(), 1);
+/// assert_eq!(recorder.len(), 2);
+/// ```
+#[derive(Clone)]
+pub struct StateRecorder<S> {
+    value: Value<S>,
+    history: Arc<Mutex<Vec<Snapshot<S>>>>,
+}
+
+impl<S> StateRecorder<S>
+where
+    S: Clone + Serialize,
+{
+    /// Start recording `value`, whose current state becomes snapshot 0.
+    pub fn new(value: Value<S>) -> Self {
+        let initial = Snapshot {
+            at: SystemTime::now(),
+            state: value.get(),
+        };
+        Self {
+            value,
+            history: Arc::new(Mutex::new(vec![initial])),
+        }
+    }
+
+    /// The recorded `Value`, e.g. to hand to UI code that only reads it.
+    pub fn value(&self) -> &Value<S> {
+        &self.value
+    }
+
+    /// The current state.
+    pub fn get(&self) -> S {
+        self.value.get()
+    }
+
+    /// Mutate the state and record a snapshot of the result.
+    pub fn mutate<F>(&self, f: F)
+    where
+        F: FnOnce(&mut S),
+    {
+        let mut state = self.value.lock().unwrap();
+        f(&mut state);
+        // Recorded while the state is still locked so snapshots keep mutation order
+        self.snapshots().push(Snapshot {
+            at: SystemTime::now(),
+            state: state.clone(),
+        });
+    }
+
+    /// A copy of every snapshot so far, oldest first.
+    ///
+    /// Recording goes on while the copy is in use.
+    pub fn history(&self) -> Vec<Snapshot<S>> {
+        self.snapshots().clone()
+    }
+
+    /// Number of snapshots, including the initial one.
+    pub fn len(&self) -> usize {
+        self.snapshots().len()
+    }
+
+    /// `true` if no snapshot is recorded, which cannot happen as the initial state
+    /// is recorded on creation and kept by `rewind_to`.
+    pub fn is_empty(&self) -> bool {
+        self.snapshots().is_empty()
+    }
+
+    /// Restore the state of snapshot `index` and discard every later snapshot,
+    /// so new mutations continue from the restored state.
+    ///
+    /// Panics if `index` is out of bounds, like slice indexing.
+    pub fn rewind_to(&self, index: usize) {
+        let mut state = self.value.lock().unwrap();
+        let mut history = self.snapshots();
+        *state = history[index].state.clone();
+        history.truncate(index + 1);
+    }
+
+    /// The history as a JSON array of `[unix_millis, state]` pairs, oldest first.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        let history = self.snapshots();
+        let entries: Vec<(u64, &S)> = history
+            .iter()
+            .map(|snapshot| (unix_millis(snapshot.at), &snapshot.state))
+            .collect();
+        serde_json::to_string(&entries)
+    }
+
+    fn snapshots(&self) -> std::sync::MutexGuard<'_, Vec<Snapshot<S>>> {
+        self.history
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn unix_millis(at: SystemTime) -> u64 {
+    at.duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::create_signal_slot;
+    use std::thread;
+    use std::time::Duration;
+
+    /// Applies `(op, operand)` commands to an accumulator, mirroring a small backend.
+    fn apply(total: &Mutex<Vec<i64>>, (op, operand): (String, i64)) {
+        let mut history = total.lock().unwrap();
+        let current = history.last().copied().unwrap_or(0);
+        let next = match op.as_str() {
+            "add" => current + operand,
+            "mul" => current * operand,
+            _ => current,
+        };
+        history.push(next);
+    }
+
+    #[test]
+    fn test_recorded_session_replays_identically() {
+        let path = std::env::temp_dir().join(format!(
+            "egui_mobius_recording_{}.jsonl",
+            std::process::id()
+        ));
+
+        let live = Arc::new(Mutex::new(Vec::new()));
+        let (signal, slot) = create_signal_slot::<(String, i64)>();
+        let mut recorder = RecordingSlot::new(slot, &path).unwrap();
+        let live_clone = Arc::clone(&live);
+        recorder.start(move |cmd| apply(&live_clone, cmd)).unwrap();
+        for cmd in [("add", 3), ("mul", 4), ("noop", 0), ("add", -2)] {
+            signal.send((cmd.0.to_string(), cmd.1)).unwrap();
+        }
+        thread::sleep(Duration::from_millis(100));
+
+        let replayed = Arc::new(Mutex::new(Vec::new()));
+        let (signal, mut slot) = create_signal_slot::<(String, i64)>();
+        let replayed_clone = Arc::clone(&replayed);
+        slot.start(move |cmd| apply(&replayed_clone, cmd)).unwrap();
+        assert_eq!(replay_into(&signal, &path).unwrap(), 4);
+        thread::sleep(Duration::from_millis(100));
+
+        assert_eq!(*live.lock().unwrap(), vec![3, 12, 12, 10]);
+        assert_eq!(*replayed.lock().unwrap(), *live.lock().unwrap());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_replay_rejects_malformed_log() {
+        let path = std::env::temp_dir().join(format!(
+            "egui_mobius_recording_bad_{}.jsonl",
+            std::process::id()
+        ));
+        std::fs::write(&path, "1\n\nnot json\n").unwrap();
+
+        let (signal, _slot) = create_signal_slot::<u32>();
+        let err = replay_into(&signal, &path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("line 3"));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_state_recorder_history_and_rewind() {
+        let recorder = StateRecorder::new(Value::new(vec!["boot".to_string()]));
+        let panel = recorder.clone();
+        for step in ["connect", "load", "render"] {
+            recorder.mutate(|log| log.push(step.to_string()));
+        }
+
+        let lengths: Vec<usize> = panel.history().iter().map(|s| s.state.len()).collect();
+        assert_eq!(lengths, vec![1, 2, 3, 4]);
+        let history = panel.history();
+        assert!(history.windows(2).all(|pair| pair[0].at <= pair[1].at));
+
+        recorder.rewind_to(1);
+        assert_eq!(recorder.get(), vec!["boot", "connect"]);
+        assert_eq!(recorder.len(), 2);
+        assert!(!recorder.is_empty());
+        // The copy taken before the rewind is not affected by it
+        assert_eq!(history.len(), 4);
+
+        // Replaying the discarded mutations reproduces the recorded states
+        for snapshot in &history[2..] {
+            let step = snapshot.state.last().unwrap().clone();
+            recorder.mutate(|log| log.push(step));
+        }
+        assert_eq!(recorder.get(), history[3].state);
+
+        let json = recorder.to_json().unwrap();
+        assert!(json.starts_with("[["));
+        assert!(json.ends_with(r#"["boot","connect","load","render"]]]"#));
+    }
+}
@@ -0,0 +1,145 @@
+//! Broadcast signals that replay recent messages to late subscribers.
+//!
+//! A `ReplaySignal<T>`, created with `factory::create_replay_signal`, delivers every
+//! message to all of its subscribers and remembers the most recent ones. A slot
+//! obtained from `subscribe` first receives that history and then the live
+//! messages, so a log view attached after startup still shows what happened
+//! before it existed.
+//!
+//! # Example
+//! ```rust
+//! use egui_mobius::factory::create_replay_signal;
+//!
+//! let events = create_replay_signal::<String>(2);
+//! events.send("starting".to_string());
+//! events.send("config loaded".to_string());
+//! events.send("connected".to_string());
+//!
+//! // Attached late, but still sees the last two messages first
+//! let mut log = events.subscribe();
+//! log.start(|line| println!("{line}")).unwrap();
+//! events.send("ready".to_string());
+//! ```
+
+use crate::factory::create_unbounded_signal_slot;
+use crate::signals::Signal;
+use crate::slot::Slot;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Message history and subscribers of a `ReplaySignal`.
+struct ReplayState<T> {
+    history: VecDeque<T>,
+    capacity: usize,
+    subscribers: Vec<Signal<T>>,
+}
+
+/// A broadcast signal keeping its last messages for new subscribers.
+///
+/// Clones share the history and the subscribers.
+pub struct ReplaySignal<T> {
+    state: Arc<Mutex<ReplayState<T>>>,
+}
+
+impl<T> ReplaySignal<T>
+where
+    T: Send + Clone + 'static,
+{
+    /// Creates a signal remembering up to `buffer` messages.
+    pub(crate) fn new(buffer: usize) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ReplayState {
+                history: VecDeque::with_capacity(buffer),
+                capacity: buffer,
+                subscribers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Send `message` to every subscriber and record it in the history.
+    ///
+    /// Subscribers whose slot has been dropped are removed. Returns the number
+    /// of subscribers the message was delivered to.
+    pub fn send(&self, message: T) -> usize {
+        let mut state = self.lock();
+        if state.capacity > 0 {
+            if state.history.len() == state.capacity {
+                state.history.pop_front();
+            }
+            state.history.push_back(message.clone());
+        }
+        state
+            .subscribers
+            .retain(|subscriber| subscriber.send(message.clone()).is_ok());
+        state.subscribers.len()
+    }
+
+    /// Creates a new subscriber slot, already holding the recorded history.
+    ///
+    /// The slot is unbounded, so a slow subscriber never blocks `send`. Nothing
+    /// sent concurrently is lost or reordered: the history and the live
+    /// messages follow each other without a gap.
+    pub fn subscribe(&self) -> Slot<T> {
+        let (signal, slot) = create_unbounded_signal_slot();
+        let mut state = self.lock();
+        for message in &state.history {
+            // Cannot fail, the slot is still in hand
+            let _ = signal.send(message.clone());
+        }
+        state.subscribers.push(signal);
+        slot
+    }
+
+    /// The recorded messages, oldest first.
+    pub fn history(&self) -> Vec<T> {
+        self.lock().history.iter().cloned().collect()
+    }
+
+    /// Number of subscribers at the last `send` or `subscribe`.
+    pub fn subscriber_count(&self) -> usize {
+        self.lock().subscribers.len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ReplayState<T>> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl<T> Clone for ReplaySignal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factory::create_replay_signal;
+
+    #[test]
+    fn test_late_subscriber_gets_recent_history_then_live_messages() {
+        let signal = create_replay_signal::<u32>(3);
+        let early = signal.subscribe();
+        for n in 1..=5 {
+            assert_eq!(signal.send(n), 1);
+        }
+        assert_eq!(signal.history(), vec![3, 4, 5]);
+
+        let late = signal.subscribe();
+        assert_eq!(signal.send(6), 2);
+
+        let received = |slot: &crate::slot::Slot<u32>| {
+            let receiver = slot.receiver.lock().unwrap();
+            std::iter::from_fn(|| receiver.try_recv().ok()).collect::<Vec<_>>()
+        };
+        assert_eq!(received(&early), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(received(&late), vec![3, 4, 5, 6]);
+
+        drop(early);
+        assert_eq!(signal.send(7), 1);
+        assert_eq!(signal.subscriber_count(), 1);
+    }
+}
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use std::sync::mpsc;
 use tokio::sync::Notify;
 
+use crate::error::MobiusError;
 use crate::slot::Slot;
 
 #[derive(Debug, Clone)]
@@ -94,7 +95,10 @@ impl<E: EventRoute + Send + Clone + 'static> MobiusRuntime<E> {
             .insert(route.to_string(), Arc::new(move |e| Box::pin(handler(e))));
     }
 
-    pub async fn run(mut self) {
+    /// Process events until `MobiusHandle::shutdown` is called.
+    ///
+    /// Fails right away if the runtime's slot cannot be started.
+    pub async fn run(mut self) -> Result<(), MobiusError> {
         self.state = RuntimeState::Running;
         let processed_tx = self.processed.clone();
         let handlers = Arc::new(self.handlers);
@@ -117,7 +121,7 @@ impl<E: EventRoute + Send + Clone + 'static> MobiusRuntime<E> {
                     let _ = processed_tx.send(Processed::Success(()));
                 }
             }
-        });
+        })?;
 
         // Give the slot time to start processing
         tokio::task::yield_now().await;
@@ -127,6 +131,7 @@ impl<E: EventRoute + Send + Clone + 'static> MobiusRuntime<E> {
 
         // Drop the slot to close the channel
         drop(slot);
+        Ok(())
     }
 }
 
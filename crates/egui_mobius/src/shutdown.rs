@@ -0,0 +1,171 @@
+//! Application-wide shutdown coordination.
+//!
+//! A `ShutdownCoordinator` hands out `ShutdownToken`s to the slots and background
+//! threads of an application. Calling `shutdown` flips every token at once: slots
+//! started with `Slot::start_until` finish the message they are handling and exit,
+//! and threads blocked in `ShutdownToken::wait` wake up, so all workers can be joined
+//! for an orderly teardown instead of calling `std::process::exit` mid-handler.
+//!
+//! # Example
+//! ```rust
+//! use egui_mobius::factory::create_signal_slot;
+//! use egui_mobius::shutdown::ShutdownCoordinator;
+//!
+//! let coordinator = ShutdownCoordinator::new();
+//!
+//! let (signal, mut slot) = create_signal_slot::<String>();
+//! let worker = slot.start_until(coordinator.token(), |cmd| println!("backend: {cmd}")).unwrap();
+//! signal.send("refresh".to_string()).unwrap();
+//!
+//! // e.g. when the window is closed
+//! coordinator.shutdown();
+//! worker.join().unwrap();
+//! ```
+
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+/// How often a slot started with `Slot::start_until` checks its token while idle.
+pub const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Shared by a coordinator and all of its tokens.
+#[derive(Default)]
+struct ShutdownState {
+    requested: Mutex<bool>,
+    changed: Condvar,
+}
+
+impl ShutdownState {
+    fn requested(&self) -> std::sync::MutexGuard<'_, bool> {
+        self.requested
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Triggers shutdown for every `ShutdownToken` it handed out.
+///
+/// Clones share the same state, so any of them can trigger the shutdown.
+#[derive(Clone, Default)]
+pub struct ShutdownCoordinator {
+    state: Arc<ShutdownState>,
+}
+
+impl ShutdownCoordinator {
+    /// Creates a coordinator that has not been shut down.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A token observing this coordinator, for one slot or thread.
+    pub fn token(&self) -> ShutdownToken {
+        ShutdownToken {
+            state: Arc::clone(&self.state),
+        }
+    }
+
+    /// Flip every token. Calling it again has no further effect.
+    pub fn shutdown(&self) {
+        *self.state.requested() = true;
+        self.state.changed.notify_all();
+    }
+
+    /// Returns `true` once `shutdown` has been called.
+    pub fn is_shutdown(&self) -> bool {
+        *self.state.requested()
+    }
+}
+
+/// Observes a `ShutdownCoordinator` from a slot or background thread.
+#[derive(Clone)]
+pub struct ShutdownToken {
+    state: Arc<ShutdownState>,
+}
+
+impl ShutdownToken {
+    /// Returns `true` once the coordinator has been shut down.
+    pub fn is_shutdown(&self) -> bool {
+        *self.state.requested()
+    }
+
+    /// Block until the coordinator is shut down.
+    pub fn wait(&self) {
+        let mut requested = self.state.requested();
+        while !*requested {
+            requested = self
+                .state
+                .changed
+                .wait(requested)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+    }
+
+    /// Block until the coordinator is shut down or `timeout` has elapsed.
+    ///
+    /// Returns `true` if shutdown was requested, which makes it a drop-in
+    /// replacement for `thread::sleep` in periodic worker loops.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let requested = self.state.requested();
+        let (requested, _) = self
+            .state
+            .changed
+            .wait_timeout_while(requested, timeout, |requested| !*requested)
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *requested
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::create_signal_slot;
+    use std::sync::mpsc;
+    use std::thread;
+
+    #[test]
+    fn test_shutdown_stops_registered_slots() {
+        let coordinator = ShutdownCoordinator::new();
+        let (tx, rx) = mpsc::channel();
+
+        let (first_signal, mut first_slot) = create_signal_slot::<u32>();
+        let first_tx = tx.clone();
+        let first = first_slot
+            .start_until(coordinator.token(), move |n| {
+                first_tx.send(n).unwrap();
+            })
+            .unwrap();
+
+        let (second_signal, mut second_slot) = create_signal_slot::<u32>();
+        let second = second_slot
+            .start_until(coordinator.token(), move |n| {
+                tx.send(n * 10).unwrap();
+            })
+            .unwrap();
+
+        first_signal.send(1).unwrap();
+        second_signal.send(2).unwrap();
+        let mut handled: Vec<u32> = (0..2)
+            .map(|_| rx.recv_timeout(Duration::from_secs(1)).unwrap())
+            .collect();
+        handled.sort();
+        assert_eq!(handled, vec![1, 20]);
+
+        // Both signals are still alive, so only the token can stop the workers
+        coordinator.shutdown();
+        first.join().unwrap();
+        second.join().unwrap();
+    }
+
+    #[test]
+    fn test_token_wait_wakes_on_shutdown() {
+        let coordinator = ShutdownCoordinator::new();
+        let token = coordinator.token();
+        assert!(!token.wait_timeout(Duration::from_millis(10)));
+
+        let waiter = thread::spawn(move || token.wait());
+        coordinator.shutdown();
+        waiter.join().unwrap();
+        assert!(coordinator.is_shutdown());
+        assert!(coordinator.token().wait_timeout(Duration::from_secs(1)));
+    }
+}
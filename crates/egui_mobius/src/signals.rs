@@ -7,11 +7,47 @@
 //! by managing signal-slot registration and message routing.
 //!
 
-use std::sync::mpsc::Sender;
+use crate::channel::Sender;
+use crate::error::MobiusError;
+use crate::slot::Slot;
+#[cfg(feature = "metrics")]
+use std::sync::Arc;
+#[cfg(feature = "metrics")]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{SendError, TrySendError};
 
 /// Signal struct with send and send_multiple methods.
 pub struct Signal<T> {
     pub sender: Sender<T>,
+    /// Receives the messages that could not be delivered, see `with_dead_letter`.
+    dead_letter: Option<Sender<T>>,
+    #[cfg(feature = "metrics")]
+    counters: Arc<SignalCounters>,
+}
+
+/// Snapshot of the send counters of a `Signal<T>`, available with the
+/// `metrics` feature.
+///
+/// The counters are shared by every clone of a signal, so the numbers
+/// reflect all traffic sent into the same slot.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SignalMetrics {
+    /// Total number of messages successfully handed to the channel.
+    pub sent: u64,
+    /// Number of messages that could not be delivered, counted one by one
+    /// also for batch sends.
+    pub failed: u64,
+    /// Capacity of the underlying channel, `None` when unbounded.
+    pub capacity: Option<usize>,
+}
+
+/// Atomic counters backing `SignalMetrics`.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Default)]
+struct SignalCounters {
+    sent: AtomicU64,
+    failed: AtomicU64,
 }
 
 impl<T> Signal<T>
@@ -29,31 +65,338 @@ where
     /// signal.send("Hello".to_string());
     /// ```
     pub fn new(sender: Sender<T>) -> Self {
-        Signal { sender }
+        Signal {
+            sender,
+            dead_letter: None,
+            #[cfg(feature = "metrics")]
+            counters: Arc::default(),
+        }
     }
 
     /// Send a ```message<T>``` to the ```Signal<T>``` instance. Typically,
     /// the ```message<T>```  is an Event, Command, or Response type
     /// but can be any type that implements the Send trait.
-    pub fn send(&self, cmd_or_msg: T) -> Result<(), String> {
+    ///
+    /// Fails with `MobiusError::Disconnected` once the slot is gone.
+    pub fn send(&self, cmd_or_msg: T) -> Result<(), MobiusError> {
         if let Err(e) = self.sender.send(cmd_or_msg) {
-            eprintln!("\n***** Failed to send command: {e:?}");
-            return Err(format!("Failed to send command: {e:?}"));
+            self.record_failed();
+            self.undelivered(e, "command");
+            return Err(MobiusError::Disconnected);
+        }
+        self.record_sent();
+        Ok(())
+    }
+
+    /// Send a ```message<T>``` without blocking.
+    ///
+    /// Where `send` waits for room in a full bounded queue, this fails with
+    /// `MobiusError::Full` instead; it fails with `MobiusError::Disconnected` once
+    /// the slot is gone. In both cases the message goes to the dead-letter slot, if
+    /// one is set. Use it where blocking is not an option, such as in async tasks.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::MobiusError;
+    /// use egui_mobius::factory::create_signal_slot_with_capacity;
+    ///
+    /// let (signal, _slot) = create_signal_slot_with_capacity::<u32>(1);
+    /// signal.try_send(1).unwrap();
+    /// assert_eq!(signal.try_send(2), Err(MobiusError::Full));
+    /// ```
+    pub fn try_send(&self, cmd_or_msg: T) -> Result<(), MobiusError> {
+        let (error, cmd_or_msg) = match self.sender.try_send(cmd_or_msg) {
+            Ok(()) => {
+                self.record_sent();
+                return Ok(());
+            }
+            Err(TrySendError::Full(msg)) => (MobiusError::Full, msg),
+            Err(TrySendError::Disconnected(msg)) => (MobiusError::Disconnected, msg),
+        };
+        self.record_failed();
+        self.forward_to_dead_letter([cmd_or_msg]);
+        Err(error)
+    }
+
+    /// Like `try_send`, but a message that finds the queue full is handed back
+    /// in `Err(Some(..))` rather than counted as failed, so the caller can retry
+    /// it later. `Err(None)` means the slot is gone and the message was handled
+    /// like an undelivered `send`.
+    pub(crate) fn try_send_or_keep(&self, cmd_or_msg: T) -> Result<(), Option<T>> {
+        match self.sender.try_send(cmd_or_msg) {
+            Ok(()) => {
+                self.record_sent();
+                Ok(())
+            }
+            Err(TrySendError::Full(msg)) => Err(Some(msg)),
+            Err(TrySendError::Disconnected(msg)) => {
+                self.record_failed();
+                self.undelivered(SendError(msg), "command");
+                Err(None)
+            }
+        }
+    }
+
+    /// Send a ```message<T>``` and get notified once the slot has processed it.
+    ///
+    /// The returned receiver resolves after the slot's handler has returned for
+    /// this message (for async handlers, once its future has completed). It fails
+    /// instead if the message is never handled: the send failed, the slot's filter
+    /// dropped it, or the handler panicked. Await it in async code, or call
+    /// `try_recv` on it once per frame from the UI.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, mut slot) = create_signal_slot::<&str>();
+    /// slot.start(|command| println!("backend: {command}")).unwrap();
+    ///
+    /// let saved = signal.send_ack("save");
+    /// saved.blocking_recv().expect("save was not processed");
+    /// ```
+    pub fn send_ack(&self, cmd_or_msg: T) -> tokio::sync::oneshot::Receiver<()> {
+        match self.sender.send_ack(cmd_or_msg) {
+            Ok(processed) => {
+                self.record_sent();
+                processed
+            }
+            Err(e) => {
+                self.record_failed();
+                self.undelivered(e, "command");
+                // The token went down with the message, so the receiver fails
+                tokio::sync::oneshot::channel().1
+            }
+        }
+    }
+
+    /// Returns `true` while the slot receiving from this signal still exists.
+    ///
+    /// A started slot stays connected for as long as its worker runs, so a
+    /// producer loop can check this to stop early instead of discovering the
+    /// disconnection through a failed `send`.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, slot) = create_signal_slot::<u32>();
+    /// assert!(signal.is_connected());
+    /// drop(slot);
+    /// assert!(!signal.is_connected());
+    /// ```
+    pub fn is_connected(&self) -> bool {
+        self.sender.is_connected()
+    }
+
+    /// The maximum number of messages queued for the slot, `None` when unbounded.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::{create_signal_slot_with_capacity, create_unbounded_signal_slot};
+    ///
+    /// let (signal, _slot) = create_signal_slot_with_capacity::<u32>(64);
+    /// assert_eq!(signal.capacity(), Some(64));
+    /// let (signal, _slot) = create_unbounded_signal_slot::<u32>();
+    /// assert_eq!(signal.capacity(), None);
+    /// ```
+    pub fn capacity(&self) -> Option<usize> {
+        self.sender.capacity()
+    }
+
+    /// Change the capacity of a bounded signal-slot pair at runtime, e.g. to let
+    /// more updates queue up under load.
+    ///
+    /// The new capacity applies to every clone of the signal. Shrinking keeps the
+    /// messages already queued; further sends wait until the queue is below the
+    /// new bound. Fails with `MobiusError::FixedCapacity` for unbounded and
+    /// latest-only pairs.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot_with_capacity;
+    ///
+    /// let (signal, _slot) = create_signal_slot_with_capacity::<u32>(2);
+    /// signal.resize(16).unwrap();
+    /// assert_eq!(signal.capacity(), Some(16));
+    /// ```
+    pub fn resize(&self, capacity: usize) -> Result<(), MobiusError> {
+        if self.sender.resize(capacity) {
+            Ok(())
+        } else {
+            Err(MobiusError::FixedCapacity)
+        }
+    }
+
+    /// Send a ```message<T>``` ahead of every regular message still queued
+    /// for the slot, e.g. a cancel or shutdown command that must not wait
+    /// behind a backlog of routine updates.
+    ///
+    /// Priority messages are delivered in the order they were sent, and
+    /// regular `send` calls stay FIFO relative to each other.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, _slot) = create_signal_slot::<&str>();
+    /// signal.send("progress").unwrap();
+    /// signal.send_priority("cancel").unwrap();
+    /// ```
+    pub fn send_priority(&self, cmd_or_msg: T) -> Result<(), MobiusError> {
+        if let Err(e) = self.sender.send_priority(cmd_or_msg) {
+            self.record_failed();
+            self.undelivered(e, "priority command");
+            return Err(MobiusError::Disconnected);
         }
+        self.record_sent();
         Ok(())
     }
+
     /// Send multiple `messages<T>` to the `Signal<T>` instance. This is
     /// a convenience function that allows one to send multiple messages
     /// to the `Signal<T>` instance in a single call.
-    pub fn send_multiple(&self, cmd_or_msg_vec: Vec<T>) -> Result<(), String> {
-        for cmd_or_msg in cmd_or_msg_vec {
+    pub fn send_multiple(&self, cmd_or_msg_vec: Vec<T>) -> Result<(), MobiusError> {
+        let mut pending = cmd_or_msg_vec.into_iter();
+        while let Some(cmd_or_msg) = pending.next() {
             if let Err(e) = self.sender.send(cmd_or_msg) {
-                eprintln!("\n***** Failed to send command: {e:?}");
-                return Err(format!("Failed to send command: {e:?}"));
+                self.record_failed();
+                self.undelivered(e, "command");
+                // The rest of the batch is not attempted, so it is undelivered as well
+                let pending: Vec<T> = pending.collect();
+                pending.iter().for_each(|_| self.record_failed());
+                self.forward_to_dead_letter(pending);
+                return Err(MobiusError::Disconnected);
             }
+            self.record_sent();
         }
         Ok(())
     }
+
+    /// Send clones of all `messages<T>` in `msgs` as one all-or-nothing batch.
+    ///
+    /// Unlike `send_multiple` the caller does not have to own a `Vec`, and the
+    /// batch never ends up partially queued: if the slot's bounded channel cannot
+    /// hold all of them, none are sent and the call fails with
+    /// `MobiusError::BatchFull`, which carries the batch size and the room that
+    /// was left. This call never blocks.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, _slot) = create_signal_slot::<u32>();
+    /// let readings = [1, 2, 3];
+    /// signal.send_slice(&readings).unwrap();
+    /// ```
+    pub fn send_slice(&self, msgs: &[T]) -> Result<(), MobiusError>
+    where
+        T: Clone,
+    {
+        let error = match self.sender.try_send_slice(msgs) {
+            Ok(()) => {
+                msgs.iter().for_each(|_| self.record_sent());
+                return Ok(());
+            }
+            Err(TrySendError::Full(room)) => MobiusError::BatchFull {
+                delivered: 0,
+                requested: msgs.len(),
+                room,
+            },
+            Err(TrySendError::Disconnected(_)) => MobiusError::Disconnected,
+        };
+        msgs.iter().for_each(|_| self.record_failed());
+        self.forward_to_dead_letter(msgs.iter().cloned());
+        Err(error)
+    }
+
+    /// Forward every message this signal fails to deliver to `dead_letter`,
+    /// instead of reporting the failure on stderr.
+    ///
+    /// A message is undeliverable once the slot is gone, or when `send_slice`
+    /// finds the queue full. The sending call still returns its error; the
+    /// dead-letter slot is where the messages themselves end up, e.g. to log or
+    /// retry them. The setting is kept by clones made afterwards.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::{create_signal_slot, create_unbounded_signal_slot};
+    ///
+    /// let (_, mut dead_letters) = create_unbounded_signal_slot::<String>();
+    /// dead_letters.start(|msg| println!("undelivered: {msg}")).unwrap();
+    ///
+    /// let (signal, slot) = create_signal_slot::<String>();
+    /// let signal = signal.with_dead_letter(&dead_letters);
+    /// drop(slot);
+    /// assert!(signal.send("lost".to_string()).is_err()); // printed by the dead-letter slot
+    /// ```
+    pub fn with_dead_letter(mut self, dead_letter: &Slot<T>) -> Self
+    where
+        T: Clone,
+    {
+        self.dead_letter = Some(dead_letter.sender());
+        self
+    }
+
+    /// Returns a snapshot of the send counters for this signal.
+    ///
+    /// Only available with the `metrics` feature; without it the counters
+    /// are compiled out entirely.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// # #[cfg(feature = "metrics")] {
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, _slot) = create_signal_slot::<u32>();
+    /// signal.send(1).unwrap();
+    /// assert_eq!(signal.metrics().sent, 1);
+    /// # }
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> SignalMetrics {
+        SignalMetrics {
+            sent: self.counters.sent.load(Ordering::Relaxed),
+            failed: self.counters.failed.load(Ordering::Relaxed),
+            capacity: self.sender.capacity(),
+        }
+    }
+
+    /// Hands the message of a failed send to the dead-letter slot, or reports
+    /// the failure on stderr if there is none.
+    fn undelivered(&self, e: SendError<T>, what: &str) {
+        if self.dead_letter.is_none() {
+            eprintln!("\n***** Failed to send {what}: {e:?}");
+        }
+        self.forward_to_dead_letter([e.0]);
+    }
+
+    /// Sends `msgs` to the dead-letter slot, if one is set.
+    fn forward_to_dead_letter(&self, msgs: impl IntoIterator<Item = T>) {
+        let Some(dead_letter) = &self.dead_letter else {
+            return;
+        };
+        for msg in msgs {
+            // Never waits, as this runs under `try_send` as well: a message the
+            // dead-letter slot has no room for, or that finds it gone, is dropped
+            let _ = dead_letter.try_send(msg);
+        }
+    }
+
+    #[inline]
+    fn record_sent(&self) {
+        #[cfg(feature = "metrics")]
+        self.counters.sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn record_failed(&self) {
+        #[cfg(feature = "metrics")]
+        self.counters.failed.fetch_add(1, Ordering::Relaxed);
+    }
 }
 
 /// ```Clone``` trait implementation for ```Signal<T>```
@@ -73,6 +416,157 @@ impl<T> Clone for Signal<T> {
     fn clone(&self) -> Self {
         Signal {
             sender: self.sender.clone(),
+            dead_letter: self.dead_letter.clone(),
+            #[cfg(feature = "metrics")]
+            counters: Arc::clone(&self.counters),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::channel;
+
+    #[test]
+    fn send_slice_reports_partial_capacity_failure() {
+        let (tx, rx) = channel::bounded(4);
+        let signal = Signal::new(tx);
+        signal.send(0).unwrap();
+
+        let error = signal.send_slice(&[1, 2, 3, 4]).unwrap_err();
+        assert_eq!(
+            error,
+            MobiusError::BatchFull {
+                delivered: 0,
+                requested: 4,
+                room: 3
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "0 of 4 messages delivered, the channel has room for 3"
+        );
+
+        signal.send_slice(&[1, 2, 3]).unwrap();
+        let received: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+        assert_eq!(received, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn is_connected_until_slot_dropped() {
+        let (signal, slot) = crate::factory::create_signal_slot::<u32>();
+        let cloned = signal.clone();
+        assert!(signal.is_connected());
+
+        drop(slot);
+        assert!(!signal.is_connected());
+        assert!(!cloned.is_connected());
+        assert_eq!(signal.send(1), Err(MobiusError::Disconnected));
+    }
+
+    #[test]
+    fn resize_grows_bounded_queue() {
+        let (tx, rx) = channel::bounded(2);
+        let signal = Signal::new(tx);
+        assert_eq!(signal.capacity(), Some(2));
+        signal.send_slice(&[1, 2]).unwrap();
+        assert!(matches!(
+            signal.send_slice(&[3]),
+            Err(MobiusError::BatchFull { room: 0, .. })
+        ));
+
+        signal.clone().resize(4).unwrap();
+        assert_eq!(signal.capacity(), Some(4));
+        signal.send_slice(&[3, 4]).unwrap();
+        let received: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+        assert_eq!(received, vec![1, 2, 3, 4]);
+
+        let (latest, _rx) = channel::latest::<u32>();
+        assert_eq!(
+            Signal::new(latest).resize(8),
+            Err(MobiusError::FixedCapacity)
+        );
+        let (unbounded, _rx) = channel::channel::<u32>();
+        assert_eq!(
+            Signal::new(unbounded).resize(8),
+            Err(MobiusError::FixedCapacity)
+        );
+    }
+
+    #[test]
+    fn undelivered_messages_go_to_dead_letter_slot() {
+        let (_, dead_letters) = crate::factory::create_unbounded_signal_slot::<u32>();
+        let (signal, slot) = crate::factory::create_signal_slot::<u32>();
+        let signal = signal.with_dead_letter(&dead_letters);
+        signal.send(1).unwrap();
+        drop(slot);
+
+        assert_eq!(signal.send(2), Err(MobiusError::Disconnected));
+        assert_eq!(
+            signal.clone().send_priority(3),
+            Err(MobiusError::Disconnected)
+        );
+        assert!(signal.send_multiple(vec![4, 5]).is_err());
+
+        let (tx, _rx) = channel::bounded(1);
+        let full = Signal::new(tx).with_dead_letter(&dead_letters);
+        assert!(matches!(
+            full.send_slice(&[6, 7]),
+            Err(MobiusError::BatchFull { requested: 2, .. })
+        ));
+
+        let receiver = dead_letters.receiver.lock().unwrap();
+        let received: Vec<_> = std::iter::from_fn(|| receiver.try_recv().ok()).collect();
+        assert_eq!(received, vec![2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn try_send_never_blocks_on_a_full_dead_letter_slot() {
+        let (dead_signal, dead_letters) = crate::factory::create_signal_slot_with_capacity(1);
+        dead_signal.send(0).unwrap();
+        let (signal, _slot) = crate::factory::create_signal_slot_with_capacity::<u32>(1);
+        let signal = signal.with_dead_letter(&dead_letters);
+        signal.try_send(1).unwrap();
+
+        // Both queues are full, and nothing drains either of them
+        let started = std::time::Instant::now();
+        assert_eq!(signal.try_send(2), Err(MobiusError::Full));
+        assert!(started.elapsed() < std::time::Duration::from_millis(100));
+        assert_eq!(dead_letters.receiver.lock().unwrap().try_recv(), Ok(0));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn metrics_report_bounded_capacity() {
+        let (tx, _rx) = channel::bounded::<u8>(8);
+        assert_eq!(Signal::new(tx).metrics().capacity, Some(8));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn metrics_count_sends_and_failures() {
+        let (signal, slot) = crate::factory::create_signal_slot::<u32>();
+        let cloned = signal.clone();
+
+        for i in 0..5 {
+            signal.send(i).unwrap();
+        }
+        cloned.send_multiple(vec![5, 6, 7]).unwrap();
+
+        let metrics = signal.metrics();
+        assert_eq!(metrics.sent, 8);
+        assert_eq!(metrics.failed, 0);
+        assert_eq!(metrics.capacity, Some(crate::factory::DEFAULT_CAPACITY));
+
+        drop(slot);
+        assert!(signal.send(8).is_err());
+        assert_eq!(cloned.metrics().failed, 1);
+        assert_eq!(cloned.metrics().sent, 8);
+
+        // Batches count every undelivered message
+        assert!(signal.send_slice(&[9, 10]).is_err());
+        assert!(signal.send_multiple(vec![11, 12, 13]).is_err());
+        assert_eq!(cloned.metrics().failed, 6);
+    }
+}
@@ -7,26 +7,117 @@
 //!
 //! Each Slot can run on its own thread or within the tokio runtime, allowing flexible
 //! concurrent execution independent of the main application thread.
+//!
+//! On `wasm32` targets, where threads are unavailable, the same API is backed by the
+//! egui frame loop instead: started slots are queued and drained by `process_pending`,
+//! which the application calls once per frame (it is a no-op on native targets, so
+//! the call can stay in cross-platform code). Async handlers run on a local executor
+//! polled by the same call.
 
+use crate::channel::{self, Ack, Receiver, Sender, SenderSource};
+use crate::error::MobiusError;
+use crate::shutdown::ShutdownToken;
+use crate::signals::Signal;
+use crate::trace;
 use futures::FutureExt;
+use std::any::Any;
 use std::fmt::{Debug, Display};
-use std::panic::AssertUnwindSafe;
-use std::sync::mpsc::Receiver;
-use std::sync::{Arc, Mutex};
-use std::thread;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, TryLockError};
+use std::thread::{self, JoinHandle};
+
+/// Callback receiving the message of a panic raised inside a slot handler.
+type PanicHandler = Arc<dyn Fn(String) + Send + Sync>;
+
+/// Callback observing each message before the handler, see `Slot::inspect`.
+type Inspector<T> = Box<dyn Fn(&T) + Send + Sync>;
+
+/// Predicate deciding which messages reach the handler, see `Slot::set_filter`.
+type Filter<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
 
 /// Slot struct with receiver
 pub struct Slot<T> {
     pub receiver: Arc<Mutex<Receiver<T>>>,
+    /// Connects senders without locking `receiver`, which a running worker holds.
+    source: SenderSource<T>,
+    panic_handler: Arc<Mutex<Option<PanicHandler>>>,
+    inspectors: Arc<Mutex<Vec<Inspector<T>>>>,
+    filter: Arc<Mutex<Option<Filter<T>>>>,
+    filtered: Arc<AtomicUsize>,
+    name: Option<String>,
+    started: bool,
 }
 
 impl<T: Clone> Clone for Slot<T> {
     fn clone(&self) -> Self {
-        let (_new_sender, new_receiver) = std::sync::mpsc::channel();
+        let (_new_sender, new_receiver) = channel::channel();
         Self {
+            source: new_receiver.sender_source(),
             receiver: Arc::new(Mutex::new(new_receiver)),
+            panic_handler: Arc::default(),
+            inspectors: Arc::default(),
+            filter: Arc::default(),
+            filtered: Arc::default(),
+            name: self.name.clone(),
+            started: false,
+        }
+    }
+}
+
+/// Handle to the worker running a slot started with `Slot::start`.
+///
+/// Dropping it detaches the worker, which keeps running until every sender of
+/// the slot is gone.
+#[derive(Debug)]
+pub struct SlotWorker {
+    name: Option<String>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl SlotWorker {
+    /// The name given with `Slot::named`, which is also the worker thread's name.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The worker thread, or `None` on `wasm32` where slots run off the frame loop.
+    pub fn thread(&self) -> Option<&JoinHandle<()>> {
+        self.handle.as_ref()
+    }
+
+    /// Returns `true` once the worker has stopped, i.e. every sender was dropped.
+    pub fn is_finished(&self) -> bool {
+        self.handle.as_ref().is_none_or(JoinHandle::is_finished)
+    }
+
+    /// Wait for the worker to stop.
+    pub fn join(self) -> thread::Result<()> {
+        match self.handle {
+            Some(handle) => handle.join(),
+            None => Ok(()),
         }
     }
+
+    /// Give up the wrapper and return the underlying join handle.
+    pub fn into_thread(self) -> Option<JoinHandle<()>> {
+        self.handle
+    }
+}
+
+/// Handle passed to a `Slot::start_with_self` handler for queueing follow-up
+/// messages onto its own slot.
+///
+/// The handler runs on the only thread draining that queue, so waiting for room
+/// in a full bounded queue would never end. `send` fails with `MobiusError::Full`
+/// instead of blocking.
+pub struct SelfSignal<T>(Signal<T>);
+
+impl<T: Send + 'static> SelfSignal<T> {
+    /// Queue `msg` behind the messages already waiting, without blocking.
+    pub fn send(&self, msg: T) -> Result<(), MobiusError> {
+        self.0.try_send(msg)
+    }
 }
 
 impl<T: Display> Display for Slot<T> {
@@ -47,58 +138,879 @@ where
 {
     pub fn new(receiver: Receiver<T>) -> Self {
         Slot {
+            source: receiver.sender_source(),
             receiver: Arc::new(Mutex::new(receiver)),
+            panic_handler: Arc::default(),
+            inspectors: Arc::default(),
+            filter: Arc::default(),
+            filtered: Arc::default(),
+            name: None,
+            started: false,
         }
     }
 
+    /// Name the slot, e.g. `"plot-consumer"`.
+    ///
+    /// The worker thread spawned by `start` carries this name, so it shows up in
+    /// panic messages, debuggers, profilers and OS thread listings.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, slot) = create_signal_slot::<u32>();
+    /// let worker = slot.named("sample-consumer").start(|sample| println!("{sample}")).unwrap();
+    /// assert_eq!(worker.name(), Some("sample-consumer"));
+    /// ```
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// The name given with `named`, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// A new sender into this slot, usable even while the slot is running.
+    pub(crate) fn sender(&self) -> Sender<T> {
+        self.source.sender()
+    }
+
+    /// Set a callback that receives the message of any panic raised by the handler.
+    ///
+    /// A panicking handler never takes the slot down: the panic is caught, reported
+    /// here (or printed to stderr when no callback is set) and the slot keeps
+    /// processing subsequent messages. The callback may be set before or after the
+    /// slot is started and applies to both `start` and `start_async`.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, mut slot) = create_signal_slot::<u32>();
+    /// slot.on_handler_panic(|message| eprintln!("slot handler failed: {message}"));
+    /// slot.start(|value| {
+    ///     let _checked = 100u32.checked_div(value).unwrap();
+    /// }).unwrap();
+    /// signal.send(0).unwrap(); // reported, the slot keeps running
+    /// ```
+    pub fn on_handler_panic<F>(&self, callback: F)
+    where
+        F: Fn(String) + Send + Sync + 'static,
+    {
+        *self.panic_handler.lock().unwrap() = Some(Arc::new(callback));
+    }
+
+    /// Observe every message right before the handler runs, e.g. to log it.
+    ///
+    /// Inspectors compose: each call adds one, and they run in the order they were
+    /// added, followed by the handler. Like `on_handler_panic`, an inspector may be
+    /// added before or after the slot is started and applies to every `start*`
+    /// method.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, mut slot) = create_signal_slot::<String>();
+    /// slot.inspect(|cmd| println!("received {cmd:?}"));
+    /// slot.start(|cmd| println!("handling {cmd}")).unwrap();
+    /// signal.send("refresh".to_string()).unwrap();
+    /// ```
+    pub fn inspect<F>(&self, inspector: F)
+    where
+        F: Fn(&T) + Send + Sync + 'static,
+    {
+        self.inspectors.lock().unwrap().push(Box::new(inspector));
+    }
+
+    /// Drop every message failing `pred` before it reaches the inspectors and
+    /// the handler.
+    ///
+    /// Dropped messages are only counted, see `filtered_count`. Setting a filter
+    /// replaces the previous one; like `inspect`, it may be set before or after
+    /// the slot is started and applies to every `start*` method.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, mut slot) = create_signal_slot::<(String, u32)>();
+    /// slot.set_filter(|(source, _)| source == "sensor");
+    /// slot.start(|(_, reading)| println!("sensor reading {reading}")).unwrap();
+    /// signal.send(("ui".to_string(), 1)).unwrap(); // dropped
+    /// ```
+    pub fn set_filter<F>(&mut self, pred: F)
+    where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        *self.filter.lock().unwrap() = Some(Box::new(pred));
+    }
+
+    /// Number of messages dropped by the filter so far.
+    pub fn filtered_count(&self) -> usize {
+        self.filtered.load(Ordering::Relaxed)
+    }
+
+    /// Wrap `handler` so it only sees messages passing the filter, each one
+    /// after the inspectors. Returns `None` for dropped messages.
+    fn prepared<F, R>(&self, mut handler: F) -> impl FnMut(T) -> Option<R> + Send + 'static
+    where
+        F: FnMut(T) -> R + Send + 'static,
+    {
+        let inspectors = Arc::clone(&self.inspectors);
+        let filter = Arc::clone(&self.filter);
+        let filtered = Arc::clone(&self.filtered);
+        move |msg| {
+            if !accepts(&filter, &filtered, &msg) {
+                return None;
+            }
+            run_inspectors(&inspectors, &msg);
+            Some(handler(msg))
+        }
+    }
+
+    /// Returns `true` once one of the `start` methods has succeeded.
+    pub fn is_started(&self) -> bool {
+        self.started
+    }
+
+    /// How a panic of this slot's handler is described, e.g. `slot 'clicks' handler`.
+    fn handler_context(&self) -> String {
+        match &self.name {
+            Some(name) => format!("slot '{name}' handler"),
+            None => "slot handler".to_string(),
+        }
+    }
+
+    /// Mark the slot as started, failing if it already was.
+    fn claim(&mut self) -> Result<(), MobiusError> {
+        if self.started {
+            return Err(MobiusError::AlreadyStarted(self.name.clone()));
+        }
+        self.started = true;
+        Ok(())
+    }
+
+    /// Give up the slot without starting it and return its channel receiver.
+    ///
+    /// This is the escape hatch for applications that run their own event loop
+    /// instead of a worker per slot: the receiver can be polled with `try_recv`,
+    /// `recv_timeout` or `iter` alongside other sources. Inspectors, the filter and
+    /// the panic handler are not applied to messages taken this way.
+    ///
+    /// Returns the slot back if its receiver is still shared, e.g. with the worker
+    /// of a started slot or a clone of `Slot::receiver`.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, slot) = create_signal_slot::<u32>();
+    /// let receiver = slot.into_receiver().unwrap();
+    /// signal.send(7).unwrap();
+    /// assert_eq!(receiver.try_recv(), Ok(7));
+    /// ```
+    pub fn into_receiver(self) -> Result<Receiver<T>, Self> {
+        match Arc::try_unwrap(self.receiver) {
+            Ok(receiver) => Ok(receiver
+                .into_inner()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)),
+            Err(receiver) => Err(Slot { receiver, ..self }),
+        }
+    }
+
+    /// Run `handler` on the messages queued when it is called, on the calling
+    /// thread, and return how many reached it.
+    ///
+    /// Meant for shutdown: once the worker of `start_until` has stopped, messages
+    /// still queued would otherwise be lost. The filter, the inspectors and the
+    /// panic handler apply as for a started slot; filtered messages are not
+    /// counted. The queue length is taken on entry and at most that many messages
+    /// are handled, so a producer that keeps sending cannot keep it running.
+    ///
+    /// Fails with `MobiusError::AlreadyStarted` instead of waiting while a worker
+    /// still holds the receiver: join the worker first.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    /// use egui_mobius::shutdown::ShutdownCoordinator;
+    ///
+    /// let coordinator = ShutdownCoordinator::new();
+    /// let (signal, mut slot) = create_signal_slot::<String>();
+    /// let worker = slot.start_until(coordinator.token(), |cmd| println!("{cmd}")).unwrap();
+    ///
+    /// coordinator.shutdown();
+    /// worker.join().unwrap();
+    /// signal.send("save settings".to_string()).unwrap(); // queued after shutdown
+    /// assert_eq!(slot.drain(|cmd| println!("{cmd} (at exit)")), Ok(1));
+    /// ```
+    pub fn drain<F>(&mut self, handler: F) -> Result<usize, MobiusError>
+    where
+        F: FnMut(T) + Send + 'static,
+    {
+        let receiver = match self.receiver.try_lock() {
+            Ok(receiver) => receiver,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => {
+                return Err(MobiusError::AlreadyStarted(self.name.clone()));
+            }
+        };
+        let mut handler = self.prepared(handler);
+        let context = self.handler_context();
+        let mut handled = 0;
+        for _ in 0..receiver.len() {
+            let Ok(delivery) = receiver.try_recv_acked() else {
+                break;
+            };
+            let counted = |msg| {
+                let result = handler(msg);
+                handled += usize::from(result.is_some());
+                result
+            };
+            dispatch(
+                counted,
+                delivery,
+                &self.panic_handler,
+                &context,
+                self.name.as_deref(),
+            );
+        }
+        Ok(handled)
+    }
+
     /// Start the slot using a dedicated thread.
-    pub fn start<F>(&mut self, mut handler: F)
+    ///
+    /// Returns a `SlotWorker` for observing or joining the thread; it can simply be
+    /// dropped to let the slot run detached. A slot can only be started once, by
+    /// any of the `start` methods: later calls return `MobiusError::AlreadyStarted` and leave
+    /// the running handler in place.
+    pub fn start<F>(&mut self, handler: F) -> Result<SlotWorker, MobiusError>
     where
         F: FnMut(T) + Send + 'static,
     {
+        self.claim()?;
+        Ok(self.spawn(handler))
+    }
+
+    /// Start the slot like `start`, passing the handler how long each message
+    /// waited in the queue, from `send` until the worker picked it up.
+    ///
+    /// Growing wait times reveal a handler that cannot keep up with its
+    /// producers. Requires the `timed` feature, which stamps every message when
+    /// it is queued; without it no timestamps are taken. Not available on
+    /// `wasm32`.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, mut slot) = create_signal_slot::<String>();
+    /// slot.start_timed(|request, waited| {
+    ///     if waited.as_millis() > 100 {
+    ///         eprintln!("backend lagging: '{request}' queued for {waited:?}");
+    ///     }
+    /// })
+    /// .unwrap();
+    /// signal.send("refresh".to_string()).unwrap();
+    /// ```
+    #[cfg(all(feature = "timed", not(target_arch = "wasm32")))]
+    pub fn start_timed<F>(&mut self, mut handler: F) -> Result<SlotWorker, MobiusError>
+    where
+        F: FnMut(T, std::time::Duration) + Send + 'static,
+    {
+        self.claim()?;
+        // Written just before each dispatch on the worker thread, read by the handler
+        let waited = Arc::new(Mutex::new(std::time::Duration::ZERO));
+        let observed = Arc::clone(&waited);
+        Ok(self.spawn_observed(
+            move |msg| handler(msg, *observed.lock().unwrap()),
+            move |ack| *waited.lock().unwrap() = ack.waited(),
+        ))
+    }
+
+    /// Start the slot like `start` unless it is already running, in which case
+    /// `handler` is dropped and the running handler stays in place.
+    ///
+    /// This makes it safe to call from code that runs repeatedly, such as
+    /// `eframe::App::update`. Returns the worker on the call that started the slot
+    /// and `None` on every later call.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, mut slot) = create_signal_slot::<u32>();
+    /// for _frame in 0..3 {
+    ///     slot.ensure_started(|n| println!("frame event {n}"));
+    /// }
+    /// signal.send(1).unwrap(); // handled once
+    /// ```
+    pub fn ensure_started<F>(&mut self, handler: F) -> Option<SlotWorker>
+    where
+        F: FnMut(T) + Send + 'static,
+    {
+        self.start(handler).ok()
+    }
+
+    /// Start the slot like `start`, handing the handler `signal_self` so it can
+    /// queue follow-up messages onto its own slot.
+    ///
+    /// A message sent from the handler is processed after the current one
+    /// returns, which lets event-sourcing style cascades unfold step by step
+    /// instead of recursing. The worker keeps `signal_self` alive, so it only
+    /// stops once every other sender is gone *and* `signal_self` is dropped,
+    /// i.e. it runs for the life of the application.
+    ///
+    /// The handler gets a `SelfSignal` rather than `signal_self` itself: a blocking
+    /// send from the worker onto its own full queue would deadlock, so a follow-up
+    /// that does not fit fails with `MobiusError::Full`.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, mut slot) = create_signal_slot::<u32>();
+    /// slot.start_with_self(signal.clone(), |stage, myself| {
+    ///     println!("stage {stage}");
+    ///     if stage < 3 {
+    ///         myself.send(stage + 1).unwrap(); // runs once this stage is done
+    ///     }
+    /// })
+    /// .unwrap();
+    /// signal.send(1).unwrap();
+    /// ```
+    pub fn start_with_self<F>(
+        &mut self,
+        signal_self: Signal<T>,
+        mut handler: F,
+    ) -> Result<SlotWorker, MobiusError>
+    where
+        F: FnMut(T, &SelfSignal<T>) + Send + 'static,
+    {
+        let signal_self = SelfSignal(signal_self);
+        self.start(move |msg| handler(msg, &signal_self))
+    }
+
+    /// Spawn the worker of `start` without checking whether the slot was started.
+    fn spawn<F>(&mut self, handler: F) -> SlotWorker
+    where
+        F: FnMut(T) + Send + 'static,
+    {
+        self.spawn_observed(handler, |_| {})
+    }
+
+    /// Like `spawn`, calling `observe` with each message's token before the message
+    /// is dispatched.
+    fn spawn_observed<F, O>(&mut self, handler: F, mut observe: O) -> SlotWorker
+    where
+        F: FnMut(T) + Send + 'static,
+        O: FnMut(&Ack) + Send + 'static,
+    {
+        let mut handler = self.prepared(handler);
         let receiver = Arc::clone(&self.receiver);
-        thread::spawn(move || {
-            let receiver = receiver.lock().unwrap();
-            for msg in receiver.iter() {
-                handler(msg);
+        let panic_handler = Arc::clone(&self.panic_handler);
+        let context = self.handler_context();
+        let name = self.name.clone();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let handle = {
+            let mut builder = thread::Builder::new();
+            if let Some(name) = &self.name {
+                builder = builder.name(name.clone());
             }
-        });
+            let handle = builder
+                .spawn(move || {
+                    let receiver = receiver.lock().unwrap();
+                    while let Ok(delivery) = receiver.recv_acked() {
+                        observe(&delivery.1);
+                        dispatch(
+                            &mut handler,
+                            delivery,
+                            &panic_handler,
+                            &context,
+                            name.as_deref(),
+                        );
+                    }
+                })
+                .expect("failed to spawn slot thread");
+            Some(handle)
+        };
+
+        #[cfg(target_arch = "wasm32")]
+        let handle = {
+            frame::register(move || {
+                let receiver = receiver.lock().unwrap();
+                while let Ok(delivery) = receiver.try_recv_acked() {
+                    observe(&delivery.1);
+                    dispatch(
+                        &mut handler,
+                        delivery,
+                        &panic_handler,
+                        &context,
+                        name.as_deref(),
+                    );
+                }
+            });
+            None
+        };
+
+        SlotWorker {
+            name: self.name.clone(),
+            handle,
+        }
+    }
+
+    /// Start the slot like `start`, but stop once `token` is flipped by its
+    /// `ShutdownCoordinator`.
+    ///
+    /// The message being handled when shutdown is requested is finished first;
+    /// messages still queued afterwards are left unhandled. The worker also stops
+    /// when every sender is gone, exactly like `start`.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    /// use egui_mobius::shutdown::ShutdownCoordinator;
+    ///
+    /// let coordinator = ShutdownCoordinator::new();
+    /// let (signal, mut slot) = create_signal_slot::<u32>();
+    /// let worker = slot.start_until(coordinator.token(), |n| println!("{n}")).unwrap();
+    ///
+    /// coordinator.shutdown();
+    /// worker.join().unwrap();
+    /// ```
+    pub fn start_until<F>(
+        &mut self,
+        token: ShutdownToken,
+        handler: F,
+    ) -> Result<SlotWorker, MobiusError>
+    where
+        F: FnMut(T) + Send + 'static,
+    {
+        self.claim()?;
+        let mut handler = self.prepared(handler);
+        let receiver = Arc::clone(&self.receiver);
+        let panic_handler = Arc::clone(&self.panic_handler);
+        let context = self.handler_context();
+        let name = self.name.clone();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let handle = {
+            use crate::shutdown::SHUTDOWN_POLL_INTERVAL;
+            use std::sync::mpsc::RecvTimeoutError;
+
+            let mut builder = thread::Builder::new();
+            if let Some(name) = &self.name {
+                builder = builder.name(name.clone());
+            }
+            let handle = builder
+                .spawn(move || {
+                    let receiver = receiver.lock().unwrap();
+                    while !token.is_shutdown() {
+                        let delivery = match receiver.recv_timeout_acked(SHUTDOWN_POLL_INTERVAL) {
+                            Ok(delivery) => delivery,
+                            Err(RecvTimeoutError::Timeout) => continue,
+                            Err(RecvTimeoutError::Disconnected) => break,
+                        };
+                        dispatch(
+                            &mut handler,
+                            delivery,
+                            &panic_handler,
+                            &context,
+                            name.as_deref(),
+                        );
+                    }
+                })
+                .expect("failed to spawn slot thread");
+            Some(handle)
+        };
+
+        #[cfg(target_arch = "wasm32")]
+        let handle = {
+            frame::register(move || {
+                let receiver = receiver.lock().unwrap();
+                while !token.is_shutdown() {
+                    let Ok(delivery) = receiver.try_recv_acked() else {
+                        break;
+                    };
+                    dispatch(
+                        &mut handler,
+                        delivery,
+                        &panic_handler,
+                        &context,
+                        name.as_deref(),
+                    );
+                }
+            });
+            None
+        };
+
+        Ok(SlotWorker {
+            name: self.name.clone(),
+            handle,
+        })
+    }
+
+    /// Start the slot on a pool of `workers` threads sharing its channel.
+    ///
+    /// Each message is handled by whichever worker takes it first, so a CPU-heavy
+    /// handler runs on several messages in parallel. In exchange, messages are no
+    /// longer handled in the order they were sent, and handlers for consecutive
+    /// messages may overlap; use `start` when order matters. A named slot names its
+    /// workers `"<name>-0"`, `"<name>-1"` and so on.
+    ///
+    /// On `wasm32` there are no threads, so the pool degrades to a single in-frame
+    /// worker, exactly like `start`.
+    ///
+    /// Fails with `MobiusError::NoWorkers` if `workers` is zero, leaving the slot
+    /// unstarted.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    ///
+    /// let (signal, mut slot) = create_signal_slot::<Vec<u8>>();
+    /// let workers = slot.start_pool(4, |image| {
+    ///     let _checksum: u32 = image.iter().map(|&b| u32::from(b)).sum();
+    /// }).unwrap();
+    /// assert_eq!(workers.len(), 4);
+    /// signal.send(vec![1, 2, 3]).unwrap();
+    /// ```
+    pub fn start_pool<F>(
+        &mut self,
+        workers: usize,
+        handler: F,
+    ) -> Result<Vec<SlotWorker>, MobiusError>
+    where
+        F: Fn(T) + Send + Sync + 'static,
+    {
+        if workers == 0 {
+            return Err(MobiusError::NoWorkers);
+        }
+        self.claim()?;
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = workers;
+            Ok(vec![self.spawn(handler)])
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let inspectors = Arc::clone(&self.inspectors);
+            let filter = Arc::clone(&self.filter);
+            let filtered = Arc::clone(&self.filtered);
+            let handler = Arc::new(move |msg: T| {
+                if !accepts(&filter, &filtered, &msg) {
+                    return None;
+                }
+                run_inspectors(&inspectors, &msg);
+                handler(msg);
+                Some(())
+            });
+            let context = self.handler_context();
+            Ok((0..workers)
+                .map(|index| {
+                    let receiver = Arc::clone(&self.receiver);
+                    let panic_handler = Arc::clone(&self.panic_handler);
+                    let handler = Arc::clone(&handler);
+                    let context = context.clone();
+                    let slot_name = self.name.clone();
+                    let name = self.name.as_ref().map(|name| format!("{name}-{index}"));
+
+                    let mut builder = thread::Builder::new();
+                    if let Some(name) = &name {
+                        builder = builder.name(name.clone());
+                    }
+                    let handle = builder
+                        .spawn(move || {
+                            loop {
+                                // Hold the receiver only while waiting, not while handling
+                                let Ok(delivery) = receiver.lock().unwrap().recv_acked() else {
+                                    break;
+                                };
+                                dispatch(
+                                    &*handler,
+                                    delivery,
+                                    &panic_handler,
+                                    &context,
+                                    slot_name.as_deref(),
+                                );
+                            }
+                        })
+                        .expect("failed to spawn slot pool thread");
+                    SlotWorker {
+                        name,
+                        handle: Some(handle),
+                    }
+                })
+                .collect())
+        }
     }
 
     /// Start the slot using an async handler with tokio executor.
-    pub fn start_async<F, Fut>(&mut self, mut handler: F)
+    pub fn start_async<F, Fut>(&mut self, handler: F) -> Result<(), MobiusError>
     where
         F: FnMut(T) -> Fut + Send + 'static,
         Fut: std::future::Future<Output = ()> + Send + 'static,
     {
+        self.claim()?;
+        let mut handler = self.prepared(handler);
         let receiver = Arc::clone(&self.receiver);
+        let panic_handler = Arc::clone(&self.panic_handler);
+        let name = self.name.clone();
+
+        #[cfg(target_arch = "wasm32")]
+        frame::register(move || {
+            let pending: Vec<(T, Ack)> = {
+                let guard = receiver.lock().unwrap();
+                std::iter::from_fn(|| guard.try_recv_acked().ok()).collect()
+            };
+            for (msg, ack) in pending {
+                let span = trace::message_span::<T>(name.as_deref());
+                if let Some(fut) = trace::in_span(&span, || handler(msg)) {
+                    let panic_handler = Arc::clone(&panic_handler);
+                    let settled = settle(fut, ack, panic_handler, "async handler".to_string());
+                    frame::spawn(trace::instrument(settled, span));
+                }
+            }
+        });
+
+        #[cfg(not(target_arch = "wasm32"))]
         tokio::spawn(async move {
             loop {
-                let msg = {
+                let delivery = {
                     let guard = receiver.lock().unwrap();
-                    guard.try_recv().ok() // Simplified using `.ok()`
+                    guard.try_recv_acked().ok() // Simplified using `.ok()`
                 };
 
-                if let Some(msg) = msg {
-                    let fut = handler(msg);
-                    tokio::spawn(async move {
-                        if let Err(err) = AssertUnwindSafe(fut).catch_unwind().await {
-                            eprintln!("⚠️  async handler panicked: {err:?}");
-                        }
-                    });
+                if let Some((msg, ack)) = delivery {
+                    let span = trace::message_span::<T>(name.as_deref());
+                    if let Some(fut) = trace::in_span(&span, || handler(msg)) {
+                        let panic_handler = Arc::clone(&panic_handler);
+                        let settled = settle(fut, ack, panic_handler, "async handler".to_string());
+                        tokio::spawn(trace::instrument(settled, span));
+                    }
                 }
 
                 // Give other tasks a chance to run
                 tokio::task::yield_now().await;
             }
         });
+
+        Ok(())
+    }
+
+    /// Start the slot with an async handler whose futures run on `runtime`.
+    ///
+    /// A dedicated thread waits for messages and spawns the handler's future for
+    /// each one on the given runtime, so it can be called from outside any tokio
+    /// context. Futures of consecutive messages may overlap. Use this instead of
+    /// `AsyncDispatcher::attach_async` when the handler produces no response.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    /// use std::time::Duration;
+    ///
+    /// let runtime = tokio::runtime::Runtime::new().unwrap();
+    /// let (signal, mut slot) = create_signal_slot::<String>();
+    /// slot.start_async_on(runtime.handle(), |path| async move {
+    ///     tokio::time::sleep(Duration::from_millis(10)).await;
+    ///     println!("saved {path}");
+    /// }).unwrap();
+    /// signal.send("config.json".to_string()).unwrap();
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn start_async_on<F, Fut>(
+        &mut self,
+        runtime: &tokio::runtime::Handle,
+        handler: F,
+    ) -> Result<SlotWorker, MobiusError>
+    where
+        F: FnMut(T) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.claim()?;
+        let mut handler = self.prepared(handler);
+        let receiver = Arc::clone(&self.receiver);
+        let panic_handler = Arc::clone(&self.panic_handler);
+        let runtime = runtime.clone();
+        let context = match &self.name {
+            Some(name) => format!("slot '{name}' async handler"),
+            None => "async handler".to_string(),
+        };
+        let name = self.name.clone();
+
+        let mut builder = thread::Builder::new();
+        if let Some(name) = &self.name {
+            builder = builder.name(name.clone());
+        }
+        let handle = builder
+            .spawn(move || {
+                let receiver = receiver.lock().unwrap();
+                while let Ok((msg, ack)) = receiver.recv_acked() {
+                    let span = trace::message_span::<T>(name.as_deref());
+                    let handled = trace::in_span(&span, || {
+                        panic::catch_unwind(AssertUnwindSafe(|| handler(msg)))
+                    });
+                    let fut = match handled {
+                        Ok(Some(fut)) => fut,
+                        Ok(None) => continue,
+                        Err(payload) => {
+                            report_panic(&panic_handler, payload, &context);
+                            continue;
+                        }
+                    };
+                    let panic_handler = Arc::clone(&panic_handler);
+                    let settled = settle(fut, ack, panic_handler, context.clone());
+                    runtime.spawn(trace::instrument(settled, span));
+                }
+            })
+            .expect("failed to spawn slot thread");
+
+        Ok(SlotWorker {
+            name: self.name.clone(),
+            handle: Some(handle),
+        })
+    }
+}
+
+/// Process the messages queued for every started slot.
+///
+/// On `wasm32` targets slots have no thread of their own, so call this once per frame
+/// (e.g. at the top of `eframe::App::update`) to run their handlers in-frame. On native
+/// targets slots run on their own threads and this does nothing.
+pub fn process_pending() {
+    #[cfg(target_arch = "wasm32")]
+    frame::run();
+}
+
+/// Frame-driven slot backend for targets without threads.
+#[cfg(target_arch = "wasm32")]
+mod frame {
+    use futures::executor::{LocalPool, LocalSpawner};
+    use futures::task::LocalSpawnExt;
+    use std::cell::RefCell;
+    use std::future::Future;
+
+    thread_local! {
+        static PUMPS: RefCell<Vec<Box<dyn FnMut()>>> = RefCell::new(Vec::new());
+        static POOL: RefCell<LocalPool> = RefCell::new(LocalPool::new());
+        static SPAWNER: LocalSpawner = POOL.with(|pool| pool.borrow().spawner());
+    }
+
+    /// Register a closure draining one slot's queue.
+    pub(super) fn register(pump: impl FnMut() + 'static) {
+        PUMPS.with(|pumps| pumps.borrow_mut().push(Box::new(pump)));
+    }
+
+    /// Queue an async handler invocation on the frame executor.
+    pub(super) fn spawn(fut: impl Future<Output = ()> + 'static) {
+        SPAWNER.with(|spawner| {
+            let _ = spawner.spawn_local(fut);
+        });
+    }
+
+    pub(super) fn run() {
+        // Take the pumps out so a handler can start another slot while they run
+        let mut pumps = PUMPS.with(|pumps| std::mem::take(&mut *pumps.borrow_mut()));
+        for pump in &mut pumps {
+            pump();
+        }
+        PUMPS.with(|registered| {
+            let mut registered = registered.borrow_mut();
+            let started_meanwhile = std::mem::replace(&mut *registered, pumps);
+            registered.extend(started_meanwhile);
+        });
+        POOL.with(|pool| pool.borrow_mut().run_until_stalled());
+    }
+}
+
+/// Run a prepared handler on one message of the slot called `name`, confirming its
+/// ack once the handler returns. Filtered messages and panicking handlers leave the
+/// ack unconfirmed.
+fn dispatch<T>(
+    handler: impl FnOnce(T) -> Option<()>,
+    (msg, ack): (T, Ack),
+    panic_handler: &Mutex<Option<PanicHandler>>,
+    context: &str,
+    name: Option<&str>,
+) {
+    let span = trace::message_span::<T>(name);
+    let handled = trace::in_span(&span, || {
+        panic::catch_unwind(AssertUnwindSafe(|| handler(msg)))
+    });
+    match handled {
+        Ok(Some(())) => ack.confirm(),
+        Ok(None) => {}
+        Err(payload) => report_panic(panic_handler, payload, context),
+    }
+}
+
+/// Await the future of an async handler, confirming its ack once it completes.
+async fn settle<Fut>(
+    fut: Fut,
+    ack: Ack,
+    panic_handler: Arc<Mutex<Option<PanicHandler>>>,
+    context: String,
+) where
+    Fut: std::future::Future<Output = ()>,
+{
+    match AssertUnwindSafe(fut).catch_unwind().await {
+        Ok(()) => ack.confirm(),
+        Err(payload) => report_panic(&panic_handler, payload, &context),
+    }
+}
+
+/// Returns `true` if `msg` passes the slot's filter, counting it otherwise.
+fn accepts<T>(filter: &Mutex<Option<Filter<T>>>, filtered: &AtomicUsize, msg: &T) -> bool {
+    let filter = filter
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let accepted = filter.as_ref().is_none_or(|pred| pred(msg));
+    if !accepted {
+        filtered.fetch_add(1, Ordering::Relaxed);
+    }
+    accepted
+}
+
+fn run_inspectors<T>(inspectors: &Mutex<Vec<Inspector<T>>>, msg: &T) {
+    let inspectors = inspectors
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    for inspect in inspectors.iter() {
+        inspect(msg);
+    }
+}
+
+/// Forward a caught panic to the slot's panic callback, or print it to stderr.
+fn report_panic(
+    panic_handler: &Mutex<Option<PanicHandler>>,
+    payload: Box<dyn Any + Send>,
+    context: &str,
+) {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "non-string panic payload".to_string());
+
+    let callback = panic_handler.lock().ok().and_then(|cb| cb.clone());
+    match callback {
+        Some(callback) => callback(message),
+        None => eprintln!("⚠️  {context} panicked: {message}"),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::{Arc, Mutex, mpsc};
+    use crate::channel;
+    use std::sync::{Arc, Mutex};
     use std::thread;
     use std::time::Duration;
     use tokio::sync::Notify;
@@ -109,9 +1021,86 @@ mod tests {
         Sub(u32),
     }
 
+    #[cfg(feature = "timed")]
+    #[test]
+    fn test_start_timed_reports_queue_wait() {
+        let (signal, mut slot) = crate::factory::create_signal_slot::<&str>();
+        signal.send("early").unwrap();
+        thread::sleep(Duration::from_millis(60));
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        slot.start_timed(move |msg, waited| tx.send((msg, waited)).unwrap())
+            .unwrap();
+        let (msg, waited) = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(msg, "early");
+        assert!(waited >= Duration::from_millis(60));
+
+        signal.send("prompt").unwrap();
+        let (msg, waited) = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(msg, "prompt");
+        assert!(waited < Duration::from_millis(60));
+    }
+
+    #[test]
+    fn test_drain_handles_queued_messages_before_returning() {
+        let (signal, mut slot) = crate::factory::create_signal_slot::<u32>();
+        slot.set_filter(|n| n % 10 != 0);
+        for n in [1, 2, 10, 3] {
+            signal.send(n).unwrap();
+        }
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        assert_eq!(slot.drain(move |n| sink.lock().unwrap().push(n)), Ok(3));
+        assert_eq!(*seen.lock().unwrap(), vec![1, 2, 3]);
+        assert_eq!(slot.filtered_count(), 1);
+        assert_eq!(slot.drain(|_| unreachable!("queue is empty")), Ok(0));
+
+        // Messages left behind by a stopped `start_until` worker
+        let coordinator = crate::shutdown::ShutdownCoordinator::new();
+        let worker = slot.start_until(coordinator.token(), |_| {}).unwrap();
+        coordinator.shutdown();
+        worker.join().unwrap();
+        signal.send(4).unwrap();
+        signal.send(5).unwrap();
+        let sink = seen.clone();
+        assert_eq!(slot.drain(move |n| sink.lock().unwrap().push(n)), Ok(2));
+        assert_eq!(*seen.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_drain_stops_at_the_messages_queued_on_entry() {
+        let (signal, mut slot) = crate::factory::create_signal_slot::<u32>();
+        signal.send(1).unwrap();
+        signal.send(2).unwrap();
+
+        // Every handled message queues another one
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let requeue = signal.clone();
+        let drained = slot.drain(move |n| {
+            sink.lock().unwrap().push(n);
+            requeue.send(n + 10).unwrap();
+        });
+        assert_eq!(drained, Ok(2));
+        assert_eq!(*seen.lock().unwrap(), vec![1, 2]);
+        assert_eq!(slot.receiver.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_drain_refuses_while_a_worker_runs() {
+        let (signal, mut slot) = crate::factory::create_signal_slot::<u32>();
+        let _worker = slot.start(|_| {}).unwrap();
+        // Give the worker time to take hold of the receiver
+        thread::sleep(Duration::from_millis(50));
+
+        assert_eq!(slot.drain(|_| {}), Err(MobiusError::AlreadyStarted(None)));
+        drop(signal);
+    }
+
     #[test]
     fn test_threaded_slot() {
-        let (sender, receiver) = mpsc::channel();
+        let (sender, receiver) = channel::channel();
         let mut slot = Slot::new(receiver);
         let result = Arc::new(Mutex::new(0));
         let result_clone = Arc::clone(&result);
@@ -122,7 +1111,8 @@ mod tests {
                 Event::Add(x) => *val += x,
                 Event::Sub(x) => *val -= x,
             }
-        });
+        })
+        .unwrap();
 
         sender.send(Event::Add(5)).unwrap();
         sender.send(Event::Sub(2)).unwrap();
@@ -132,9 +1122,273 @@ mod tests {
         assert_eq!(final_val, 3);
     }
 
+    #[test]
+    fn test_priority_messages_dispatched_first() {
+        let (signal, mut slot) = crate::factory::create_signal_slot::<Event>();
+        signal.send(Event::Add(1)).unwrap();
+        signal.send_priority(Event::Sub(10)).unwrap();
+        signal.send(Event::Add(2)).unwrap();
+        signal.send_priority(Event::Sub(20)).unwrap();
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+        slot.start(move |event: Event| {
+            seen_clone.lock().unwrap().push(event);
+        })
+        .unwrap();
+        thread::sleep(Duration::from_millis(100));
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![Event::Sub(10), Event::Sub(20), Event::Add(1), Event::Add(2)]
+        );
+    }
+
+    #[test]
+    fn test_inspectors_run_before_handler() {
+        let (signal, mut slot) = crate::factory::create_signal_slot::<u32>();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        for tag in ["log", "trace"] {
+            let seen = Arc::clone(&seen);
+            slot.inspect(move |n| seen.lock().unwrap().push(format!("{tag} {n}")));
+        }
+        let seen_clone = Arc::clone(&seen);
+        slot.start(move |n| seen_clone.lock().unwrap().push(format!("handle {n}")))
+            .unwrap();
+
+        signal.send(1).unwrap();
+        signal.send(2).unwrap();
+        thread::sleep(Duration::from_millis(100));
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                "log 1", "trace 1", "handle 1", "log 2", "trace 2", "handle 2"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pool_handles_messages_concurrently() {
+        let (signal, slot) = crate::factory::create_signal_slot::<u32>();
+        let handled = Arc::new(Mutex::new(Vec::new()));
+        let handled_clone = Arc::clone(&handled);
+        let workers = slot
+            .named("pool")
+            .start_pool(4, move |n| {
+                thread::sleep(Duration::from_millis(100));
+                handled_clone.lock().unwrap().push(n);
+            })
+            .unwrap();
+        assert_eq!(workers[3].name(), Some("pool-3"));
+
+        let started = std::time::Instant::now();
+        for n in 0..4 {
+            signal.send(n).unwrap();
+        }
+        while handled.lock().unwrap().len() < 4 {
+            assert!(started.elapsed() < Duration::from_secs(5));
+            thread::sleep(Duration::from_millis(5));
+        }
+        // Serially the four handlers would take at least 400ms
+        assert!(started.elapsed() < Duration::from_millis(300));
+
+        let mut handled = handled.lock().unwrap().clone();
+        handled.sort();
+        assert_eq!(handled, vec![0, 1, 2, 3]);
+
+        drop(signal);
+        for worker in workers {
+            worker.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_empty_pool_is_an_error() {
+        let (_signal, mut slot) = crate::factory::create_signal_slot::<u32>();
+        assert_eq!(
+            slot.start_pool(0, |_| {}).unwrap_err(),
+            MobiusError::NoWorkers
+        );
+        assert!(!slot.is_started());
+        assert_eq!(
+            slot.start_pool(1, |_| {}).map(|workers| workers.len()),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn test_handler_panic_is_reported_and_slot_survives() {
+        let (sender, receiver) = channel::channel();
+        let mut slot = Slot::new(receiver);
+        let result = Arc::new(Mutex::new(0));
+        let result_clone = Arc::clone(&result);
+        let panics = Arc::new(Mutex::new(Vec::new()));
+        let panics_clone = Arc::clone(&panics);
+
+        slot.on_handler_panic(move |message| panics_clone.lock().unwrap().push(message));
+        slot.start(move |event: Event| {
+            if let Event::Sub(0) = event {
+                panic!("cannot subtract zero");
+            }
+            if let Event::Add(x) = event {
+                *result_clone.lock().unwrap() += x;
+            }
+        })
+        .unwrap();
+
+        sender.send(Event::Sub(0)).unwrap();
+        sender.send(Event::Add(4)).unwrap();
+        thread::sleep(Duration::from_millis(100));
+
+        assert_eq!(*result.lock().unwrap(), 4);
+        assert_eq!(*panics.lock().unwrap(), vec!["cannot subtract zero"]);
+    }
+
+    #[test]
+    fn test_named_slot_worker_thread() {
+        let (sender, receiver) = channel::channel();
+        let mut slot = Slot::new(receiver).named("event-consumer");
+        let thread_name = Arc::new(Mutex::new(None));
+        let thread_name_clone = Arc::clone(&thread_name);
+
+        let worker = slot
+            .start(move |_event: Event| {
+                *thread_name_clone.lock().unwrap() = thread::current().name().map(str::to_string);
+            })
+            .unwrap();
+        assert_eq!(worker.name(), Some("event-consumer"));
+        assert_eq!(
+            worker.thread().and_then(|handle| handle.thread().name()),
+            Some("event-consumer")
+        );
+
+        sender.send(Event::Add(1)).unwrap();
+        drop(sender);
+        worker.join().unwrap();
+        assert_eq!(
+            thread_name.lock().unwrap().as_deref(),
+            Some("event-consumer")
+        );
+    }
+
+    #[test]
+    fn test_second_start_is_rejected() {
+        let (sender, receiver) = channel::channel();
+        let mut slot = Slot::new(receiver).named("once");
+        let (tx, rx) = std::sync::mpsc::channel();
+        let worker = slot
+            .start(move |event: Event| tx.send(event).unwrap())
+            .unwrap();
+        assert!(slot.is_started());
+
+        let second = slot.start(|_| panic!("second handler must not run"));
+        assert_eq!(
+            second.unwrap_err(),
+            MobiusError::AlreadyStarted(Some("once".to_string()))
+        );
+        assert!(slot.start_pool(2, |_| {}).is_err());
+
+        sender.send(Event::Add(1)).unwrap();
+        assert_eq!(rx.recv_timeout(Duration::from_secs(1)), Ok(Event::Add(1)));
+        drop(sender);
+        worker.join().unwrap();
+    }
+
+    #[test]
+    fn test_ensure_started_installs_handler_once() {
+        let (sender, receiver) = channel::channel();
+        let mut slot = Slot::new(receiver);
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let mut workers = Vec::new();
+        for _frame in 0..5 {
+            let calls = Arc::clone(&calls);
+            workers.extend(slot.ensure_started(move |_event: Event| {
+                calls.fetch_add(1, Ordering::SeqCst);
+            }));
+        }
+        assert_eq!(workers.len(), 1);
+
+        sender.send(Event::Add(1)).unwrap();
+        drop(sender);
+        workers.pop().unwrap().join().unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_into_receiver_without_starting() {
+        let (sender, receiver) = channel::channel();
+        let slot = Slot::new(receiver);
+        sender.send(Event::Add(1)).unwrap();
+
+        let receiver = slot.into_receiver().unwrap();
+        sender.send(Event::Add(2)).unwrap();
+        assert!(matches!(receiver.recv(), Ok(Event::Add(1))));
+        assert!(matches!(receiver.recv(), Ok(Event::Add(2))));
+
+        // A running worker still holds the receiver
+        let (_sender, receiver) = channel::channel::<Event>();
+        let mut slot = Slot::new(receiver).named("busy");
+        slot.start(|_| {}).unwrap();
+        let Err(slot) = slot.into_receiver() else {
+            panic!("the worker's receiver was handed out");
+        };
+        assert!(slot.is_started());
+        assert_eq!(slot.name(), Some("busy"));
+    }
+
+    #[test]
+    fn test_start_with_self_processes_follow_ups_in_order() {
+        let (sender, receiver) = channel::channel();
+        let signal = Signal::new(sender);
+        let mut slot = Slot::new(receiver);
+        let (seen_tx, seen_rx) = std::sync::mpsc::channel();
+
+        slot.start_with_self(signal.clone(), move |stage: u32, myself| {
+            if stage < 3 {
+                myself.send(stage + 1).unwrap();
+            }
+            // The follow-up is queued, not handled before this one returns
+            seen_tx.send(stage).unwrap();
+        })
+        .unwrap();
+        signal.send(1).unwrap();
+
+        let stages: Vec<u32> = (0..3)
+            .map(|_| seen_rx.recv_timeout(Duration::from_secs(1)).unwrap())
+            .collect();
+        assert_eq!(stages, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_start_with_self_reports_a_full_queue() {
+        let (signal, mut slot) = crate::factory::create_signal_slot_with_capacity::<u32>(2);
+        let (seen_tx, seen_rx) = std::sync::mpsc::channel();
+
+        let _worker = slot
+            .start_with_self(signal.clone(), move |n: u32, myself| {
+                if n == 0 {
+                    // Fill the queue, then try to queue one more follow-up
+                    let filled = [myself.send(1), myself.send(2)];
+                    seen_tx.send(filled.iter().all(Result::is_ok)).unwrap();
+                    seen_tx
+                        .send(myself.send(3) == Err(MobiusError::Full))
+                        .unwrap();
+                }
+            })
+            .unwrap();
+        signal.send(0).unwrap();
+
+        // A blocking send would hang the worker before either report
+        for _ in 0..2 {
+            assert!(seen_rx.recv_timeout(Duration::from_secs(1)).unwrap());
+        }
+    }
+
     #[tokio::test]
     async fn test_async_slot_tokio_single_message() {
-        let (sender, receiver) = mpsc::channel();
+        let (sender, receiver) = channel::channel();
         let mut slot = Slot::new(receiver);
         let result = Arc::new(Mutex::new(0));
         let result_clone = Arc::clone(&result);
@@ -152,7 +1406,8 @@ mod tests {
                 }
                 notify_clone.notify_one();
             }
-        });
+        })
+        .unwrap();
 
         sender.send(Event::Add(10)).unwrap();
         notify.notified().await;
@@ -163,7 +1418,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_async_slot_tokio_multiple_messages() {
-        let (sender, receiver) = mpsc::channel();
+        let (sender, receiver) = channel::channel();
         let mut slot = Slot::new(receiver);
         let result = Arc::new(Mutex::new(100));
         let result_clone = Arc::clone(&result);
@@ -177,7 +1432,8 @@ mod tests {
                     Event::Sub(x) => *val -= x,
                 }
             }
-        });
+        })
+        .unwrap();
 
         sender.send(Event::Add(50)).unwrap();
         sender.send(Event::Sub(20)).unwrap();
@@ -189,19 +1445,20 @@ mod tests {
 
     #[tokio::test]
     async fn test_async_slot_empty_queue() {
-        let (_sender, receiver) = mpsc::channel();
+        let (_sender, receiver) = channel::channel();
         let mut slot = Slot::new(receiver);
 
         slot.start_async(move |_event: Event| async move {
             panic!("Should not be called");
-        });
+        })
+        .unwrap();
 
         tokio::time::sleep(Duration::from_millis(50)).await;
     }
 
     #[tokio::test]
     async fn test_async_slot_handler_panics() {
-        let (sender, receiver) = mpsc::channel();
+        let (sender, receiver) = channel::channel();
         let mut slot = Slot::new(receiver);
         let result = Arc::new(Mutex::new(0));
         let result_clone = Arc::clone(&result);
@@ -215,7 +1472,8 @@ mod tests {
                 let mut val = result_clone.lock().unwrap();
                 *val += 1;
             }
-        });
+        })
+        .unwrap();
 
         let _ = sender.send(Event::Add(999));
         let _ = sender.send(Event::Add(1));
@@ -227,8 +1485,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_multiple_async_slots_run_independently() {
-        let (sender1, receiver1) = mpsc::channel();
-        let (sender2, receiver2) = mpsc::channel();
+        let (sender1, receiver1) = channel::channel();
+        let (sender2, receiver2) = channel::channel();
         let mut slot1 = Slot::new(receiver1);
         let mut slot2 = Slot::new(receiver2);
 
@@ -238,25 +1496,29 @@ mod tests {
         let res1_clone = Arc::clone(&res1);
         let res2_clone = Arc::clone(&res2);
 
-        slot1.start_async(move |event| {
-            let res1 = Arc::clone(&res1_clone);
-            async move {
-                if let Event::Add(x) = event {
-                    let mut val = res1.lock().unwrap();
-                    *val += x;
+        slot1
+            .start_async(move |event| {
+                let res1 = Arc::clone(&res1_clone);
+                async move {
+                    if let Event::Add(x) = event {
+                        let mut val = res1.lock().unwrap();
+                        *val += x;
+                    }
                 }
-            }
-        });
+            })
+            .unwrap();
 
-        slot2.start_async(move |event| {
-            let res2 = Arc::clone(&res2_clone);
-            async move {
-                if let Event::Sub(x) = event {
-                    let mut val = res2.lock().unwrap();
-                    *val += x;
+        slot2
+            .start_async(move |event| {
+                let res2 = Arc::clone(&res2_clone);
+                async move {
+                    if let Event::Sub(x) = event {
+                        let mut val = res2.lock().unwrap();
+                        *val += x;
+                    }
                 }
-            }
-        });
+            })
+            .unwrap();
 
         sender1.send(Event::Add(3)).unwrap();
         sender2.send(Event::Sub(7)).unwrap();
@@ -265,4 +1527,101 @@ mod tests {
         assert_eq!(*res1.lock().unwrap(), 3);
         assert_eq!(*res2.lock().unwrap(), 7);
     }
+
+    #[test]
+    fn test_async_slot_on_runtime_handle() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let (sender, receiver) = channel::channel();
+        let mut slot = Slot::new(receiver);
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        // Started outside any tokio context
+        let worker = slot
+            .start_async_on(runtime.handle(), move |event: Event| {
+                let tx = tx.clone();
+                async move {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    tx.send(event).unwrap();
+                }
+            })
+            .unwrap();
+
+        sender.send(Event::Add(1)).unwrap();
+        sender.send(Event::Sub(2)).unwrap();
+        let mut handled: Vec<_> = (0..2)
+            .map(|_| rx.recv_timeout(Duration::from_secs(1)).unwrap())
+            .collect();
+        handled.sort_by_key(|event| matches!(event, Event::Sub(_)));
+        assert_eq!(handled, vec![Event::Add(1), Event::Sub(2)]);
+
+        drop(sender);
+        worker.join().unwrap();
+    }
+
+    #[test]
+    fn test_filter_drops_rejected_messages() {
+        let (signal, mut slot) = crate::factory::create_signal_slot::<Event>();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let inspected = Arc::new(Mutex::new(0));
+        let inspected_clone = Arc::clone(&inspected);
+        slot.inspect(move |_| *inspected_clone.lock().unwrap() += 1);
+        slot.set_filter(|event| matches!(event, Event::Add(_)));
+        let seen_clone = Arc::clone(&seen);
+        let worker = slot
+            .start(move |event| seen_clone.lock().unwrap().push(event))
+            .unwrap();
+
+        for event in [Event::Add(1), Event::Sub(2), Event::Add(3), Event::Sub(4)] {
+            signal.send(event).unwrap();
+        }
+        drop(signal);
+        worker.join().unwrap();
+
+        assert_eq!(*seen.lock().unwrap(), vec![Event::Add(1), Event::Add(3)]);
+        assert_eq!(*inspected.lock().unwrap(), 2);
+        assert_eq!(slot.filtered_count(), 2);
+    }
+
+    #[test]
+    fn test_send_ack_resolves_after_handler() {
+        let (signal, mut slot) = crate::factory::create_signal_slot::<Event>();
+        let result = Arc::new(Mutex::new(0));
+        let result_clone = Arc::clone(&result);
+        slot.set_filter(|event| !matches!(event, Event::Sub(0)));
+        slot.start(move |event| {
+            thread::sleep(Duration::from_millis(50));
+            if let Event::Add(x) = event {
+                *result_clone.lock().unwrap() += x;
+            }
+        })
+        .unwrap();
+
+        let processed = signal.send_ack(Event::Add(7));
+        processed.blocking_recv().unwrap();
+        // The handler has returned, so its effect is already visible
+        assert_eq!(*result.lock().unwrap(), 7);
+
+        // A message that never reaches the handler is not confirmed
+        assert!(signal.send_ack(Event::Sub(0)).blocking_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_send_ack_awaits_async_handler() {
+        let (signal, mut slot) = crate::factory::create_signal_slot::<Event>();
+        let result = Arc::new(Mutex::new(0));
+        let result_clone = Arc::clone(&result);
+        slot.start_async(move |event| {
+            let result = Arc::clone(&result_clone);
+            async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                if let Event::Add(x) = event {
+                    *result.lock().unwrap() += x;
+                }
+            }
+        })
+        .unwrap();
+
+        signal.send_ack(Event::Add(3)).await.unwrap();
+        assert_eq!(*result.lock().unwrap(), 3);
+    }
 }
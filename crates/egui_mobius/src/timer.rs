@@ -0,0 +1,177 @@
+//! Periodic timer signals, see `factory::create_interval_signal`.
+//!
+//! A timer thread sends the current `Instant` on a signal once per interval, replacing
+//! the hand-rolled `loop { send; sleep }` threads that drive clocks and plots. The
+//! returned `TimerHandle` pauses and resumes the ticks; dropping it ends the thread.
+
+use crate::signals::Signal;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Run state shared between a `TimerHandle` and its thread.
+#[derive(Debug, Default)]
+struct TimerState {
+    running: bool,
+    /// Bumped by `stop` and `restart`, so the thread starts a fresh interval.
+    generation: u64,
+    /// Set when the handle is dropped.
+    closed: bool,
+}
+
+#[derive(Debug, Default)]
+struct TimerShared {
+    state: Mutex<TimerState>,
+    wake: Condvar,
+}
+
+impl TimerShared {
+    fn lock(&self) -> MutexGuard<'_, TimerState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Controls the timer behind a signal created by `create_interval_signal`.
+///
+/// The timer runs until the handle is dropped or the slot is gone.
+#[derive(Debug)]
+pub struct TimerHandle {
+    shared: Arc<TimerShared>,
+    interval: Duration,
+}
+
+impl TimerHandle {
+    /// Starts a timer thread sending on `signal` every `interval`.
+    pub(crate) fn spawn(signal: Signal<Instant>, interval: Duration) -> Self {
+        assert!(!interval.is_zero(), "a timer interval must be non-zero");
+        let shared = Arc::new(TimerShared::default());
+        shared.lock().running = true;
+
+        let timer = Arc::clone(&shared);
+        thread::Builder::new()
+            .name("egui_mobius-timer".to_string())
+            .spawn(move || run_timer(&timer, &signal, interval))
+            .expect("failed to spawn timer thread");
+
+        Self { shared, interval }
+    }
+
+    /// The time between two ticks.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns `true` unless the timer is stopped.
+    pub fn is_running(&self) -> bool {
+        self.shared.lock().running
+    }
+
+    /// Pause the ticks; no tick is sent until `restart`.
+    pub fn stop(&self) {
+        self.update(|state| state.running = false);
+    }
+
+    /// Resume a stopped timer, or restart a running one: the next tick follows
+    /// one full interval from now.
+    pub fn restart(&self) {
+        self.update(|state| state.running = true);
+    }
+
+    fn update(&self, change: impl FnOnce(&mut TimerState)) {
+        let mut state = self.shared.lock();
+        change(&mut state);
+        state.generation += 1;
+        self.shared.wake.notify_all();
+    }
+}
+
+impl Drop for TimerHandle {
+    fn drop(&mut self) {
+        self.shared.lock().closed = true;
+        self.shared.wake.notify_all();
+    }
+}
+
+/// Body of the timer thread: sends a tick whenever an interval has elapsed while
+/// running, until the handle is dropped or the slot disconnects.
+fn run_timer(shared: &TimerShared, signal: &Signal<Instant>, interval: Duration) {
+    let mut seen_generation = 0;
+    let mut next = Instant::now() + interval;
+    loop {
+        let mut state = shared.lock();
+        loop {
+            if state.closed {
+                return;
+            }
+            if state.generation != seen_generation {
+                seen_generation = state.generation;
+                next = Instant::now() + interval;
+            }
+            if !state.running {
+                state = shared
+                    .wake
+                    .wait(state)
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+                continue;
+            }
+            let now = Instant::now();
+            if now >= next {
+                break;
+            }
+            state = shared
+                .wake
+                .wait_timeout(state, next - now)
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .0;
+        }
+        drop(state);
+
+        let now = Instant::now();
+        if signal.send(now).is_err() {
+            return;
+        }
+        // Keep the cadence, but skip ticks missed while the thread was descheduled
+        next += interval;
+        if next <= now {
+            next = now + interval;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factory::create_interval_signal;
+    use std::sync::mpsc;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_interval_signal_ticks_and_stops() {
+        let (_signal, mut slot, timer) = create_interval_signal(Duration::from_millis(20));
+        let (tx, rx) = mpsc::channel();
+        slot.start(move |tick: Instant| {
+            let _ = tx.send(tick);
+        })
+        .unwrap();
+
+        let ticks: Vec<Instant> = (0..3)
+            .map(|_| rx.recv_timeout(Duration::from_secs(1)).unwrap())
+            .collect();
+        assert!(ticks[2] - ticks[0] >= Duration::from_millis(30));
+        assert!(timer.is_running());
+
+        timer.stop();
+        assert!(!timer.is_running());
+        // At most one tick was already on its way when stopping
+        while rx.recv_timeout(Duration::from_millis(60)).is_ok() {}
+        assert!(rx.recv_timeout(Duration::from_millis(80)).is_err());
+
+        timer.restart();
+        assert!(rx.recv_timeout(Duration::from_secs(1)).is_ok());
+
+        drop(timer);
+        while rx.recv_timeout(Duration::from_millis(60)).is_ok() {}
+        assert!(rx.recv_timeout(Duration::from_millis(80)).is_err());
+    }
+}
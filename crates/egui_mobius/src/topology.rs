@@ -0,0 +1,173 @@
+//! A record of the signal/slot wiring, for generated documentation.
+//!
+//! The application owns a `TopologyRegistry` and creates its pairs with
+//! `factory::create_named_signal_slot`, which registers each one together with a
+//! description and its message type. Once the application is wired up,
+//! `export_dot` on the registry renders every connection as a Graphviz graph,
+//! turning the implicit wiring into a diagram. The registry is an ordinary value
+//! rather than a process-wide static, so independent applications or tests do
+//! not mix their connections. With the `serde` feature,
+//! `Connection` also serializes, e.g. to JSON.
+//!
+//! # Example
+//! ```rust
+//! use egui_mobius::factory::create_named_signal_slot;
+//! use egui_mobius::topology::TopologyRegistry;
+//!
+//! let topology = TopologyRegistry::new();
+//! let (signal, slot) = create_named_signal_slot::<String>(
+//!     &topology,
+//!     "log_events",
+//!     "Log lines sent from the UI to the file writer",
+//! );
+//!
+//! let dot = topology.export_dot();
+//! assert!(dot.contains("\"log_events\""));
+//! // std::fs::write("wiring.dot", dot) and render with `dot -Tsvg wiring.dot`
+//! ```
+
+use std::fmt::Write;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// One signal/slot pair registered by `create_named_signal_slot`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Connection {
+    /// Name of the pair, also given to its slot.
+    pub name: String,
+    /// What travels over the connection and why.
+    pub description: String,
+    /// Rust type of the messages, as reported by `std::any::type_name`.
+    pub message_type: &'static str,
+}
+
+/// A collection of named signal/slot connections.
+///
+/// Clones share the same connections, so the registry can be handed to every
+/// part of the application that creates pairs. Separate registries, e.g. one per
+/// test, do not see each other's connections.
+#[derive(Debug, Clone, Default)]
+pub struct TopologyRegistry {
+    /// The connections registered so far, in creation order.
+    connections: Arc<Mutex<Vec<Connection>>>,
+}
+
+impl TopologyRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a connection; called by `create_named_signal_slot`.
+    ///
+    /// Registering a name again replaces the earlier entry, so code that
+    /// re-creates its wiring does not produce duplicates.
+    pub fn register(&self, connection: Connection) {
+        let mut connections = self.lock();
+        match connections.iter_mut().find(|c| c.name == connection.name) {
+            Some(existing) => *existing = connection,
+            None => connections.push(connection),
+        }
+    }
+
+    /// Every registered connection, in creation order.
+    pub fn connections(&self) -> Vec<Connection> {
+        self.lock().clone()
+    }
+
+    /// Forget every registered connection.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Render the topology as a Graphviz `digraph`.
+    ///
+    /// Each connection becomes an edge from its signal to its slot, labeled
+    /// with the message type; the description is attached as a tooltip.
+    pub fn export_dot(&self) -> String {
+        let mut dot = String::from("digraph egui_mobius {\n    rankdir=LR;\n");
+        for connection in self.lock().iter() {
+            let name = escape(&connection.name);
+            let _ = writeln!(
+                dot,
+                "    \"{name}\" [shape=box, label=\"{name}\\nsignal\"];\n    \
+                 \"{name} slot\" [shape=ellipse, label=\"{name}\\nslot\"];\n    \
+                 \"{name}\" -> \"{name} slot\" [label=\"{}\", tooltip=\"{}\"];",
+                escape(&short_type_name(connection.message_type)),
+                escape(&connection.description),
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Connection>> {
+        self.connections
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Strips module paths, e.g. `Vec<alloc::string::String>` becomes `Vec<String>`.
+fn short_type_name(full: &str) -> String {
+    full.split_inclusive(['<', '>', ',', ' ', '(', ')', '[', ']', ';', '&'])
+        .map(|part| part.rsplit("::").next().unwrap_or(part))
+        .collect()
+}
+
+/// Escape a string for use inside a quoted DOT identifier.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::create_named_signal_slot;
+
+    #[test]
+    fn test_named_pairs_are_exported_as_dot() {
+        let topology = TopologyRegistry::new();
+        let (signal, slot) =
+            create_named_signal_slot::<String>(&topology, "log", "Lines for the \"log\" view");
+        assert_eq!(slot.name(), Some("log"));
+        let _ = create_named_signal_slot::<u32>(&topology, "count", "Counter updates");
+        // Re-creating a pair replaces its entry
+        let _ = create_named_signal_slot::<u32>(&topology, "count", "Counter changes");
+
+        assert_eq!(
+            topology.connections(),
+            vec![
+                Connection {
+                    name: "log".to_string(),
+                    description: "Lines for the \"log\" view".to_string(),
+                    message_type: std::any::type_name::<String>(),
+                },
+                Connection {
+                    name: "count".to_string(),
+                    description: "Counter changes".to_string(),
+                    message_type: "u32",
+                },
+            ]
+        );
+
+        let dot = topology.export_dot();
+        assert!(dot.starts_with("digraph egui_mobius {"));
+        assert!(dot.contains(
+            "\"log\" -> \"log slot\" [label=\"String\", tooltip=\"Lines for the \\\"log\\\" view\"];"
+        ));
+        assert!(dot.trim_end().ends_with('}'));
+
+        // Other registries are unaffected
+        let other = TopologyRegistry::new();
+        assert!(other.connections().is_empty());
+        topology.clear();
+        assert!(topology.connections().is_empty());
+
+        signal.send("still a normal pair".to_string()).unwrap();
+        assert_eq!(
+            slot.receiver.lock().unwrap().try_recv(),
+            Ok("still a normal pair".to_string())
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! Optional `tracing` instrumentation of slots and async dispatchers.
+//!
+//! With the `tracing` feature enabled, every message a slot handles runs inside an
+//! `INFO` span named `slot`, and every task spawned by an `AsyncDispatcher` inside
+//! one named `async_task`. Both carry the slot (or channel) name in `name` and the
+//! message type in `message`, so a subscriber such as `tracing-subscriber` can show
+//! where time goes per slot. Without the feature these helpers compile to nothing.
+
+/// The span type used by the helpers below, a zero-sized stand-in without the feature.
+#[cfg(feature = "tracing")]
+pub(crate) use tracing::Span;
+
+#[cfg(not(feature = "tracing"))]
+#[derive(Clone)]
+pub(crate) struct Span;
+
+/// Span covering one message of type `T` handled by the slot called `name`.
+#[cfg_attr(not(feature = "tracing"), allow(clippy::extra_unused_type_parameters))]
+pub(crate) fn message_span<T>(name: Option<&str>) -> Span {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::info_span!(
+            "slot",
+            name = name.unwrap_or("unnamed"),
+            message = std::any::type_name::<T>()
+        )
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = name;
+        Span
+    }
+}
+
+/// Span covering a dispatcher task spawned for an event of type `E`.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg_attr(not(feature = "tracing"), allow(clippy::extra_unused_type_parameters))]
+pub(crate) fn task_span<E>(name: Option<&str>) -> Span {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::info_span!(
+            "async_task",
+            name = name.unwrap_or("unnamed"),
+            message = std::any::type_name::<E>()
+        )
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = name;
+        Span
+    }
+}
+
+/// Run `f` inside `span`.
+pub(crate) fn in_span<R>(span: &Span, f: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "tracing")]
+    {
+        span.in_scope(f)
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = span;
+        f()
+    }
+}
+
+/// Enter `span` every time `fut` is polled.
+pub(crate) fn instrument<F: Future>(fut: F, span: Span) -> impl Future<Output = F::Output> {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::Instrument::instrument(fut, span)
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = span;
+        fut
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::dispatching::AsyncDispatcher;
+    use crate::factory::create_signal_slot;
+    use std::fmt::Debug;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Mutex, mpsc};
+    use std::time::Duration;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Spans created by every thread, as (span name, `name` field, `message` field).
+    static SPANS: Mutex<Vec<(String, String, String)>> = Mutex::new(Vec::new());
+
+    /// Minimal subscriber recording new spans into `SPANS`.
+    struct Capture {
+        next_id: AtomicU64,
+    }
+
+    #[derive(Default)]
+    struct Fields {
+        name: String,
+        message: String,
+    }
+
+    impl Visit for Fields {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            match field.name() {
+                "name" => self.name = value.to_string(),
+                "message" => self.message = value.to_string(),
+                _ => {}
+            }
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.record_str(field, &format!("{value:?}"));
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = Fields::default();
+            span.record(&mut fields);
+            SPANS.lock().unwrap().push((
+                span.metadata().name().to_string(),
+                fields.name,
+                fields.message,
+            ));
+            Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed))
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn spans_named(name: &str) -> Vec<(String, String, String)> {
+        SPANS
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, slot, _)| slot == name)
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn test_slots_and_dispatcher_tasks_open_spans() {
+        // Workers run on their own threads, so the subscriber has to be global
+        tracing::subscriber::set_global_default(Capture {
+            next_id: AtomicU64::new(1),
+        })
+        .unwrap();
+
+        let (signal, slot) = create_signal_slot::<u32>();
+        let (tx, rx) = mpsc::channel();
+        let _worker = slot
+            .named("traced-worker")
+            .start(move |n| tx.send(n).unwrap());
+        signal.send(1).unwrap();
+        signal.send(2).unwrap();
+        for _ in 0..2 {
+            rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        }
+        assert_eq!(
+            spans_named("traced-worker"),
+            vec![
+                (
+                    "slot".to_string(),
+                    "traced-worker".to_string(),
+                    "u32".to_string()
+                );
+                2
+            ]
+        );
+
+        let dispatcher = AsyncDispatcher::<String, usize>::new();
+        let (signal, slot) = create_signal_slot::<String>();
+        let (result_signal, result_slot) = create_signal_slot::<usize>();
+        dispatcher
+            .attach_async(
+                slot.named("traced-fetch"),
+                result_signal,
+                |url| async move { url.len() },
+            )
+            .unwrap();
+        signal.send("mobius".to_string()).unwrap();
+        let result = result_slot
+            .receiver
+            .lock()
+            .unwrap()
+            .recv_timeout(Duration::from_secs(1));
+        assert_eq!(result, Ok(6));
+
+        let kinds: Vec<_> = spans_named("traced-fetch")
+            .into_iter()
+            .map(|(kind, _, message)| (kind, message))
+            .collect();
+        let string = std::any::type_name::<String>().to_string();
+        assert_eq!(
+            kinds,
+            vec![
+                ("slot".to_string(), string.clone()),
+                ("async_task".to_string(), string)
+            ]
+        );
+    }
+}
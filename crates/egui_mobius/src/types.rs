@@ -18,12 +18,14 @@
 //! in signal transitions, particularly useful in immediate mode GUI contexts.
 //!
 
+use parking_lot::{MappedMutexGuard, Mutex, MutexGuard};
 use std::fmt::{self, Debug, Display, Formatter};
 use std::ops::{Deref, DerefMut};
-use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::sync::{Arc, PoisonError};
+use std::time::Duration;
 
-pub type Enqueue<T> = std::sync::mpsc::Sender<T>;
-pub type Dequeue<T> = std::sync::mpsc::Receiver<T>;
+pub type Enqueue<T> = crate::channel::Sender<T>;
+pub type Dequeue<T> = crate::channel::Receiver<T>;
 
 pub type EventEnqueue<T> = tokio::sync::mpsc::Sender<T>;
 pub type EventDequeue<T> = tokio::sync::mpsc::Receiver<T>;
@@ -105,9 +107,42 @@ impl<T> Clone for Value<T> {
 }
 
 impl<T> Value<T> {
-    // TODO avoid exposing `PoisonError` in the API here.
-    pub fn lock(&self) -> Result<ValueGuard<'_, T>, PoisonError<MutexGuard<'_, T>>> {
-        self.0.lock().map(|result| ValueGuard(result))
+    /// Lock the Value, blocking until it is available.
+    ///
+    /// Never returns an error: a thread panicking while it holds the lock does not
+    /// poison the Value. The `Result` remains so that `lock().unwrap()` call sites
+    /// keep compiling.
+    // TODO drop the `Result` from the API here.
+    pub fn lock(&self) -> Result<ValueGuard<'_, T>, PoisonError<ValueGuard<'_, T>>> {
+        Ok(ValueGuard(self.0.lock()))
+    }
+
+    /// Lock the Value, giving up if it is not available within `timeout`.
+    ///
+    /// Returns `None` on timeout, so UI code can skip a frame instead of stalling
+    /// behind a lock held by a busy producer thread. The wait is a timed block on
+    /// the lock, not a polling loop. On `wasm32` there is no other thread that
+    /// could release the lock, so it is tried only once.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::types::Value;
+    /// use std::time::Duration;
+    ///
+    /// let samples = Value::new(vec![1.0, 2.0]);
+    /// if let Some(samples) = samples.try_lock_for(Duration::from_millis(5)) {
+    ///     assert_eq!(samples.len(), 2);
+    /// }
+    /// ```
+    pub fn try_lock_for(&self, timeout: Duration) -> Option<ValueGuard<'_, T>> {
+        #[cfg(target_arch = "wasm32")]
+        let guard = {
+            let _ = timeout;
+            self.0.try_lock()
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let guard = self.0.try_lock_for(timeout);
+        guard.map(ValueGuard)
     }
 
     /// Create a new Value instance with the given value of type T.
@@ -142,6 +177,37 @@ impl<T> Value<T> {
     pub fn set(&self, value: T) {
         self.write(value);
     }
+
+    /// Lock the Value and return a guard that derefs to just one part of it.
+    ///
+    /// This is a mapped mutex guard: the **whole** Value stays locked for as long
+    /// as the returned `ProjectedGuard` is alive, so keep it short-lived exactly as
+    /// you would a `ValueGuard`. It is useful for handing a UI section access to a
+    /// single field without exposing the rest of the state.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::types::Value;
+    ///
+    /// struct AppState {
+    ///     counter: usize,
+    ///     logs: Vec<String>,
+    /// }
+    ///
+    /// let state = Value::new(AppState { counter: 0, logs: Vec::new() });
+    /// {
+    ///     let mut logs = state.project(|s| &mut s.logs);
+    ///     logs.push("started".to_string());
+    /// } // lock released here
+    /// assert_eq!(state.lock().unwrap().logs.len(), 1);
+    /// ```
+    pub fn project<U, F>(&self, f: F) -> ProjectedGuard<'_, U>
+    where
+        U: ?Sized,
+        F: Fn(&mut T) -> &mut U,
+    {
+        ValueGuard::map(self.lock().unwrap(), f)
+    }
 }
 
 impl<T: Send> Value<T> {}
@@ -166,6 +232,59 @@ impl<T> DerefMut for ValueGuard<'_, T> {
     }
 }
 
+impl<'a, T> ValueGuard<'a, T> {
+    /// Narrow an existing guard to one part of the value, keeping the lock.
+    ///
+    /// This is `Value::project` for a guard that is already held, e.g. one returned
+    /// by `try_lock_for`. It is an associated function, called as
+    /// `ValueGuard::map(guard, ..)`, so it cannot shadow a `map` method of `T`.
+    ///
+    /// Example Usage:
+    /// ```rust
+    /// use egui_mobius::types::{Value, ValueGuard};
+    /// use std::time::Duration;
+    ///
+    /// struct Dashboard {
+    ///     counter: usize,
+    ///     title: String,
+    /// }
+    ///
+    /// let state = Value::new(Dashboard { counter: 0, title: "Dashboard".into() });
+    /// if let Some(guard) = state.try_lock_for(Duration::from_millis(5)) {
+    ///     let mut counter = ValueGuard::map(guard, |s| &mut s.counter);
+    ///     *counter += 1;
+    /// }
+    /// assert_eq!(state.lock().unwrap().counter, 1);
+    /// ```
+    pub fn map<U, F>(guard: Self, f: F) -> ProjectedGuard<'a, U>
+    where
+        U: ?Sized,
+        F: Fn(&mut T) -> &mut U,
+    {
+        ProjectedGuard(MutexGuard::map(guard.0, f))
+    }
+}
+
+/// ProjectedGuard type - a locked `Value<T>` narrowed to a part `U` of it.
+///
+/// Returned by `Value::project` and `ValueGuard::map`. The underlying mutex stays locked until the
+/// guard is dropped.
+pub struct ProjectedGuard<'a, U: ?Sized>(MappedMutexGuard<'a, U>);
+
+impl<U: ?Sized> Deref for ProjectedGuard<'_, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        self.0.deref()
+    }
+}
+
+impl<U: ?Sized> DerefMut for ProjectedGuard<'_, U> {
+    fn deref_mut(&mut self) -> &mut U {
+        self.0.deref_mut()
+    }
+}
+
 // need to implement push_back for VecDeque
 // This will facilitate the producer thread to send messages to the UI
 // in an ergonomic way.
@@ -260,6 +379,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Instant;
     //---------------------------------------------------------------------
     // Unit tests for the Value Type
     //---------------------------------------------------------------------
@@ -312,6 +432,83 @@ mod tests {
         assert_eq!(value.get(), "world".to_string());
     }
 
+    #[test]
+    fn test_value_project() {
+        struct State {
+            counter: usize,
+            logs: Vec<String>,
+        }
+
+        let value = Value::new(State {
+            counter: 7,
+            logs: Vec::new(),
+        });
+
+        {
+            let mut logs = value.project(|s| &mut s.logs);
+            logs.push("first".to_string());
+            assert_eq!(logs.len(), 1);
+            // The whole Value stays locked while the projection is alive
+            assert!(value.0.try_lock().is_none());
+        }
+
+        let mut counter = ValueGuard::map(value.lock().unwrap(), |s| &mut s.counter);
+        *counter += 1;
+        assert!(value.0.try_lock().is_none());
+        drop(counter);
+
+        let guard = value.lock().unwrap();
+        assert_eq!(guard.logs, vec!["first".to_string()]);
+        assert_eq!(guard.counter, 8);
+    }
+
+    #[test]
+    fn test_value_try_lock_for_times_out() {
+        use std::sync::mpsc;
+        use std::thread;
+
+        let value = Value::new(0);
+        let (locked_tx, locked_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+
+        let holder = {
+            let value = value.clone();
+            thread::spawn(move || {
+                let mut guard = value.lock().unwrap();
+                *guard = 1;
+                locked_tx.send(()).unwrap();
+                release_rx.recv().unwrap();
+            })
+        };
+        locked_rx.recv().unwrap();
+
+        let start = Instant::now();
+        assert!(value.try_lock_for(Duration::from_millis(20)).is_none());
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        release_tx.send(()).unwrap();
+        holder.join().unwrap();
+        let guard = value.try_lock_for(Duration::from_millis(20)).unwrap();
+        assert_eq!(*guard, 1);
+    }
+
+    #[test]
+    fn test_value_survives_panicking_holder() {
+        let value = Value::new(0);
+        let holder = value.clone();
+        let panicked = std::thread::spawn(move || {
+            let mut guard = holder.lock().unwrap();
+            *guard = 1;
+            panic!("handler failed while holding the lock");
+        })
+        .join();
+        assert!(panicked.is_err());
+
+        // No poisoning: the Value is usable, with the write made before the panic
+        assert_eq!(*value.try_lock_for(Duration::from_millis(20)).unwrap(), 1);
+        assert_eq!(value.get(), 1);
+    }
+
     //---------------------------------------------------------------------
     // Unit tests for the Value Type
     //---------------------------------------------------------------------
@@ -37,7 +37,7 @@
 /// The RunStop variant is used for logs generated by the
 /// run/stop widget.    
 ///
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum LogType {
     Checkbox,
     Slider,
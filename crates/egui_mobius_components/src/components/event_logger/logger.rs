@@ -10,17 +10,24 @@
 //!
 //! - EguiMobiusEventLogger struct
 //! - Logging methods
+//! - Session save/load
 //! - UI rendering
 //!  
 use egui;
 use egui_mobius::{Dispatcher, Signal, SignalDispatcher, Slot};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
 
 use crate::components::event_logger::log_colors::LogColors;
 use crate::components::event_logger::log_type::LogType;
-use crate::components::event_logger::logger_state::LoggerState;
+use crate::components::event_logger::logger_state::{LogGroup, LoggerState};
 use crate::components::event_logger::messages::{
     LogEntry, LogSender, LoggerEvent, LoggerResponse, Message,
 };
+use crate::components::event_logger::serialization::log_serializer::{
+    JsonSerializer, LogSerializer,
+};
 
 /// The main event logger component
 #[allow(dead_code)]
@@ -32,6 +39,8 @@ pub struct EguiMobiusEventLogger {
     response_slot: Option<Slot<LoggerResponse>>,
     /// The UI context
     ctx: egui::Context,
+    /// The on-disk format of saved sessions
+    serializer: Arc<dyn LogSerializer>,
 }
 
 impl Default for EguiMobiusEventLogger {
@@ -63,11 +72,13 @@ impl EguiMobiusEventLogger {
         if let Some(mut slot) = response_slot.clone() {
             let ctx_clone = ctx.clone();
 
-            slot.start(move |_response| {
+            if let Err(e) = slot.start(move |_response| {
                 // The state is already updated in the processor
                 // We just need to request a repaint
                 ctx_clone.request_repaint();
-            });
+            }) {
+                eprintln!("Event logger could not start its response slot: {e}");
+            }
         }
 
         // Register slot for logger events
@@ -79,7 +90,43 @@ impl EguiMobiusEventLogger {
             dispatcher,
             response_slot,
             ctx,
+            serializer: Arc::new(JsonSerializer),
+        }
+    }
+
+    /// Select the format used by `save_session` and `load_session` (JSON by default)
+    pub fn set_serializer(&mut self, serializer: impl LogSerializer + 'static) {
+        self.serializer = Arc::new(serializer);
+    }
+
+    /// Write every log entry to `path` in the selected format
+    pub fn save_session(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let entries: Vec<LogEntry> = crate::components::event_logger::processor::LOGGER_STATE
+            .lock()
+            .unwrap()
+            .logs
+            .iter()
+            .cloned()
+            .collect();
+        std::fs::write(path, self.serializer.serialize(&entries)?)
+    }
+
+    /// Replace the log with the entries saved at `path`, returning how many were loaded
+    ///
+    /// The file must have been written with the same serializer.
+    pub fn load_session(&self, path: impl AsRef<Path>) -> io::Result<usize> {
+        let entries = self.serializer.deserialize(&std::fs::read(path)?)?;
+        let count = entries.len();
+        let mut state = crate::components::event_logger::processor::LOGGER_STATE
+            .lock()
+            .unwrap();
+        state.clear();
+        for entry in entries {
+            state.add_log(entry);
         }
+        drop(state);
+        self.ctx.request_repaint();
+        Ok(count)
     }
 
     /// Add a new log entry
@@ -182,64 +229,96 @@ impl EguiMobiusEventLogger {
     }
 
     /// Displays the event log content with two columns
+    ///
+    /// Runs of identical entries collapse into one row with a "×N" badge,
+    /// which expands the individual entries when clicked.
     fn show_event_log_content(&self, ui: &mut egui::Ui, state: &LoggerState) {
         // Get column visibility settings
         let show_timestamps = state.show_timestamps;
         let show_messages = state.show_messages;
 
-        // Choose layout based on visible columns
-        if show_timestamps && show_messages {
-            // Show both columns in a table
-            egui::ScrollArea::vertical()
-                .auto_shrink([false, false])
-                .stick_to_bottom(true)
-                .show(ui, |ui| {
-                    // Two-column layout
-                    egui::Grid::new("logger_grid")
-                        .num_columns(2)
-                        .spacing([10.0, 6.0])
-                        .striped(true)
-                        .show(ui, |ui| {
-                            // Add headers
+        if !show_timestamps && !show_messages {
+            // Nothing to show
+            ui.label("No columns selected");
+            return;
+        }
+
+        let columns = usize::from(show_timestamps) + usize::from(show_messages);
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .stick_to_bottom(true)
+            .show(ui, |ui| {
+                egui::Grid::new("logger_grid")
+                    .num_columns(columns)
+                    .spacing([10.0, 6.0])
+                    .striped(columns == 2)
+                    .show(ui, |ui| {
+                        // Add headers when showing both columns
+                        if columns == 2 {
                             ui.strong("Time");
                             ui.strong("Message");
                             ui.end_row();
+                        }
 
-                            // Add entries
-                            for entry in state.logs.iter().rev() {
-                                let (timestamp, message) = state.format_log_entry(entry);
-
-                                ui.label(timestamp);
-                                ui.label(message);
-                                ui.end_row();
-                            }
-                        });
-                });
-        } else if show_timestamps {
-            // Show only timestamps
-            egui::ScrollArea::vertical()
-                .auto_shrink([false, false])
-                .stick_to_bottom(true)
-                .show(ui, |ui| {
-                    for entry in state.logs.iter().rev() {
-                        let (timestamp, _) = state.format_log_entry(entry);
-                        ui.label(timestamp);
-                    }
-                });
-        } else if show_messages {
-            // Show only messages
-            egui::ScrollArea::vertical()
-                .auto_shrink([false, false])
-                .stick_to_bottom(true)
-                .show(ui, |ui| {
-                    for entry in state.logs.iter().rev() {
-                        let (_, message) = state.format_log_entry(entry);
-                        ui.label(message);
-                    }
-                });
-        } else {
-            // Nothing to show
-            ui.label("No columns selected");
+                        // Add entries
+                        for group in state.groups() {
+                            Self::show_log_group(ui, state, &group);
+                        }
+                    });
+            });
+    }
+
+    /// Displays one group of identical entries as a grid row, plus one row per
+    /// entry while the group is expanded
+    fn show_log_group(ui: &mut egui::Ui, state: &LoggerState, group: &LogGroup<'_>) {
+        // Keyed on the oldest entry, which stays the same as repeats arrive
+        let expanded_id = egui::Id::new(("logger_group", group.oldest().timestamp));
+        let mut expanded = ui.data(|d| d.get_temp::<bool>(expanded_id).unwrap_or(false));
+
+        let (timestamp, message) = state.format_log_entry(group.latest());
+        let mut cells = Vec::new();
+        if state.show_timestamps {
+            cells.push(timestamp);
+        }
+        if state.show_messages {
+            cells.push(message);
+        }
+
+        // The count badge goes after the last visible column
+        let last = cells.len() - 1;
+        for (index, cell) in cells.into_iter().enumerate() {
+            if index < last || group.count() == 1 {
+                ui.label(cell);
+                continue;
+            }
+            ui.horizontal(|ui| {
+                ui.label(cell);
+                let badge = ui
+                    .small_button(format!("×{}", group.count()))
+                    .on_hover_text(if expanded {
+                        "Collapse"
+                    } else {
+                        "Show each entry"
+                    });
+                if badge.clicked() {
+                    expanded = !expanded;
+                    ui.data_mut(|d| d.insert_temp(expanded_id, expanded));
+                }
+            });
+        }
+        ui.end_row();
+
+        if expanded && group.count() > 1 {
+            for entry in &group.entries {
+                let (timestamp, message) = state.format_log_entry(entry);
+                if state.show_timestamps {
+                    ui.label(timestamp);
+                }
+                if state.show_messages {
+                    ui.label(message);
+                }
+                ui.end_row();
+            }
         }
     }
 }
@@ -86,6 +86,23 @@ impl LoggerState {
         self.logs.iter().skip(start_index).cloned().collect()
     }
 
+    /// Group runs of consecutive identical entries, newest first
+    ///
+    /// Entries belong to the same group when their message, sender and style
+    /// match; any different entry in between starts a new group.
+    pub fn groups(&self) -> Vec<LogGroup<'_>> {
+        let mut groups: Vec<LogGroup<'_>> = Vec::new();
+        for entry in self.logs.iter().rev() {
+            match groups.last_mut() {
+                Some(group) if group.latest().is_repeat_of(entry) => group.entries.push(entry),
+                _ => groups.push(LogGroup {
+                    entries: vec![entry],
+                }),
+            }
+        }
+        groups
+    }
+
     /// Process an entry for display, creating formatted rich text
     pub fn format_log_entry(&self, entry: &LogEntry) -> (RichText, RichText) {
         // Format timestamp
@@ -136,3 +153,85 @@ impl LoggerState {
         (timestamp_rich, message_rich)
     }
 }
+
+/// A run of consecutive identical log entries, displayed as a single row
+pub struct LogGroup<'a> {
+    /// The entries of the run, newest first
+    pub entries: Vec<&'a LogEntry>,
+}
+
+impl LogGroup<'_> {
+    /// Number of entries in the run
+    pub fn count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The most recent entry, shown in the collapsed row
+    pub fn latest(&self) -> &LogEntry {
+        self.entries[0]
+    }
+
+    /// The first entry of the run, which stays the same as repeats arrive
+    pub fn oldest(&self) -> &LogEntry {
+        self.entries[self.entries.len() - 1]
+    }
+}
+
+impl LogEntry {
+    /// Whether `other` repeats this entry's message, sender and style
+    fn is_repeat_of(&self, other: &LogEntry) -> bool {
+        self.message == other.message
+            && self.sender == other.sender
+            && self.style_type == other.style_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::event_logger::messages::LogSender;
+    use chrono::Local;
+
+    fn entry(text: &str) -> LogEntry {
+        LogEntry {
+            timestamp: Local::now(),
+            message: Message::Info(text.to_string()),
+            sender: LogSender::system(),
+            style_type: LogType::Default,
+        }
+    }
+
+    #[test]
+    fn test_consecutive_duplicates_are_grouped() {
+        let mut state = LoggerState::default();
+        for text in ["tick", "tick", "tick", "done", "tick"] {
+            state.add_log(entry(text));
+        }
+
+        let counts: Vec<(String, usize)> = state
+            .groups()
+            .iter()
+            .map(|g| (g.latest().message.content().to_string(), g.count()))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![
+                ("tick".to_string(), 1),
+                ("done".to_string(), 1),
+                ("tick".to_string(), 3),
+            ]
+        );
+
+        // A new duplicate extends the newest group rather than adding a row
+        state.add_log(entry("tick"));
+        let groups = state.groups();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].count(), 2);
+
+        // A different sender or style breaks the group
+        let mut other = entry("tick");
+        other.style_type = LogType::Slider;
+        state.add_log(other);
+        assert_eq!(state.groups().len(), 4);
+    }
+}
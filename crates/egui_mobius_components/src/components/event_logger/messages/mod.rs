@@ -5,11 +5,13 @@
 
 use crate::components::event_logger::log_colors::LogColors;
 use crate::components::event_logger::log_type::LogType;
+use crate::components::event_logger::serialization::timestamp_serde;
 use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
 /// Message types with different severity levels
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Message {
     Info(String),
     Warn(String),
@@ -40,7 +42,7 @@ impl Message {
 }
 
 /// Types of UI widgets that can generate messages
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum UiWidgetType {
     Slider,
     Checkbox,
@@ -57,7 +59,7 @@ pub enum UiWidgetType {
 }
 
 /// A sender represents the source of a log message
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LogSender {
     widget_type: UiWidgetType,
     id: Option<String>, // Optional widget ID/name
@@ -150,8 +152,9 @@ impl LogSender {
 }
 
 /// A log entry contains timestamp, message, sender and visual styling type
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct LogEntry {
+    #[serde(with = "timestamp_serde")]
     pub timestamp: DateTime<Local>,
     pub message: Message,
     pub sender: LogSender,
@@ -0,0 +1,111 @@
+//! MobiusLogger
+//!
+//! **Description**
+//!
+//! Routes records from the `log` crate into an `EguiMobiusEventLogger`, so the
+//! standard `log::info!`/`log::warn!` macros show up in the UI terminal alongside
+//! the application's own log entries.
+//!
+//! **Mapping**
+//!
+//! - `Error` to `Message::Error`, `Warn` to `Message::Warn`, `Info` to `Message::Info`
+//! - `Debug` and `Trace` to `Message::Debug`
+//! - The record's module path (or target) to a custom `LogSender`
+//!
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::components::event_logger::log_type::LogType;
+use crate::components::event_logger::logger::EguiMobiusEventLogger;
+use crate::components::event_logger::messages::{LogSender, Message};
+
+/// A `log::Log` implementation forwarding records to an event logger
+pub struct MobiusLogger {
+    /// The event logger that receives the records
+    logger: EguiMobiusEventLogger,
+    /// The most verbose level that is forwarded
+    level: LevelFilter,
+}
+
+impl MobiusLogger {
+    /// Create a logger forwarding records up to `Info` to `logger`
+    pub fn new(logger: EguiMobiusEventLogger) -> Self {
+        Self {
+            logger,
+            level: LevelFilter::Info,
+        }
+    }
+
+    /// Set the most verbose level that is forwarded
+    pub fn with_level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Install a `MobiusLogger` for `logger` as the global `log` logger
+    ///
+    /// Fails if a global logger has already been installed.
+    pub fn init(logger: EguiMobiusEventLogger) -> Result<(), SetLoggerError> {
+        Self::new(logger).install()
+    }
+
+    /// Install this logger as the global `log` logger, keeping its level
+    pub fn install(self) -> Result<(), SetLoggerError> {
+        let level = self.level;
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(level);
+        Ok(())
+    }
+}
+
+impl Log for MobiusLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let text = record.args().to_string();
+        let message = match record.level() {
+            log::Level::Error => Message::Error(text),
+            log::Level::Warn => Message::Warn(text),
+            log::Level::Info => Message::Info(text),
+            log::Level::Debug | log::Level::Trace => Message::Debug(text),
+        };
+        let sender = LogSender::custom(record.module_path().unwrap_or(record.target()));
+        self.logger.add_log(message, sender, LogType::Default);
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::event_logger::log_colors::LogColors;
+    use crate::components::event_logger::logger::create_event_logger;
+    use crate::components::event_logger::processor::{LOGGER_STATE, STATE_TEST_LOCK};
+
+    #[test]
+    fn test_log_macros_reach_the_event_logger() {
+        let _state = STATE_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let (logger, _event_slot, _response_signal) =
+            create_event_logger(egui::Context::default(), LogColors::default());
+        MobiusLogger::init(logger).unwrap();
+
+        log::warn!("disk almost full");
+        log::debug!("filtered out at the default level");
+
+        let entries = LOGGER_STATE.lock().unwrap().export_recent(10);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].message,
+            Message::Warn("disk almost full".to_string())
+        );
+        assert_eq!(
+            entries[0].sender.display_name(),
+            format!("Custom({})", module_path!())
+        );
+    }
+}
@@ -10,6 +10,7 @@ pub mod log_type;
 pub mod logger;
 pub mod logger_state;
 pub mod messages;
+pub mod mobius_logger;
 pub mod platform;
 pub mod prelude;
 pub mod processor;
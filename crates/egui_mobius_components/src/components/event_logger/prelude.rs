@@ -10,13 +10,17 @@ pub use super::log_type::LogType;
 pub use super::logger::{EguiMobiusEventLogger, create_event_logger};
 
 // Import and re-export from logger_state
-pub use super::logger_state::LoggerState;
+pub use super::logger_state::{LogGroup, LoggerState};
 
 // Import and re-export from messages
 pub use super::messages::{LogEntry, LogSender, Message};
 
+// Import and re-export from mobius_logger
+pub use super::mobius_logger::MobiusLogger;
+
 // Import and re-export from serialization
 pub use super::serialization::color32_serde::{deserialize, serialize};
+pub use super::serialization::log_serializer::{BincodeSerializer, JsonSerializer, LogSerializer};
 
 // Import and re-export from platform
 pub use super::platform::{banner::Banner, details::Details};
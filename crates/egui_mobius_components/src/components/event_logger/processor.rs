@@ -19,6 +19,10 @@ lazy_static! {
         Arc::new(Mutex::new(LoggerState::default()));
 }
 
+/// Held by tests that read or reset `LOGGER_STATE`, which the whole test binary shares
+#[cfg(test)]
+pub(crate) static STATE_TEST_LOCK: Mutex<()> = Mutex::new(());
+
 /// Initialize the logger backend with a given color scheme
 pub fn init_logger_backend(colors: LogColors) {
     let mut state = LOGGER_STATE.lock().unwrap();
@@ -78,10 +82,12 @@ pub fn run_logger_backend(
     mut event_slot: Slot<LoggerEvent>,
     response_signal: Signal<LoggerResponse>,
 ) {
-    event_slot.start(move |event| {
+    if let Err(e) = event_slot.start(move |event| {
         let response = process_event(event);
         if let Err(e) = response_signal.send(response) {
             eprintln!("Failed to send logger response: {e:?}");
         }
-    });
+    }) {
+        eprintln!("Logger backend could not start its event slot: {e}");
+    }
 }
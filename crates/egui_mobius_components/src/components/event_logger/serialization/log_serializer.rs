@@ -0,0 +1,121 @@
+//! Log Serializer
+//!
+//! On-disk formats for saved logger sessions. `EguiMobiusEventLogger::set_serializer`
+//! selects the format used by `save_session` and `load_session`:
+//!
+//! - `JsonSerializer` (the default) writes human-readable JSON
+//! - `BincodeSerializer` writes a compact binary encoding, much faster to save
+//!   and load for large logs
+
+use crate::components::event_logger::messages::LogEntry;
+use std::io;
+
+/// Converts a list of log entries to and from bytes.
+pub trait LogSerializer: Send + Sync {
+    /// Encode `entries`.
+    fn serialize(&self, entries: &[LogEntry]) -> io::Result<Vec<u8>>;
+
+    /// Decode entries written by `serialize`.
+    fn deserialize(&self, bytes: &[u8]) -> io::Result<Vec<LogEntry>>;
+}
+
+/// Human-readable JSON, one object per entry.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonSerializer;
+
+impl LogSerializer for JsonSerializer {
+    fn serialize(&self, entries: &[LogEntry]) -> io::Result<Vec<u8>> {
+        serde_json::to_vec_pretty(entries).map_err(io::Error::other)
+    }
+
+    fn deserialize(&self, bytes: &[u8]) -> io::Result<Vec<LogEntry>> {
+        serde_json::from_slice(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Compact binary encoding using `bincode`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BincodeSerializer;
+
+impl LogSerializer for BincodeSerializer {
+    fn serialize(&self, entries: &[LogEntry]) -> io::Result<Vec<u8>> {
+        bincode::serialize(entries).map_err(io::Error::other)
+    }
+
+    fn deserialize(&self, bytes: &[u8]) -> io::Result<Vec<LogEntry>> {
+        bincode::deserialize(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::event_logger::log_colors::LogColors;
+    use crate::components::event_logger::log_type::LogType;
+    use crate::components::event_logger::logger::create_event_logger;
+    use crate::components::event_logger::messages::{LogSender, Message};
+    use crate::components::event_logger::processor::{LOGGER_STATE, STATE_TEST_LOCK};
+    use chrono::Local;
+
+    fn sample_entries() -> Vec<LogEntry> {
+        vec![
+            LogEntry {
+                timestamp: Local::now(),
+                message: Message::Info("slider moved to 0.5".to_string()),
+                sender: LogSender::slider("gain"),
+                style_type: LogType::Slider,
+            },
+            LogEntry {
+                timestamp: Local::now(),
+                message: Message::Error("device \"A\" lost".to_string()),
+                sender: LogSender::custom("serial"),
+                style_type: LogType::Default,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_serializers_round_trip_in_their_own_format() {
+        let entries = sample_entries();
+
+        let json = JsonSerializer.serialize(&entries).unwrap();
+        let binary = BincodeSerializer.serialize(&entries).unwrap();
+        assert!(json.starts_with(b"["));
+        assert!(binary.len() < json.len());
+
+        assert_eq!(JsonSerializer.deserialize(&json).unwrap(), entries);
+        assert_eq!(BincodeSerializer.deserialize(&binary).unwrap(), entries);
+
+        let error = JsonSerializer.deserialize(&binary).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_logger_session_round_trips_with_a_non_default_serializer() {
+        let _state = STATE_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let (mut logger, _event_slot, _response_signal) =
+            create_event_logger(egui::Context::default(), LogColors::default());
+        logger.set_serializer(BincodeSerializer);
+
+        let entries = sample_entries();
+        {
+            let mut state = LOGGER_STATE.lock().unwrap();
+            for entry in entries.clone() {
+                state.add_log(entry);
+            }
+        }
+
+        let path = std::env::temp_dir().join(format!("logger-session-{}.bin", std::process::id()));
+        logger.save_session(&path).unwrap();
+        let saved = std::fs::read(&path).unwrap();
+        assert_eq!(BincodeSerializer.deserialize(&saved).unwrap(), entries);
+
+        LOGGER_STATE.lock().unwrap().clear();
+        let loaded = logger.load_session(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), entries.len());
+
+        let restored: Vec<LogEntry> = LOGGER_STATE.lock().unwrap().logs.iter().cloned().collect();
+        assert_eq!(restored, entries);
+    }
+}
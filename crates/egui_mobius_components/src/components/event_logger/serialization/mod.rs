@@ -1 +1,3 @@
 pub mod color32_serde;
+pub mod log_serializer;
+pub mod timestamp_serde;
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<S>(timestamp: &DateTime<Local>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    timestamp.to_rfc3339().serialize(serializer)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Local>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let text = String::deserialize(deserializer)?;
+    DateTime::parse_from_rfc3339(&text)
+        .map(|timestamp| timestamp.with_timezone(&Local))
+        .map_err(serde::de::Error::custom)
+}
@@ -1,5 +1,8 @@
 //! Core types and traits for building **egui_mobius_reactive** applications.
+use crate::{Derived, Dynamic};
 use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt;
 use std::sync::{Arc, Mutex};
 
 /// Subscribers
@@ -15,6 +18,30 @@ use std::sync::{Arc, Mutex};
 ///
 pub type Subscribers = Arc<Mutex<Vec<Box<dyn Fn() + Send + Sync>>>>;
 
+/// Callbacks receiving the index of a `ReactiveList` element that was mutated in place.
+pub type ItemSubscribers = Arc<Mutex<Vec<Box<dyn Fn(usize) + Send + Sync>>>>;
+
+/// Error returned by `ReactiveList` operations given an index past the end of the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfBounds {
+    /// The offending index.
+    pub index: usize,
+    /// Length of the list at the time of the call.
+    pub len: usize,
+}
+
+impl fmt::Display for IndexOutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds for a list of length {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl std::error::Error for IndexOutOfBounds {}
+
 /// Trait implemented by all reactive types (`Dynamic`, `Derived`, `ReactiveList`)
 /// that can be observed for changes.
 ///
@@ -90,11 +117,61 @@ pub trait ReactiveValue: Send + Sync {
     /// }
     /// ```
     fn as_any(&self) -> &dyn Any;
+
+    /// Identifies the value in the dependency graph; clones of one value share it.
+    ///
+    /// Returns `None` for values that do not take part in the graph, which is the
+    /// default for custom implementations.
+    fn node_id(&self) -> Option<usize> {
+        None
+    }
+
+    /// The `node_id`s of the values this one is computed from.
+    fn dependency_ids(&self) -> Vec<usize> {
+        Vec::new()
+    }
+
+    /// Name of the concrete type, as given by `std::any::type_name`.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
+
+/// A type-erased dependency of a `Derived` value.
+///
+/// Dependency lists hold `ReactiveDep`s rather than a concrete type, so one list can
+/// mix `Dynamic<i32>`, `Dynamic<f64>`, `Derived<String>`, `ReactiveList<T>` and so on.
+/// Build them with `dep`, or with `Arc::new` directly inside an array literal passed
+/// to `Derived::new`.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius_reactive::{Derived, Dynamic, ReactiveDep, dep};
+///
+/// let samples = Dynamic::new(120i32);
+/// let rate_hz = Dynamic::new(60.0f64);
+/// let deps: Vec<ReactiveDep> = vec![dep(&samples), dep(&rate_hz)];
+///
+/// let (samples_in, rate_in) = (samples.clone(), rate_hz.clone());
+/// let seconds = Derived::new(&deps, move || samples_in.get() as f64 / rate_in.get());
+/// assert_eq!(seconds.get(), 2.0);
+/// ```
+pub type ReactiveDep = Arc<dyn ReactiveValue>;
+
+/// Wraps a clone of `value` as a `ReactiveDep`.
+pub fn dep<R>(value: &R) -> ReactiveDep
+where
+    R: ReactiveValue + Clone + 'static,
+{
+    Arc::new(value.clone())
 }
 
-/// A reactive list that notifies subscribers when items are added, removed, or cleared.
+/// A reactive list that notifies subscribers when items are added, removed, cleared or
+/// mutated in place.
 ///
 /// Each modification to the internal `Vec<T>` triggers all registered callbacks.
+/// In-place element mutations (`set`, `update`) additionally report the element's
+/// index to callbacks registered with `on_item_change`.
 /// This is useful for binding list updates to UI refreshes or effects.
 ///
 /// # Example
@@ -107,6 +184,7 @@ pub trait ReactiveValue: Send + Sync {
 pub struct ReactiveList<T> {
     items: Arc<Mutex<Vec<T>>>,
     subscribers: Subscribers,
+    item_subscribers: ItemSubscribers,
 }
 
 impl<T: Clone + Send + Sync + 'static> ReactiveList<T> {
@@ -121,6 +199,7 @@ impl<T: Clone + Send + Sync + 'static> ReactiveList<T> {
         Self {
             items: Arc::new(Mutex::new(Vec::new())),
             subscribers: Arc::new(Mutex::new(Vec::new())),
+            item_subscribers: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -157,6 +236,88 @@ impl<T: Clone + Send + Sync + 'static> ReactiveList<T> {
         self.notify_subscribers();
     }
 
+    /// Inserts an item at `index`, shifting later items right, and notifies subscribers.
+    ///
+    /// `index` may equal the length of the list, which appends the item.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::ReactiveList;
+    /// let list = ReactiveList::new();
+    /// list.push(1);
+    /// list.insert(0, 0).unwrap();
+    /// assert_eq!(list.get_all(), vec![0, 1]);
+    /// assert!(list.insert(5, 9).is_err());
+    /// ```
+    pub fn insert(&self, index: usize, item: T) -> Result<(), IndexOutOfBounds> {
+        {
+            let mut items = self.items.lock().unwrap();
+            let len = items.len();
+            if index > len {
+                return Err(IndexOutOfBounds { index, len });
+            }
+            items.insert(index, item);
+        }
+        self.notify_subscribers();
+        Ok(())
+    }
+
+    /// Swaps the items at indices `a` and `b` and notifies subscribers.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::ReactiveList;
+    /// let list = ReactiveList::new();
+    /// list.push(1);
+    /// list.push(2);
+    /// list.swap(0, 1).unwrap();
+    /// assert_eq!(list.get_all(), vec![2, 1]);
+    /// ```
+    pub fn swap(&self, a: usize, b: usize) -> Result<(), IndexOutOfBounds> {
+        {
+            let mut items = self.items.lock().unwrap();
+            let len = items.len();
+            if let Some(index) = [a, b].into_iter().find(|&index| index >= len) {
+                return Err(IndexOutOfBounds { index, len });
+            }
+            items.swap(a, b);
+        }
+        self.notify_subscribers();
+        Ok(())
+    }
+
+    /// Moves the item at `from` so that it ends up at index `to`, shifting the
+    /// items in between, and notifies subscribers.
+    ///
+    /// This is the operation behind drag-to-reorder.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::ReactiveList;
+    /// let list = ReactiveList::new();
+    /// for item in ['a', 'b', 'c', 'd'] {
+    ///     list.push(item);
+    /// }
+    /// list.move_item(0, 2).unwrap();
+    /// assert_eq!(list.get_all(), vec!['b', 'c', 'a', 'd']);
+    /// ```
+    pub fn move_item(&self, from: usize, to: usize) -> Result<(), IndexOutOfBounds> {
+        {
+            let mut items = self.items.lock().unwrap();
+            let len = items.len();
+            if let Some(index) = [from, to].into_iter().find(|&index| index >= len) {
+                return Err(IndexOutOfBounds { index, len });
+            }
+            if from < to {
+                items[from..=to].rotate_left(1);
+            } else {
+                items[to..=from].rotate_right(1);
+            }
+        }
+        self.notify_subscribers();
+        Ok(())
+    }
+
     /// Clears all items from the list and notifies subscribers.
     ///
     /// # Example
@@ -171,6 +332,61 @@ impl<T: Clone + Send + Sync + 'static> ReactiveList<T> {
         self.notify_subscribers();
     }
 
+    /// Replaces the item at `index` and notifies subscribers.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the item to replace.
+    /// * `item` - The new item.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::ReactiveList;
+    /// let list = ReactiveList::new();
+    /// list.push(1);
+    /// list.set(0, 5).unwrap();
+    /// assert_eq!(list.get_all(), vec![5]);
+    /// assert!(list.set(3, 7).is_err());
+    /// ```
+    pub fn set(&self, index: usize, item: T) -> Result<(), IndexOutOfBounds> {
+        self.update(index, |slot| *slot = item)
+    }
+
+    /// Mutates the item at `index` in place and notifies subscribers.
+    ///
+    /// Both the list's change callbacks and the `on_item_change` callbacks fire,
+    /// so `Derived` values built on the list (such as `sum`) recompute.
+    ///
+    /// # Arguments
+    /// * `index` - The index of the item to mutate.
+    /// * `f` - Closure receiving a mutable reference to the item.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::ReactiveList;
+    /// let list = ReactiveList::new();
+    /// list.push(1);
+    /// list.update(0, |item| *item += 10).unwrap();
+    /// assert_eq!(list.get_all(), vec![11]);
+    /// ```
+    pub fn update<F>(&self, index: usize, f: F) -> Result<(), IndexOutOfBounds>
+    where
+        F: FnOnce(&mut T),
+    {
+        {
+            let mut items = self.items.lock().unwrap();
+            let len = items.len();
+            let item = items
+                .get_mut(index)
+                .ok_or(IndexOutOfBounds { index, len })?;
+            f(item);
+        }
+        for f in self.item_subscribers.lock().unwrap().iter() {
+            f(index);
+        }
+        self.notify_subscribers();
+        Ok(())
+    }
+
     /// Returns a cloned copy of the entire list.
     ///
     /// # Example
@@ -185,6 +401,69 @@ impl<T: Clone + Send + Sync + 'static> ReactiveList<T> {
         self.items.lock().unwrap().clone()
     }
 
+    /// Exposes the `len` items starting at `offset`, recomputed whenever the list
+    /// or the offset changes.
+    ///
+    /// Only the visible slice is cloned, which suits virtualized rendering of a
+    /// long list in a scroll area. An offset too close to the end is clamped so
+    /// the window still ends at the last item; a list shorter than `len` is
+    /// exposed whole.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::{Dynamic, ReactiveList};
+    ///
+    /// let list = ReactiveList::new();
+    /// for item in 0..100 {
+    ///     list.push(item);
+    /// }
+    /// let offset = Dynamic::new(10);
+    /// let visible = list.window(offset.clone(), 3);
+    /// assert_eq!(visible.get(), vec![10, 11, 12]);
+    /// ```
+    pub fn window(&self, offset: Dynamic<usize>, len: usize) -> Derived<Vec<T>> {
+        let items = Arc::clone(&self.items);
+        let deps = [dep(self), dep(&offset)];
+        Derived::new(&deps, move || {
+            let items = items.lock().unwrap();
+            let start = offset.get().min(items.len().saturating_sub(len));
+            let end = (start + len).min(items.len());
+            items[start..end].to_vec()
+        })
+    }
+
+    /// Exposes a copy of the list sorted with `cmp`, re-sorted whenever the list
+    /// changes, so a view ordered by some key does not sort on every frame.
+    ///
+    /// The sort is stable: items comparing equal keep their list order. For
+    /// floats, compare with `total_cmp`, which puts NaN after every number,
+    /// rather than `partial_cmp(..).unwrap()`, which panics on NaN.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::ReactiveList;
+    ///
+    /// let readings = ReactiveList::new();
+    /// readings.push(2.5);
+    /// readings.push(f64::NAN);
+    /// readings.push(-1.0);
+    /// let by_magnitude = readings.sorted_by(|a: &f64, b: &f64| a.total_cmp(b));
+    /// assert_eq!(by_magnitude.get()[..2], [-1.0, 2.5]);
+    /// assert!(by_magnitude.get()[2].is_nan());
+    /// ```
+    pub fn sorted_by<F>(&self, cmp: F) -> Derived<Vec<T>>
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
+        let items = Arc::clone(&self.items);
+        let cmp = Arc::new(cmp);
+        Derived::new(&[dep(self)], move || {
+            let mut sorted = items.lock().unwrap().clone();
+            sorted.sort_by(|a, b| cmp(a, b));
+            sorted
+        })
+    }
+
     /// Registers a callback to be called when the list changes.
     ///
     /// # Arguments
@@ -201,6 +480,23 @@ impl<T: Clone + Send + Sync + 'static> ReactiveList<T> {
         self.subscribers.lock().unwrap().push(Box::new(f));
     }
 
+    /// Registers a callback receiving the index of each element mutated in place
+    /// through `set` or `update`.
+    ///
+    /// Structural changes (push, remove, clear) only fire `on_change`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::ReactiveList;
+    /// let list = ReactiveList::new();
+    /// list.push(1);
+    /// list.on_item_change(|index| println!("item {index} changed"));
+    /// list.set(0, 2).unwrap();
+    /// ```
+    pub fn on_item_change(&self, f: impl Fn(usize) + Send + Sync + 'static) {
+        self.item_subscribers.lock().unwrap().push(Box::new(f));
+    }
+
     /// Notifies all registered subscribers.
     ///
     /// This method is called internally whenever the list is modified.
@@ -224,6 +520,7 @@ impl<T> Clone for ReactiveList<T> {
         Self {
             items: Arc::clone(&self.items),
             subscribers: Arc::clone(&self.subscribers),
+            item_subscribers: Arc::clone(&self.item_subscribers),
         }
     }
 }
@@ -256,6 +553,10 @@ impl<T: Clone + Send + Sync + 'static> ReactiveValue for ReactiveList<T> {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn node_id(&self) -> Option<usize> {
+        Some(Arc::as_ptr(&self.items) as *const () as usize)
+    }
 }
 
 // Removed redundant implementation of Default for ReactiveList<T>
@@ -272,3 +573,105 @@ impl<T: Clone + Send + Sync + 'static> Default for ReactiveList<T> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReactiveListSum;
+
+    #[test]
+    fn test_element_mutation_recomputes_sum() {
+        let list = ReactiveList::new();
+        list.push(1);
+        list.push(2);
+        let sum = list.sum();
+        let changed = Arc::new(Mutex::new(Vec::new()));
+        let changed_clone = changed.clone();
+        list.on_item_change(move |index| changed_clone.lock().unwrap().push(index));
+
+        list.update(0, |item| *item = 10).unwrap();
+        assert_eq!(sum.get(), 12);
+        list.set(1, 5).unwrap();
+        assert_eq!(sum.get(), 15);
+        assert_eq!(*changed.lock().unwrap(), vec![0, 1]);
+
+        assert_eq!(list.set(2, 0), Err(IndexOutOfBounds { index: 2, len: 2 }));
+        assert_eq!(sum.get(), 15);
+    }
+
+    #[test]
+    fn test_window_follows_offset_and_list() {
+        let list = ReactiveList::new();
+        for item in 0..10 {
+            list.push(item);
+        }
+        let offset = Dynamic::new(2);
+        let window = list.window(offset.clone(), 3);
+        assert_eq!(window.get(), vec![2, 3, 4]);
+
+        offset.set(5);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(window.get(), vec![5, 6, 7]);
+
+        list.set(6, 60).unwrap();
+        assert_eq!(window.get(), vec![5, 60, 7]);
+
+        // Past the end the window is clamped to the last items
+        offset.set(42);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(window.get(), vec![7, 8, 9]);
+
+        list.clear();
+        list.push(1);
+        assert_eq!(window.get(), vec![1]);
+    }
+
+    #[test]
+    fn test_sorted_view_resorts_on_push() {
+        let list = ReactiveList::new();
+        let by_value = list.sorted_by(|a: &(u32, char), b: &(u32, char)| a.0.cmp(&b.0));
+        for item in [(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd')] {
+            list.push(item);
+        }
+        assert_eq!(by_value.get(), vec![(1, 'b'), (2, 'd'), (3, 'a'), (3, 'c')]);
+
+        list.push((0, 'e'));
+        assert_eq!(by_value.get()[0], (0, 'e'));
+        list.set(0, (9, 'a')).unwrap();
+        assert_eq!(by_value.get().last(), Some(&(9, 'a')));
+        // The list itself keeps its insertion order
+        assert_eq!(list.get_all()[1], (1, 'b'));
+    }
+
+    #[test]
+    fn test_reordering_keeps_sum_and_rejects_bad_indices() {
+        let list = ReactiveList::new();
+        for item in [1, 2, 3] {
+            list.push(item);
+        }
+        let sum = list.sum();
+        let notified = Arc::new(Mutex::new(0));
+        let notified_clone = notified.clone();
+        list.on_change(move || *notified_clone.lock().unwrap() += 1);
+
+        list.insert(1, 10).unwrap();
+        list.swap(0, 3).unwrap();
+        list.move_item(3, 0).unwrap();
+        list.move_item(1, 2).unwrap();
+        assert_eq!(list.get_all(), vec![1, 10, 3, 2]);
+        assert_eq!(sum.get(), 16);
+        assert_eq!(*notified.lock().unwrap(), 4);
+
+        assert_eq!(
+            list.insert(5, 0),
+            Err(IndexOutOfBounds { index: 5, len: 4 })
+        );
+        assert_eq!(list.swap(0, 4), Err(IndexOutOfBounds { index: 4, len: 4 }));
+        assert_eq!(
+            list.move_item(7, 0),
+            Err(IndexOutOfBounds { index: 7, len: 4 })
+        );
+        assert_eq!(list.get_all(), vec![1, 10, 3, 2]);
+        assert_eq!(*notified.lock().unwrap(), 4);
+    }
+}
@@ -6,8 +6,12 @@
 //!
 
 use crate::Dynamic;
+use crate::ReactiveDep;
 use crate::ReactiveValue;
 use crate::Subscribers;
+#[cfg(feature = "chrono")]
+use std::sync::Weak;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 /// Type alias for a list of subscribers.
 ///
@@ -47,6 +51,14 @@ pub struct Derived<T: Clone + Send + Sync + 'static> {
     value: Arc<Mutex<T>>,
     /// List of subscribers to notify when the value changes.
     subscribers: Subscribers,
+    /// Recomputes the value and notifies subscribers; `None` for detached values.
+    refresh: Option<Arc<dyn Fn() + Send + Sync>>,
+    /// Reports whether the inputs changed since the last computation (memoized only).
+    stale: Option<Arc<dyn Fn() -> bool + Send + Sync>>,
+    /// `node_id`s of the values this one is computed from.
+    sources: Arc<[usize]>,
+    /// For `new_lazy` values, whether the first computation has happened.
+    computed: Option<Arc<AtomicBool>>,
 }
 
 /// Implementation of the `Derived` struct.
@@ -70,48 +82,247 @@ pub struct Derived<T: Clone + Send + Sync + 'static> {
 /// ```
 impl<T: Clone + Send + Sync + 'static> Derived<T> {
     /// Creates a new derived value that depends on the given reactive sources.
-    pub fn new<F>(deps: &[Arc<dyn ReactiveValue>], compute: F) -> Self
+    pub fn new<F>(deps: &[ReactiveDep], compute: F) -> Self
     where
         F: Fn() -> T + Send + Sync + Clone + 'static,
     {
-        let initial = compute();
-        let value = Arc::new(Mutex::new(initial));
-        let subscribers: Subscribers = Arc::new(Mutex::new(Vec::new()));
-
-        let compute = Arc::new(compute);
-        let value_clone = value.clone();
-        let subs_clone = subscribers.clone();
-
-        for dep in deps {
-            let compute = compute.clone();
-            let value = value.clone();
-            let subs = subscribers.clone();
-            dep.subscribe(Box::new(move || {
-                let new_value = compute();
-                *value.lock().unwrap() = new_value;
-                for cb in subs.lock().unwrap().iter() {
-                    cb();
+        let derived = Self::detached(compute());
+        let target = derived.clone();
+        let refresh: Arc<dyn Fn() + Send + Sync> = Arc::new(move || target.replace(compute()));
+        subscribe_all(deps, &refresh);
+        Self {
+            refresh: Some(refresh),
+            ..derived.with_sources(&node_ids(deps))
+        }
+    }
+
+    /// Creates a derived value whose first computation is deferred until the first
+    /// `get`, for expensive values that may never be displayed.
+    ///
+    /// Until then the value holds `T::default()` and dependency changes cost
+    /// nothing. From the first `get` (or `invalidate`) on, the value is cached and
+    /// recomputed on dependency changes exactly like one built with `new`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::{Derived, Dynamic, dep};
+    ///
+    /// let samples = Dynamic::new(vec![3, 1, 2]);
+    /// let source = samples.clone();
+    /// let sorted = Derived::new_lazy(&[dep(&samples)], move || {
+    ///     let mut sorted = source.get();
+    ///     sorted.sort();
+    ///     sorted
+    /// });
+    /// // Nothing has been sorted yet; this `get` runs the closure once
+    /// assert_eq!(sorted.get(), vec![1, 2, 3]);
+    /// ```
+    pub fn new_lazy<F>(deps: &[ReactiveDep], compute: F) -> Self
+    where
+        T: Default,
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        let derived = Self::detached(T::default());
+        let computed = Arc::new(AtomicBool::new(false));
+        let target = derived.clone();
+        let refresh: Arc<dyn Fn() + Send + Sync> = Arc::new(move || target.replace(compute()));
+
+        let on_dependency_change: Arc<dyn Fn() + Send + Sync> = {
+            let (refresh, computed) = (refresh.clone(), computed.clone());
+            Arc::new(move || {
+                if computed.load(Ordering::SeqCst) {
+                    refresh();
                 }
-            }));
+            })
+        };
+        subscribe_all(deps, &on_dependency_change);
+        Self {
+            refresh: Some(refresh),
+            computed: Some(computed),
+            ..derived.with_sources(&node_ids(deps))
         }
+    }
 
+    /// Creates a derived value that recomputes only when its inputs actually change.
+    ///
+    /// `inputs` takes a cheap snapshot of whatever `compute` depends on (for example
+    /// a history length and its last sample). When a dependency notifies, the snapshot
+    /// is compared with the one used for the cached value and the expensive `compute`
+    /// runs only if they differ, so a `set` with an equal value costs nothing.
+    ///
+    /// Use `invalidate` to force a recomputation where dependency tracking is
+    /// incomplete, and `is_stale` to check whether the inputs moved unnoticed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::{Derived, Dynamic};
+    /// use std::sync::Arc;
+    ///
+    /// let samples = Dynamic::new(vec![1.0, 2.0, 4.0]);
+    /// let source = samples.clone();
+    /// let points = Derived::memoized(
+    ///     &[Arc::new(samples.clone())],
+    ///     move || source.get(),
+    ///     |samples: &Vec<f64>| samples.iter().enumerate().map(|(i, y)| [i as f64, *y]).collect::<Vec<_>>(),
+    /// );
+    /// assert_eq!(points.get().len(), 3);
+    /// ```
+    pub fn memoized<K, I, F>(deps: &[ReactiveDep], inputs: I, compute: F) -> Self
+    where
+        K: PartialEq + Send + 'static,
+        I: Fn() -> K + Send + Sync + 'static,
+        F: Fn(&K) -> T + Send + Sync + 'static,
+    {
+        let key = inputs();
+        let derived = Self::detached(compute(&key));
+        let last = Arc::new(Mutex::new(key));
+        let inputs = Arc::new(inputs);
+
+        let target = derived.clone();
+        let refresh: Arc<dyn Fn() + Send + Sync> = {
+            let (last, inputs) = (last.clone(), inputs.clone());
+            Arc::new(move || {
+                let key = inputs();
+                let value = compute(&key);
+                *last.lock().unwrap() = key;
+                target.replace(value);
+            })
+        };
+        let stale: Arc<dyn Fn() -> bool + Send + Sync> = {
+            let (last, inputs) = (last.clone(), inputs.clone());
+            Arc::new(move || *last.lock().unwrap() != inputs())
+        };
+
+        let on_dependency_change: Arc<dyn Fn() + Send + Sync> = {
+            let (refresh, stale) = (refresh.clone(), stale.clone());
+            Arc::new(move || {
+                if stale() {
+                    refresh();
+                }
+            })
+        };
+        subscribe_all(deps, &on_dependency_change);
         Self {
-            value: value_clone,
-            subscribers: subs_clone,
+            refresh: Some(refresh),
+            stale: Some(stale),
+            ..derived.with_sources(&node_ids(deps))
+        }
+    }
+
+    /// Recomputes the value now and notifies subscribers, even if no dependency
+    /// changed. Does nothing for values that are not computed from sources, such
+    /// as the result of `Dynamic::scan`.
+    pub fn invalidate(&self) {
+        if let Some(computed) = &self.computed {
+            computed.store(true, Ordering::SeqCst);
+        }
+        if let Some(refresh) = &self.refresh {
+            refresh();
+        }
+    }
+
+    /// Returns `true` if the inputs of a `memoized` value changed without a
+    /// recomputation, i.e. a dependency was missed. Always `false` otherwise.
+    pub fn is_stale(&self) -> bool {
+        self.stale.as_ref().is_some_and(|stale| stale())
+    }
+
+    /// Creates a derived value without dependencies, updated internally via `replace`.
+    pub(crate) fn detached(initial: T) -> Self {
+        Self {
+            value: Arc::new(Mutex::new(initial)),
+            subscribers: Arc::new(Mutex::new(Vec::new())),
+            refresh: None,
+            stale: None,
+            sources: Arc::new([]),
+            computed: None,
+        }
+    }
+
+    /// Records the `node_id`s this value is computed from, for `SignalRegistry::graph`.
+    pub(crate) fn with_sources(self, sources: &[Option<usize>]) -> Self {
+        Self {
+            sources: sources.iter().flatten().copied().collect(),
+            ..self
+        }
+    }
+
+    /// A reference that does not keep the value alive, for background refreshers.
+    #[cfg(feature = "chrono")]
+    pub(crate) fn downgrade(&self) -> WeakDerived<T> {
+        WeakDerived {
+            value: Arc::downgrade(&self.value),
+            subscribers: self.subscribers.clone(),
+        }
+    }
+
+    /// Stores a new value and notifies subscribers.
+    pub(crate) fn replace(&self, new_value: T) {
+        *self.value.lock().unwrap() = new_value;
+        for cb in self.subscribers.lock().unwrap().iter() {
+            cb();
         }
     }
 
     /// Gets the current value of the derived signal.
+    ///
+    /// For a `new_lazy` value, the first call runs the computation.
     pub fn get(&self) -> T {
+        self.ensure_computed();
         self.value.lock().unwrap().clone()
     }
 
+    /// Runs the deferred first computation of a `new_lazy` value, if still pending.
+    fn ensure_computed(&self) {
+        if let Some(computed) = &self.computed
+            && !computed.swap(true, Ordering::SeqCst)
+            && let Some(refresh) = &self.refresh
+        {
+            refresh();
+        }
+    }
+
     /// Registers a callback to be called whenever the derived value changes.
     pub fn on_change(&self, f: Box<dyn Fn() + Send + Sync>) {
         self.subscribers.lock().unwrap().push(f);
     }
 }
 
+/// A `Derived` that may already have been dropped, see `Derived::downgrade`.
+#[cfg(feature = "chrono")]
+pub(crate) struct WeakDerived<T> {
+    value: Weak<Mutex<T>>,
+    subscribers: Subscribers,
+}
+
+#[cfg(feature = "chrono")]
+impl<T: Clone + Send + Sync + 'static> WeakDerived<T> {
+    /// The value as a detached `Derived`, usable with `replace`, while it still exists.
+    pub(crate) fn upgrade(&self) -> Option<Derived<T>> {
+        Some(Derived {
+            value: self.value.upgrade()?,
+            subscribers: self.subscribers.clone(),
+            refresh: None,
+            stale: None,
+            sources: Arc::new([]),
+            computed: None,
+        })
+    }
+}
+
+/// The `node_id`s of `deps`, `None` for those outside the graph.
+pub(crate) fn node_ids(deps: &[ReactiveDep]) -> Vec<Option<usize>> {
+    deps.iter().map(|dep| dep.node_id()).collect()
+}
+
+/// Runs `on_change` whenever any of `deps` notifies.
+pub(crate) fn subscribe_all(deps: &[ReactiveDep], on_change: &Arc<dyn Fn() + Send + Sync>) {
+    for dep in deps {
+        let on_change = on_change.clone();
+        dep.subscribe(Box::new(move || on_change()));
+    }
+}
+
 impl<T: Clone + Send + Sync + 'static> From<Derived<T>> for Dynamic<T> {
     fn from(val: Derived<T>) -> Self {
         let initial_value = val.get();
@@ -119,6 +330,18 @@ impl<T: Clone + Send + Sync + 'static> From<Derived<T>> for Dynamic<T> {
     }
 }
 
+/// Serializes the current value, as if the `Derived` were a plain `T`.
+///
+/// There is deliberately no `Deserialize`: a derived value is recomputed from its
+/// sources, so mark such fields `#[serde(skip)]` on load and rebuild them afterwards.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize + Clone + Send + Sync + 'static> serde::Serialize for Derived<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.ensure_computed();
+        self.value.lock().unwrap().serialize(serializer)
+    }
+}
+
 impl<T: Clone + Send + Sync + 'static> ReactiveValue for Derived<T> {
     fn subscribe(&self, f: Box<dyn Fn() + Send + Sync>) {
         self.on_change(f);
@@ -127,15 +350,107 @@ impl<T: Clone + Send + Sync + 'static> ReactiveValue for Derived<T> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn node_id(&self) -> Option<usize> {
+        Some(Arc::as_ptr(&self.value) as *const () as usize)
+    }
+
+    fn dependency_ids(&self) -> Vec<usize> {
+        self.sources.to_vec()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::atomic::{AtomicBool, Ordering};
     use std::thread;
     use std::time::Duration;
 
+    #[test]
+    fn test_mixed_dependency_types_recompute() {
+        let samples = Dynamic::new(120i32);
+        let rate_hz = Dynamic::new(60.0f64);
+        let (samples_in, rate_in) = (samples.clone(), rate_hz.clone());
+        let seconds = Derived::new(&[crate::dep(&samples), crate::dep(&rate_hz)], move || {
+            samples_in.get() as f64 / rate_in.get()
+        });
+        assert_eq!(seconds.get(), 2.0);
+
+        samples.set(240);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(seconds.get(), 4.0);
+
+        rate_hz.set(120.0);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(seconds.get(), 2.0);
+    }
+
+    #[test]
+    fn test_lazy_defers_computation_until_get() {
+        use std::sync::atomic::AtomicUsize;
+
+        let count = Dynamic::new(1);
+        let computations = Arc::new(AtomicUsize::new(0));
+        let (source, counter) = (count.clone(), computations.clone());
+        let doubled = Derived::new_lazy(&[crate::dep(&count)], move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            source.get() * 2
+        });
+
+        count.set(2);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(computations.load(Ordering::SeqCst), 0);
+
+        assert_eq!(doubled.get(), 4);
+        assert_eq!(doubled.get(), 4);
+        assert_eq!(computations.load(Ordering::SeqCst), 1);
+
+        // Once computed it follows its dependencies like any other derived value
+        count.set(5);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(computations.load(Ordering::SeqCst), 2);
+        assert_eq!(doubled.get(), 10);
+    }
+
+    #[test]
+    fn test_memoized_skips_equal_inputs_until_invalidated() {
+        use std::sync::atomic::AtomicUsize;
+
+        let history = Dynamic::new(vec![1, 2, 3]);
+        let computations = Arc::new(AtomicUsize::new(0));
+        let (source, counter) = (history.clone(), computations.clone());
+        let total = Derived::memoized(
+            &[Arc::new(history.clone())],
+            move || source.get(),
+            move |history: &Vec<i32>| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                history.iter().sum::<i32>()
+            },
+        );
+        assert_eq!((total.get(), computations.load(Ordering::SeqCst)), (6, 1));
+
+        // An equal value notifies but does not recompute
+        history.set(vec![1, 2, 3]);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(computations.load(Ordering::SeqCst), 1);
+        assert!(!total.is_stale());
+
+        history.set(vec![1, 2, 3, 4]);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!((total.get(), computations.load(Ordering::SeqCst)), (10, 2));
+
+        // A change the dependency tracking misses leaves the value stale
+        history.lock().push(5);
+        assert!(total.is_stale());
+        total.invalidate();
+        assert_eq!((total.get(), computations.load(Ordering::SeqCst)), (15, 3));
+        assert!(!total.is_stale());
+
+        // invalidate recomputes even when nothing changed
+        total.invalidate();
+        assert_eq!(computations.load(Ordering::SeqCst), 4);
+    }
+
     /// Test the ReactiveValue implementation for the Derived struct.
     ///
     #[test]
@@ -4,12 +4,20 @@
 //! provides mechanisms to monitor changes to the value. It is often on the argument list to the
 //! UiState or AppState function.  
 //!
-use crate::ReactiveValue;
+use crate::reactive::listener;
+use crate::{Derived, ReactiveValue};
+#[cfg(feature = "signals")]
+use egui_mobius::MobiusError;
+#[cfg(feature = "signals")]
+use egui_mobius::slot::Slot;
 use parking_lot::Mutex as PLMutex;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{Sender, channel};
-use std::sync::{Arc, Mutex};
-use std::thread;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 /// A thread-safe container for dynamic values that can be monitored for changes.
 ///
@@ -32,15 +40,28 @@ pub struct Dynamic<T> {
     pub(crate) inner: Arc<Mutex<T>>,
     /// A list of notifiers (channels) to notify listeners when the value changes.
     notifiers: Arc<PLMutex<Vec<Sender<()>>>>,
+    /// Notifiers that receive a copy of every value passed to `set`.
+    watchers: Arc<PLMutex<Vec<Sender<T>>>>,
+    /// Decides whether a `set` is a change, see `new_with_eq`.
+    eq: Option<EqFn<T>>,
 }
 
+/// Comparator given to `Dynamic::new_with_eq`.
+type EqFn<T> = Arc<dyn Fn(&T, &T) -> bool + Send + Sync>;
+
 impl<T> Dynamic<T> {
     /// Gets a lock on the inner value.
     ///
     /// This method provides direct access to the inner value by locking the `Mutex`.
     ///
+    /// In debug builds, locking a `Dynamic` again on a thread that already holds
+    /// its lock (including through `get` or `set`) panics with a message naming
+    /// the value's type, instead of deadlocking. This typically happens in a
+    /// compute closure that locks the same value twice, e.g. `*a.lock() + *b.lock()`
+    /// where `a` and `b` are clones of one `Dynamic`.
+    ///
     /// # Returns
-    /// A `MutexGuard` to the inner value.
+    /// A `DynamicGuard` to the inner value.
     ///
     /// # Example
     /// ```rust
@@ -51,8 +72,99 @@ impl<T> Dynamic<T> {
     /// *guard = 84;
     /// assert_eq!(*guard, 84);
     /// ```
-    pub fn lock(&self) -> std::sync::MutexGuard<'_, T> {
-        self.inner.lock().unwrap()
+    pub fn lock(&self) -> DynamicGuard<'_, T> {
+        #[cfg(debug_assertions)]
+        let held = reentrancy::Held::enter::<T>(self.id());
+        DynamicGuard {
+            guard: self.inner.lock().unwrap(),
+            #[cfg(debug_assertions)]
+            _held: held,
+        }
+    }
+
+    /// Address of the shared value, identical for every clone.
+    pub(crate) fn id(&self) -> usize {
+        Arc::as_ptr(&self.inner) as *const () as usize
+    }
+}
+
+/// Keeps a callback registered with `Dynamic::observe` running.
+///
+/// Dropping the subscription unregisters the callback: it is not called for any
+/// later `set`.
+#[must_use = "dropping a Subscription unregisters its callback right away"]
+pub struct Subscription {
+    active: Arc<AtomicBool>,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.active.store(false, Ordering::Release);
+    }
+}
+
+/// Guard returned by `Dynamic::lock`; the value stays locked while it is alive.
+pub struct DynamicGuard<'a, T> {
+    guard: MutexGuard<'a, T>,
+    // Declared after `guard` so the lock is released before the marker is cleared.
+    #[cfg(debug_assertions)]
+    _held: reentrancy::Held,
+}
+
+impl<T> Deref for DynamicGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+impl<T> DerefMut for DynamicGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+/// Debug-build detection of a thread re-locking a `Dynamic` it already holds.
+#[cfg(debug_assertions)]
+mod reentrancy {
+    use std::cell::RefCell;
+
+    thread_local! {
+        /// Addresses of the `Dynamic` values locked by the current thread.
+        static HELD: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Marks a `Dynamic` as locked by the current thread until dropped.
+    pub(super) struct Held(usize);
+
+    impl Held {
+        /// Panics if the current thread already holds the `Dynamic` at `address`.
+        pub(super) fn enter<T>(address: usize) -> Self {
+            HELD.with(|held| {
+                if held.borrow().contains(&address) {
+                    panic!(
+                        "Dynamic<{}> was locked again on a thread that already holds its lock, \
+                         which would deadlock; a compute closure or callback probably locks a \
+                         value that is still locked, e.g. `*a.lock() + *a.lock()`",
+                        std::any::type_name::<T>()
+                    );
+                }
+                held.borrow_mut().push(address);
+            });
+            Held(address)
+        }
+    }
+
+    impl Drop for Held {
+        fn drop(&mut self) {
+            HELD.with(|held| {
+                let mut held = held.borrow_mut();
+                if let Some(index) = held.iter().rposition(|&address| address == self.0) {
+                    held.swap_remove(index);
+                }
+            });
+        }
     }
 }
 
@@ -76,6 +188,40 @@ impl<T: Clone + Send + 'static> Dynamic<T> {
         Self {
             inner: Arc::new(Mutex::new(initial)),
             notifiers: Arc::new(PLMutex::new(Vec::new())),
+            watchers: Arc::new(PLMutex::new(Vec::new())),
+            eq: None,
+        }
+    }
+
+    /// Creates a new `Dynamic` whose `set` is ignored when `eq` considers the new
+    /// value equal to the current one.
+    ///
+    /// A plain `Dynamic` treats every `set` as a change. With a tolerance-based
+    /// comparator, tiny float jitter neither notifies listeners nor recomputes
+    /// dependent `Derived` values. An ignored value is not stored either, so
+    /// repeated small steps cannot drift the value away unnoticed.
+    ///
+    /// # Arguments
+    /// * `initial` - The initial value to store in the `Dynamic`.
+    /// * `eq` - Returns `true` when the current and new value count as equal.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::Dynamic;
+    ///
+    /// let voltage = Dynamic::new_with_eq(1.0_f64, |a, b| (a - b).abs() < 1e-3);
+    /// voltage.set(1.0004);
+    /// assert_eq!(voltage.get(), 1.0);
+    /// voltage.set(1.2);
+    /// assert_eq!(voltage.get(), 1.2);
+    /// ```
+    pub fn new_with_eq<F>(initial: T, eq: F) -> Self
+    where
+        F: Fn(&T, &T) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            eq: Some(Arc::new(eq)),
+            ..Self::new(initial)
         }
     }
 
@@ -92,12 +238,13 @@ impl<T: Clone + Send + 'static> Dynamic<T> {
     /// assert_eq!(value.get(), 42);
     /// ```
     pub fn get(&self) -> T {
-        self.inner.lock().unwrap().clone()
+        self.lock().clone()
     }
 
     /// Sets a new value.
     ///
-    /// This method updates the stored value and notifies all registered listeners.
+    /// This method updates the stored value and notifies all registered listeners,
+    /// unless a comparator given to `new_with_eq` finds it equal to the current value.
     ///
     /// # Arguments
     /// * `value` - The new value to set.
@@ -111,7 +258,14 @@ impl<T: Clone + Send + 'static> Dynamic<T> {
     /// assert_eq!(value.get(), 84);
     /// ```
     pub fn set(&self, value: T) {
-        let mut guard = self.inner.lock().unwrap();
+        let mut guard = self.lock();
+        if self.eq.as_ref().is_some_and(|eq| eq(&guard, &value)) {
+            return;
+        }
+        // Sent while the lock is held so watchers observe values in `set` order
+        self.watchers
+            .lock()
+            .retain(|watcher| watcher.send(value.clone()).is_ok());
         *guard = value;
 
         // Notify all listeners
@@ -121,6 +275,384 @@ impl<T: Clone + Send + 'static> Dynamic<T> {
     }
 }
 
+impl<T: Clone + Send + 'static> Dynamic<T> {
+    /// Calls `f` with every value passed to `set`, in order, on a dedicated thread
+    /// (or from `SignalRegistry::tick` on `wasm32`).
+    ///
+    /// Unlike `on_change`, which only signals that *something* changed, no
+    /// intermediate values are skipped when several sets happen in quick succession.
+    pub(crate) fn watch<F>(&self, f: F)
+    where
+        F: Fn(T) + Send + 'static,
+    {
+        let (tx, rx) = channel();
+        self.watchers.lock().push(tx);
+        listener::spawn(rx, f);
+    }
+
+    /// Calls `f` with every value passed to `set`, until the returned
+    /// `Subscription` is dropped.
+    ///
+    /// An escape hatch for side effects such as logging or persisting a value,
+    /// without building a `Derived`. Like `on_change`, `f` runs on a dedicated
+    /// thread (or from `SignalRegistry::tick` on `wasm32`), but it receives the
+    /// new value and no value is skipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::Dynamic;
+    ///
+    /// let count = Dynamic::new(0);
+    /// let logging = count.observe(|count| println!("count is now {count}"));
+    /// count.set(1);
+    ///
+    /// drop(logging); // later sets are no longer logged
+    /// count.set(2);
+    /// ```
+    pub fn observe<F>(&self, f: F) -> Subscription
+    where
+        F: Fn(&T) + Send + 'static,
+    {
+        let active = Arc::new(AtomicBool::new(true));
+        let (tx, rx) = channel();
+        self.watchers.lock().push(tx);
+        let still_active = active.clone();
+        listener::spawn_while(rx, move |value: T| {
+            let active = still_active.load(Ordering::Acquire);
+            if active {
+                f(&value);
+            }
+            active
+        });
+        Subscription { active }
+    }
+
+    /// Folds every change of this value into an accumulator.
+    ///
+    /// `f` receives the previous accumulator and the newly set value and runs
+    /// exactly once per `set`; the returned `Derived` holds the latest result.
+    /// This is handy for running sums, counters or other statistics that would
+    /// otherwise require keeping a history `Vec` around.
+    ///
+    /// # Arguments
+    /// * `init` - The initial accumulator value.
+    /// * `f` - Combines the current accumulator with a new value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::Dynamic;
+    ///
+    /// let sample = Dynamic::new(0);
+    /// let total = sample.scan(0, |sum, value| sum + value);
+    ///
+    /// sample.set(3);
+    /// sample.set(4);
+    /// std::thread::sleep(std::time::Duration::from_millis(50));
+    /// assert_eq!(total.get(), 7);
+    /// ```
+    pub fn scan<A, F>(&self, init: A, f: F) -> Derived<A>
+    where
+        A: Clone + Send + Sync + 'static,
+        F: Fn(&A, &T) -> A + Send + 'static,
+    {
+        let derived = Derived::detached(init).with_sources(&[Some(self.id())]);
+        let target = derived.clone();
+        self.watch(move |value| {
+            let next = f(&target.get(), &value);
+            target.replace(next);
+        });
+        derived
+    }
+
+    /// The largest value this `Dynamic` has ever held, its initial value included.
+    ///
+    /// Unlike a maximum over a history buffer, the peak is kept after the value
+    /// drops again, e.g. to scale a plot axis to everything seen so far. Values
+    /// that do not compare, like a NaN `f64`, are skipped, so start from a
+    /// comparable value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::Dynamic;
+    ///
+    /// let temperature = Dynamic::new(20.0);
+    /// let peak = temperature.running_max();
+    ///
+    /// for reading in [35.0, 80.0, 42.0] {
+    ///     temperature.set(reading);
+    /// }
+    /// std::thread::sleep(std::time::Duration::from_millis(50));
+    /// assert_eq!(peak.get(), 80.0);
+    /// ```
+    pub fn running_max(&self) -> Derived<T>
+    where
+        T: PartialOrd + Sync,
+    {
+        self.scan(self.get(), |max, value| {
+            if value > max { value } else { max }.clone()
+        })
+    }
+
+    /// The smallest value this `Dynamic` has ever held, its initial value included.
+    ///
+    /// The counterpart of `running_max`, with the same handling of values that do
+    /// not compare.
+    pub fn running_min(&self) -> Derived<T>
+    where
+        T: PartialOrd + Sync,
+    {
+        self.scan(self.get(), |min, value| {
+            if value < min { value } else { min }.clone()
+        })
+    }
+
+    /// Counts how many distinct values this `Dynamic` has held, its initial value
+    /// included.
+    ///
+    /// Every value passed to `set` is remembered, so the count only grows; e.g.
+    /// for counting the distinct error codes seen on a stream. Memory grows with
+    /// the number of distinct values.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::Dynamic;
+    ///
+    /// let error_code = Dynamic::new(404);
+    /// let distinct = error_code.distinct_count();
+    ///
+    /// for code in [500, 404, 503] {
+    ///     error_code.set(code);
+    /// }
+    /// std::thread::sleep(std::time::Duration::from_millis(50));
+    /// assert_eq!(distinct.get(), 3);
+    /// ```
+    pub fn distinct_count(&self) -> Derived<usize>
+    where
+        T: Hash + Eq,
+    {
+        let seen = Mutex::new(HashSet::from([self.get()]));
+        let derived = Derived::detached(1).with_sources(&[Some(self.id())]);
+        let target = derived.clone();
+        self.watch(move |value| {
+            let mut seen = seen.lock().unwrap();
+            seen.insert(value);
+            target.replace(seen.len());
+        });
+        derived
+    }
+
+    /// Maps this value through a pure function, caching the result for every
+    /// distinct input.
+    ///
+    /// `f` runs once per distinct value; setting a value that was seen before
+    /// takes its result from the cache instead, so switching back and forth
+    /// between a few inputs of an expensive lookup is instant. The cache is
+    /// never evicted, so memory grows with the number of distinct values.
+    ///
+    /// # Arguments
+    /// * `f` - Computes the result for an input; it must not depend on anything else.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::Dynamic;
+    ///
+    /// let channel = Dynamic::new(1u8);
+    /// let gain = channel.memoized_map(|channel| f64::from(*channel).sqrt());
+    /// assert_eq!(gain.get(), 1.0);
+    ///
+    /// channel.set(4);
+    /// channel.set(1); // served from the cache
+    /// std::thread::sleep(std::time::Duration::from_millis(50));
+    /// assert_eq!(gain.get(), 1.0);
+    /// ```
+    pub fn memoized_map<V, F>(&self, f: F) -> Derived<V>
+    where
+        T: Hash + Eq,
+        V: Clone + Send + Sync + 'static,
+        F: Fn(&T) -> V + Send + 'static,
+    {
+        let initial = self.get();
+        let first = f(&initial);
+        let cache = Mutex::new(HashMap::from([(initial, first.clone())]));
+        let derived = Derived::detached(first).with_sources(&[Some(self.id())]);
+        let target = derived.clone();
+        self.watch(move |value| {
+            let mut cache = cache.lock().unwrap();
+            let result = cache.entry(value).or_insert_with_key(|value| f(value));
+            target.replace(result.clone());
+        });
+        derived
+    }
+
+    /// Formats this value into a `Derived<String>` that is re-rendered on every `set`.
+    ///
+    /// Keeps label formatting in one place instead of a `format!` per frame, and
+    /// pairs with `Derived::bind_label` (with the `widgets` feature) to show it.
+    ///
+    /// # Arguments
+    /// * `fmt` - Renders a value as text.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::Dynamic;
+    ///
+    /// let temperature = Dynamic::new(21.5);
+    /// let text = temperature.to_display(|t| format!("{t:.1} °C"));
+    /// assert_eq!(text.get(), "21.5 °C");
+    ///
+    /// temperature.set(23.0);
+    /// std::thread::sleep(std::time::Duration::from_millis(50));
+    /// assert_eq!(text.get(), "23.0 °C");
+    /// ```
+    pub fn to_display<F>(&self, fmt: F) -> Derived<String>
+    where
+        F: Fn(&T) -> String + Send + 'static,
+    {
+        let derived = Derived::detached(fmt(&self.lock())).with_sources(&[Some(self.id())]);
+        let target = derived.clone();
+        self.watch(move |value| target.replace(fmt(&value)));
+        derived
+    }
+}
+
+#[cfg(feature = "signals")]
+impl<T: Clone + Send + Sync + 'static> Dynamic<T> {
+    /// Creates a `Dynamic` whose value is folded from the messages arriving on a slot.
+    ///
+    /// The slot is started on its own worker, and `reducer` applies each message to
+    /// the current value, which is then `set` so listeners and `Derived` values
+    /// update as usual. This bridges a plain `Signal<E>` event stream into
+    /// reactive state. Requires the `signals` feature.
+    ///
+    /// # Arguments
+    /// * `slot` - The receiving end of the event stream.
+    /// * `init` - The initial value.
+    /// * `reducer` - Applies one message to the value.
+    ///
+    /// # Returns
+    /// The new `Dynamic`, or the `MobiusError` raised while starting `slot`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius::factory::create_signal_slot;
+    /// use egui_mobius_reactive::Dynamic;
+    ///
+    /// #[derive(Clone)]
+    /// enum Event { Add(i32), Reset }
+    ///
+    /// let (signal, slot) = create_signal_slot::<Event>();
+    /// let total = Dynamic::from_signal(slot, 0, |total, event| match event {
+    ///     Event::Add(n) => *total += n,
+    ///     Event::Reset => *total = 0,
+    /// })
+    /// .unwrap();
+    ///
+    /// signal.send(Event::Add(5)).unwrap();
+    /// std::thread::sleep(std::time::Duration::from_millis(50));
+    /// assert_eq!(total.get(), 5);
+    /// ```
+    pub fn from_signal<E, F>(mut slot: Slot<E>, init: T, reducer: F) -> Result<Self, MobiusError>
+    where
+        E: Send + Clone + 'static,
+        F: Fn(&mut T, E) + Send + 'static,
+    {
+        let dynamic = Self::new(init);
+        let target = dynamic.clone();
+        slot.start(move |event| {
+            let mut next = target.get();
+            reducer(&mut next, event);
+            target.set(next);
+        })?;
+        Ok(dynamic)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Dynamic<Dynamic<T>> {
+    /// Follows whichever inner `Dynamic` this value currently holds.
+    ///
+    /// The returned `Derived` tracks the changes of the current inner value. When
+    /// a different inner value is `set`, it takes that value's current contents
+    /// right away and from then on only follows the new one; late changes of the
+    /// previous inner value are ignored. Useful for swappable sources, such as
+    /// selecting which sensor feeds a plot.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::Dynamic;
+    ///
+    /// let inlet = Dynamic::new(21.0);
+    /// let exhaust = Dynamic::new(48.0);
+    /// let selected = Dynamic::new(inlet.clone());
+    /// let reading = selected.flatten();
+    /// assert_eq!(reading.get(), 21.0);
+    ///
+    /// selected.set(exhaust.clone());
+    /// std::thread::sleep(std::time::Duration::from_millis(50));
+    /// assert_eq!(reading.get(), 48.0);
+    /// ```
+    pub fn flatten(&self) -> Derived<T> {
+        // Held until the swap watcher is registered, so no `set` slips in between
+        let current = self.lock();
+        let derived = Derived::detached(current.get()).with_sources(&[Some(self.id())]);
+        let followed = Arc::new(PLMutex::new(Followed {
+            generation: 0,
+            subscription: None,
+        }));
+        follow(&current, &derived, &followed);
+
+        let target = derived.clone();
+        self.watch(move |inner: Dynamic<T>| follow(&inner, &target, &followed));
+        drop(current);
+        derived
+    }
+}
+
+/// The inner value a `flatten`ed `Derived` currently follows.
+struct Followed {
+    /// Bumped on every swap, so a late change of a previous inner value is dropped.
+    generation: usize,
+    subscription: Option<Subscription>,
+}
+
+/// Switches `target` over to `inner`, unsubscribing from the previous inner value.
+fn follow<T: Clone + Send + Sync + 'static>(
+    inner: &Dynamic<T>,
+    target: &Derived<T>,
+    followed: &Arc<PLMutex<Followed>>,
+) {
+    let mut state = followed.lock();
+    state.generation += 1;
+    let generation = state.generation;
+
+    let (observer_target, observer_followed) = (target.clone(), followed.clone());
+    let subscription = inner.observe(move |value| {
+        if observer_followed.lock().generation == generation {
+            observer_target.replace(value.clone());
+        }
+    });
+    target.replace(inner.get());
+    state.subscription = Some(subscription);
+}
+
+/// Serializes the current value, as if the `Dynamic` were a plain `T`.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Dynamic<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.lock().serialize(serializer)
+    }
+}
+
+/// Deserializes a plain `T` into a new `Dynamic` without any listeners.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Dynamic<T>
+where
+    T: serde::Deserialize<'de> + Clone + Send + 'static,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Dynamic::new)
+    }
+}
+
 impl<T: PartialEq> PartialEq for Dynamic<T> {
     /// Compares two `Value` instances for equality.
     ///
@@ -160,6 +692,7 @@ pub trait ValueExt<T: Clone + Send + Sync + 'static> {
     /// Registers a callback to be called when the value changes.
     ///
     /// The callback is invoked in a dedicated background thread that waits for change notifications.
+    /// On `wasm32` targets it is invoked from `SignalRegistry::tick` instead.
     ///
     /// # Arguments
     /// * `callback` - The callback function to invoke when the value changes.
@@ -196,12 +729,8 @@ impl<T: Clone + Send + Sync + PartialEq + 'static> ValueExt<T> for Dynamic<T> {
         // Add the sender to our notifiers
         self.notifiers.lock().push(tx);
 
-        // Spawn a background thread to wait for notifications
-        thread::spawn(move || {
-            while rx.recv().is_ok() {
-                cb_clone();
-            }
-        });
+        // Wait for notifications on a background thread (or the frame tick on wasm)
+        listener::spawn(rx, move |()| cb_clone());
 
         cb
     }
@@ -216,6 +745,10 @@ impl<T: Clone + Send + Sync + PartialEq + 'static> ReactiveValue for Dynamic<T>
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn node_id(&self) -> Option<usize> {
+        Some(self.id())
+    }
 }
 
 /// Converts a `Dynamic<T>` to a `Dynamic<U>` where `T` can be converted to `U`.
@@ -269,8 +802,162 @@ mod tests {
     use crate::ValueExt;
     use std::sync::Arc;
     use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
     use std::time::Duration; // Import the ValueExt trait
 
+    /// Tests that `from_signal` reduces every event sent through the signal.
+    #[cfg(feature = "signals")]
+    #[test]
+    fn test_from_signal_reduces_events() {
+        #[derive(Clone)]
+        enum Event {
+            Push(i32),
+            Clear,
+        }
+
+        let (signal, slot) = egui_mobius::factory::create_signal_slot::<Event>();
+        let items = Dynamic::from_signal(slot, Vec::new(), |items, event| match event {
+            Event::Push(n) => items.push(n),
+            Event::Clear => items.clear(),
+        })
+        .unwrap();
+        let source = items.clone();
+        let len = Derived::new(&[Arc::new(items.clone())], move || source.lock().len());
+
+        for event in [Event::Push(1), Event::Clear, Event::Push(2), Event::Push(3)] {
+            signal.send(event).unwrap();
+        }
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(items.get(), vec![2, 3]);
+        assert_eq!(len.get(), 2);
+    }
+
+    /// Tests that a custom comparator suppresses changes within tolerance.
+    #[test]
+    fn test_new_with_eq_ignores_jitter() {
+        use std::sync::atomic::AtomicUsize;
+
+        let reading = Dynamic::new_with_eq(10.0_f64, |a, b| (a - b).abs() < 0.01);
+        let notified = Arc::new(AtomicUsize::new(0));
+        let notified_clone = notified.clone();
+        reading.on_change(move || {
+            notified_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        reading.set(10.004);
+        reading.set(9.995);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(notified.load(Ordering::SeqCst), 0);
+        assert_eq!(reading.get(), 10.0);
+
+        reading.set(10.5);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(notified.load(Ordering::SeqCst), 1);
+        assert_eq!(reading.get(), 10.5);
+    }
+
+    /// Tests that a compute closure re-locking a held dependency panics instead of hanging.
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(
+        expected = "Dynamic<i32> was locked again on a thread that already holds its lock"
+    )]
+    fn test_reentrant_lock_panics() {
+        let count = Dynamic::new(2);
+        let alias = count.clone();
+        let dep = count.clone();
+        let _squared = Derived::new(&[crate::dep(&dep)], move || *count.lock() * *alias.lock());
+    }
+
+    /// Tests that an observer sees every set value until its subscription drops.
+    #[test]
+    fn test_observe_stops_after_subscription_drops() {
+        let count = Dynamic::new(0);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let subscription = count.observe(move |value| seen_clone.lock().unwrap().push(*value));
+
+        count.set(1);
+        count.set(2);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(*seen.lock().unwrap(), vec![1, 2]);
+
+        drop(subscription);
+        count.set(3);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(*seen.lock().unwrap(), vec![1, 2]);
+    }
+
+    /// Tests that a struct of `Dynamic` fields round-trips through JSON.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Settings {
+            volume: Dynamic<u8>,
+            device: Dynamic<String>,
+        }
+
+        let settings = Settings {
+            volume: Dynamic::new(7),
+            device: Dynamic::new("default".to_string()),
+        };
+        settings.volume.set(11);
+
+        let json = serde_json::to_string(&settings).unwrap();
+        assert_eq!(json, r#"{"volume":11,"device":"default"}"#);
+
+        let loaded: Settings = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.volume.get(), 11);
+        assert_eq!(loaded.device.get(), "default");
+
+        // Derived values serialize as their current value
+        let source = settings.volume.clone();
+        let doubled = Derived::new(&[Arc::new(settings.volume.clone())], move || {
+            u16::from(*source.lock()) * 2
+        });
+        assert_eq!(serde_json::to_string(&doubled).unwrap(), "22");
+    }
+
+    /// Tests that `to_display` re-renders its text on every `set`.
+    #[test]
+    fn test_to_display_follows_value() {
+        let count = Dynamic::new(1);
+        let text = count.to_display(|c| format!("Count: {c}"));
+        assert_eq!(text.get(), "Count: 1");
+
+        count.set(2);
+        count.set(3);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(text.get(), "Count: 3");
+    }
+
+    /// Tests that `flatten` switches to a new inner value and ignores the old one.
+    #[test]
+    fn test_flatten_follows_swapped_inner() {
+        let first = Dynamic::new(1);
+        let second = Dynamic::new(10);
+        let selected = Dynamic::new(first.clone());
+        let flat = selected.flatten();
+        assert_eq!(flat.get(), 1);
+
+        first.set(2);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(flat.get(), 2);
+
+        selected.set(second.clone());
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(flat.get(), 10);
+
+        first.set(3);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(flat.get(), 10);
+
+        second.set(11);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(flat.get(), 11);
+    }
+
     /// Tests the `get` and `set` methods of the `Dynamic` struct.
     #[test]
     fn test_value_get_set() {
@@ -325,4 +1012,77 @@ mod tests {
         thread::sleep(Duration::from_millis(50));
         assert!(changed.load(Ordering::SeqCst));
     }
+
+    /// Tests that `distinct_count` counts each value once.
+    #[test]
+    fn test_distinct_count_ignores_repeats() {
+        let source = Dynamic::new(1);
+        let distinct = source.distinct_count();
+        assert_eq!(distinct.get(), 1);
+
+        for value in [2, 2, 3, 1] {
+            source.set(value);
+        }
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(distinct.get(), 3);
+    }
+
+    /// Tests that `running_max` and `running_min` keep the extremes once passed.
+    #[test]
+    fn test_running_extremes_keep_the_peak() {
+        let sample = Dynamic::new(5);
+        let max = sample.running_max();
+        let min = sample.running_min();
+        assert_eq!((min.get(), max.get()), (5, 5));
+
+        for value in [6, 9, 12, 8, 3, 7] {
+            sample.set(value);
+        }
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(max.get(), 12);
+        assert_eq!(min.get(), 3);
+    }
+
+    /// Tests that `memoized_map` computes each distinct input only once.
+    #[test]
+    fn test_memoized_map_computes_each_input_once() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counted = calls.clone();
+        let input = Dynamic::new(2);
+        let squared = input.memoized_map(move |n| {
+            counted.fetch_add(1, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(20)); // an expensive lookup
+            n * n
+        });
+        assert_eq!(squared.get(), 4);
+
+        for value in [3, 2, 3, 5, 2] {
+            input.set(value);
+        }
+        thread::sleep(Duration::from_millis(200));
+        assert_eq!(squared.get(), 4);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        input.set(5);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(squared.get(), 25); // served from the cache
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    /// Tests that `scan` folds every set value into a running sum.
+    #[test]
+    fn test_scan_running_sum() {
+        let sample = Dynamic::new(0);
+        let total = sample.scan(0, |sum, value| sum + value);
+        let count = sample.scan(0usize, |count, _| count + 1);
+        assert_eq!(total.get(), 0);
+
+        for value in [1, 2, 3, 4, 5] {
+            sample.set(value);
+        }
+        thread::sleep(Duration::from_millis(50));
+
+        assert_eq!(total.get(), 15);
+        assert_eq!(count.get(), 5);
+    }
 }
@@ -0,0 +1,146 @@
+//! Relative time labels ("3s ago", "2m ago") for timestamps, with the `chrono` feature.
+//!
+//! `Dynamic<DateTime<Local>>::elapsed_string` derives a label that is recomputed when
+//! the timestamp changes and also once per `ELAPSED_REFRESH`, so the age of a log
+//! entry or of the last update keeps advancing while the timestamp itself stays put.
+//!
+//! # Example
+//! ```rust
+//! use chrono::{Local, TimeDelta};
+//! use egui_mobius_reactive::Dynamic;
+//!
+//! let last_update = Dynamic::new(Local::now() - TimeDelta::seconds(90));
+//! let age = last_update.elapsed_string();
+//! assert_eq!(age.get(), "1m ago");
+//!
+//! last_update.set(Local::now());
+//! # std::thread::sleep(std::time::Duration::from_millis(50));
+//! assert_eq!(age.get(), "0s ago");
+//! ```
+
+use crate::reactive::derived::Derived;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::reactive::derived::WeakDerived;
+use crate::reactive::dynamic::Dynamic;
+use chrono::{DateTime, Local, TimeDelta};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::{Arc, Mutex, Weak};
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// How often an `elapsed_string` label is refreshed without a change of its source.
+pub const ELAPSED_REFRESH: Duration = Duration::from_secs(1);
+
+impl Dynamic<DateTime<Local>> {
+    /// A label with the time since the stored timestamp, like "3s ago" or "2h ago".
+    ///
+    /// Besides following `set`, the label is refreshed every `ELAPSED_REFRESH` by a
+    /// background thread, which ends once the label is dropped; it does not keep
+    /// the timestamp alive either. On `wasm32`, without threads, it only follows
+    /// `set`. Pair it with a periodic repaint, e.g.
+    /// `ctx.request_repaint_after(ELAPSED_REFRESH)`, to show the updates.
+    pub fn elapsed_string(&self) -> Derived<String> {
+        self.elapsed_label().0
+    }
+
+    /// `elapsed_string` together with its refresh thread, if there is one.
+    fn elapsed_label(&self) -> (Derived<String>, Option<RefreshThread>) {
+        let timestamp = *self.lock();
+        let derived = Derived::detached(format_elapsed(Local::now() - timestamp))
+            .with_sources(&[Some(self.id())]);
+        // Neither the listener nor the refresher may keep the label alive
+        let target = derived.downgrade();
+        self.watch(move |timestamp| {
+            if let Some(target) = target.upgrade() {
+                target.replace(format_elapsed(Local::now() - timestamp));
+            }
+        });
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let refresher = Some(spawn_refresher(
+            derived.downgrade(),
+            Arc::downgrade(&self.inner),
+            timestamp,
+        ));
+        #[cfg(target_arch = "wasm32")]
+        let refresher = None;
+        (derived, refresher)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type RefreshThread = JoinHandle<()>;
+#[cfg(target_arch = "wasm32")]
+type RefreshThread = ();
+
+/// Refreshes `target` every `ELAPSED_REFRESH` until it is dropped.
+///
+/// `source` is only read while it exists: a dropped timestamp cannot change any
+/// more, so the label keeps counting from its last value.
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_refresher(
+    target: WeakDerived<String>,
+    source: Weak<Mutex<DateTime<Local>>>,
+    mut timestamp: DateTime<Local>,
+) -> JoinHandle<()> {
+    thread::spawn(move || {
+        loop {
+            thread::sleep(ELAPSED_REFRESH);
+            let Some(target) = target.upgrade() else {
+                break;
+            };
+            if let Some(source) = source.upgrade() {
+                timestamp = *source.lock().unwrap();
+            }
+            target.replace(format_elapsed(Local::now() - timestamp));
+        }
+    })
+}
+
+/// Renders `elapsed` in its largest whole unit, e.g. "45s ago", "2m ago" or "3d ago".
+///
+/// Timestamps in the future count as "0s ago".
+pub fn format_elapsed(elapsed: TimeDelta) -> String {
+    let seconds = elapsed.num_seconds().max(0);
+    match seconds {
+        0..60 => format!("{seconds}s ago"),
+        60..3_600 => format!("{}m ago", seconds / 60),
+        3_600..86_400 => format!("{}h ago", seconds / 3_600),
+        _ => format!("{}d ago", seconds / 86_400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_advances_without_source_change() {
+        assert_eq!(format_elapsed(TimeDelta::seconds(-5)), "0s ago");
+        assert_eq!(format_elapsed(TimeDelta::seconds(59)), "59s ago");
+        assert_eq!(format_elapsed(TimeDelta::seconds(125)), "2m ago");
+        assert_eq!(format_elapsed(TimeDelta::hours(5)), "5h ago");
+        assert_eq!(format_elapsed(TimeDelta::days(3)), "3d ago");
+
+        let logged_at = Dynamic::new(Local::now());
+        let age = logged_at.elapsed_string();
+        assert_eq!(age.get(), "0s ago");
+
+        // The timestamp is never set again, yet the label moves on
+        thread::sleep(ELAPSED_REFRESH * 2 + Duration::from_millis(100));
+        assert_ne!(age.get(), "0s ago");
+    }
+
+    #[test]
+    fn test_refresher_ends_with_label_and_timestamp() {
+        let logged_at = Dynamic::new(Local::now());
+        let (age, refresher) = logged_at.elapsed_label();
+        let refresher = refresher.unwrap();
+
+        drop(age);
+        drop(logged_at);
+        thread::sleep(ELAPSED_REFRESH + Duration::from_millis(200));
+        assert!(refresher.is_finished());
+    }
+}
@@ -0,0 +1,307 @@
+//! ReactiveGraphView – an interactive node view of a `SignalRegistry`.
+//!
+//! Every registered signal is drawn as a node showing its name, its type and its
+//! current value; an arrow runs from each value to the values computed from it.
+//! Nodes start out in columns by dependency depth (sources on the left) and can be
+//! dragged around; dragging the background pans the whole graph.
+//!
+//! Values of the common scalar types and `String` are labeled out of the box, for
+//! `Dynamic`, `Derived` and `ReactiveList`. Other types can be labeled with
+//! `with_formatter`.
+//!
+//! # Example
+//! ```rust,no_run
+//! use egui_mobius_reactive::{ReactiveGraphView, SignalRegistry};
+//!
+//! struct Debugger {
+//!     registry: SignalRegistry,
+//!     graph_view: ReactiveGraphView,
+//! }
+//!
+//! impl Debugger {
+//!     fn ui(&mut self, ctx: &egui::Context) {
+//!         egui::Window::new("Reactive graph").show(ctx, |ui| {
+//!             self.graph_view.show(ui, &self.registry);
+//!         });
+//!     }
+//! }
+//! ```
+use crate::reactive::core::ReactiveList;
+use crate::reactive::derived::Derived;
+use crate::reactive::dynamic::Dynamic;
+use crate::reactive::registry::{ReactiveGraph, SignalRegistry};
+use egui::{Align2, Color32, CornerRadius, FontId, Rect, Sense, Stroke, Ui, Vec2};
+use std::any::Any;
+use std::collections::HashMap;
+
+/// Renders a registered value as text, or returns `None` if it has another type.
+type ValueFormatter = Box<dyn Fn(&dyn Any) -> Option<String> + Send + Sync>;
+
+/// Size of a node card.
+const NODE_SIZE: Vec2 = Vec2::new(160.0, 58.0);
+/// Distance between the columns and rows of the initial layout.
+const SPACING: Vec2 = Vec2::new(210.0, 78.0);
+/// Longest value label shown before it is cut off.
+const MAX_VALUE_CHARS: usize = 24;
+
+/// A draggable node-graph view of the signals in a `SignalRegistry`.
+///
+/// Keep the view in the application state: it remembers where each node was
+/// dragged to.
+#[derive(Default)]
+pub struct ReactiveGraphView {
+    /// Node positions relative to the canvas origin, by signal name.
+    positions: HashMap<String, Vec2>,
+    pan: Vec2,
+    formatters: Vec<ValueFormatter>,
+}
+
+impl ReactiveGraphView {
+    /// Creates a view with the built-in value labels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Labels registered values of type `V`, e.g. `Dynamic<MyState>`, with `format`.
+    ///
+    /// Custom formatters are tried before the built-in ones.
+    pub fn with_formatter<V: 'static>(
+        mut self,
+        format: impl Fn(&V) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.formatters
+            .push(Box::new(move |any| any.downcast_ref::<V>().map(&format)));
+        self
+    }
+
+    /// Forget dragged positions and panning, restoring the layered layout.
+    pub fn reset_layout(&mut self) {
+        self.positions.clear();
+        self.pan = Vec2::ZERO;
+    }
+
+    /// Shows the graph of `registry`, filling the available space.
+    pub fn show(&mut self, ui: &mut Ui, registry: &SignalRegistry) -> egui::Response {
+        let graph = registry.graph();
+        let values: HashMap<String, Option<String>> = registry
+            .list_signals()
+            .into_iter()
+            .map(|(name, signal)| {
+                let label = self.value_label(signal.as_any());
+                (name, label)
+            })
+            .collect();
+
+        for (name, depth, row) in initial_layout(&graph) {
+            self.positions
+                .entry(name)
+                .or_insert_with(|| Vec2::new(depth as f32 * SPACING.x, row as f32 * SPACING.y));
+        }
+
+        let size = Vec2::new(ui.available_width(), ui.available_height().max(240.0));
+        let (canvas, painter) = ui.allocate_painter(size, Sense::drag());
+        if canvas.dragged() {
+            self.pan += canvas.drag_delta();
+        }
+        let origin = canvas.rect.min + Vec2::splat(12.0) + self.pan;
+        let painter = painter.with_clip_rect(canvas.rect);
+        let visuals = ui.visuals().clone();
+
+        // Nodes are interacted with first, so edges follow a node dragged this frame
+        let mut rects = HashMap::new();
+        for node in &graph.nodes {
+            let offset = self.positions[&node.name];
+            let rect = Rect::from_min_size(origin + offset, NODE_SIZE);
+            let response = ui.interact(rect, canvas.id.with(&node.name), Sense::click_and_drag());
+            if response.dragged() {
+                *self.positions.get_mut(&node.name).unwrap() += response.drag_delta();
+            }
+            let rect = rect.translate(response.drag_delta());
+            rects.insert(node.name.as_str(), (rect, response.hovered()));
+        }
+
+        let edge_stroke = Stroke::new(1.5, visuals.weak_text_color());
+        for edge in &graph.edges {
+            let (Some((from, _)), Some((to, _))) =
+                (rects.get(edge.from.as_str()), rects.get(edge.to.as_str()))
+            else {
+                continue;
+            };
+            let start = from.right_center();
+            let end = to.left_center();
+            painter.arrow(start, end - start, edge_stroke);
+        }
+
+        for node in &graph.nodes {
+            let (rect, hovered) = rects[node.name.as_str()];
+            let border = if hovered {
+                visuals.selection.stroke.color
+            } else {
+                visuals.widgets.noninteractive.bg_stroke.color
+            };
+            painter.rect_filled(rect, CornerRadius::same(6), visuals.extreme_bg_color);
+            painter.rect_stroke(
+                rect,
+                CornerRadius::same(6),
+                Stroke::new(1.0, border),
+                egui::StrokeKind::Inside,
+            );
+
+            let text = rect.left_top() + Vec2::new(8.0, 6.0);
+            painter.text(
+                text,
+                Align2::LEFT_TOP,
+                &node.name,
+                FontId::proportional(14.0),
+                visuals.strong_text_color(),
+            );
+            painter.text(
+                text + Vec2::new(0.0, 18.0),
+                Align2::LEFT_TOP,
+                short_type_name(node.type_name),
+                FontId::monospace(10.0),
+                visuals.weak_text_color(),
+            );
+            let value = values.get(&node.name).cloned().flatten();
+            painter.text(
+                text + Vec2::new(0.0, 32.0),
+                Align2::LEFT_TOP,
+                value.as_deref().map_or("…".to_string(), truncate),
+                FontId::monospace(12.0),
+                Color32::from_rgb(100, 200, 255),
+            );
+        }
+
+        canvas
+    }
+
+    /// The current value of a registered signal as text, if its type is known.
+    fn value_label(&self, any: &dyn Any) -> Option<String> {
+        self.formatters
+            .iter()
+            .find_map(|format| format(any))
+            .or_else(|| builtin_label(any))
+    }
+}
+
+/// Labels `Dynamic`, `Derived` and `ReactiveList` values of common types.
+fn builtin_label(any: &dyn Any) -> Option<String> {
+    macro_rules! label_types {
+        ($($t:ty),*) => {$(
+            if let Some(value) = any.downcast_ref::<Dynamic<$t>>() {
+                return Some(format!("{:?}", value.get()));
+            }
+            if let Some(value) = any.downcast_ref::<Derived<$t>>() {
+                return Some(format!("{:?}", value.get()));
+            }
+            if let Some(list) = any.downcast_ref::<ReactiveList<$t>>() {
+                return Some(format!("{:?}", list.get_all()));
+            }
+        )*};
+    }
+    label_types!(bool, i32, i64, u32, u64, usize, f32, f64, String);
+    None
+}
+
+/// Places every node in the column of its dependency depth, sources first, and
+/// numbers the nodes within each column in registration order.
+fn initial_layout(graph: &ReactiveGraph) -> Vec<(String, usize, usize)> {
+    let mut depth: HashMap<&str, usize> = graph
+        .nodes
+        .iter()
+        .map(|node| (node.name.as_str(), 0))
+        .collect();
+    // Longest path from a source; bounded by the node count in case of a cycle
+    for _ in 0..graph.nodes.len() {
+        let mut changed = false;
+        for edge in &graph.edges {
+            let next = depth[edge.from.as_str()] + 1;
+            if next > depth[edge.to.as_str()] {
+                depth.insert(edge.to.as_str(), next);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut rows: HashMap<usize, usize> = HashMap::new();
+    graph
+        .nodes
+        .iter()
+        .map(|node| {
+            let column = depth[node.name.as_str()];
+            let row = rows.entry(column).or_default();
+            *row += 1;
+            (node.name.clone(), column, *row - 1)
+        })
+        .collect()
+}
+
+/// Strips module paths, e.g. `egui_mobius_reactive::reactive::dynamic::Dynamic<i32>`
+/// becomes `Dynamic<i32>`.
+fn short_type_name(full: &str) -> String {
+    full.split_inclusive(['<', '>', ',', ' ', '(', ')', '[', ']', ';', '&'])
+        .map(|part| part.rsplit("::").next().unwrap_or(part))
+        .collect()
+}
+
+fn truncate(value: &str) -> String {
+    match value.char_indices().nth(MAX_VALUE_CHARS) {
+        Some((end, _)) => format!("{}…", &value[..end]),
+        None => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ReactiveMath, dep, reactive};
+
+    #[test]
+    fn test_layout_and_labels_follow_registry() {
+        let registry = SignalRegistry::new();
+        let count = Dynamic::new(2i32);
+        let doubled = count.doubled();
+        let source = doubled.clone();
+        let squared = Derived::new(&[dep(&doubled)], move || source.get().pow(2));
+        let name = Dynamic::new("mobius".to_string());
+        reactive!(registry, "count" => count, "doubled" => doubled, "squared" => squared, "name" => name);
+
+        let layout = initial_layout(&registry.graph());
+        assert_eq!(
+            layout,
+            vec![
+                ("count".to_string(), 0, 0),
+                ("doubled".to_string(), 1, 0),
+                ("squared".to_string(), 2, 0),
+                ("name".to_string(), 0, 1),
+            ]
+        );
+
+        let view = ReactiveGraphView::new().with_formatter(|name: &Dynamic<String>| name.get());
+        let labels: Vec<_> = registry
+            .list_signals()
+            .iter()
+            .map(|(_, signal)| view.value_label(signal.as_any()))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                Some("2".to_string()),
+                Some("4".to_string()),
+                Some("16".to_string()),
+                Some("mobius".to_string())
+            ]
+        );
+
+        assert_eq!(
+            short_type_name(
+                "egui_mobius_reactive::reactive::derived::Derived<alloc::string::String>"
+            ),
+            "Derived<String>"
+        );
+        assert_eq!(truncate(&"x".repeat(30)), format!("{}…", "x".repeat(24)));
+    }
+}
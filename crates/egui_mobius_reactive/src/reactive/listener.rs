@@ -0,0 +1,118 @@
+//! Execution of change listeners registered through `on_change` and friends.
+//!
+//! Natively every listener drains its notification channel on a dedicated thread.
+//! `wasm32` targets have no threads, so listeners are queued instead and run by
+//! `tick`, which `SignalRegistry::tick` exposes to be called once per egui frame.
+
+use std::sync::mpsc::Receiver;
+
+/// Run `f` for every message arriving on `rx` until all senders are gone.
+pub(crate) fn spawn<M, F>(rx: Receiver<M>, mut f: F)
+where
+    M: Send + 'static,
+    F: FnMut(M) + Send + 'static,
+{
+    spawn_while(rx, move |msg| {
+        f(msg);
+        true
+    });
+}
+
+/// Like `spawn`, but also stop (dropping `rx`) as soon as `f` returns `false`.
+pub(crate) fn spawn_while<M, F>(rx: Receiver<M>, mut f: F)
+where
+    M: Send + 'static,
+    F: FnMut(M) -> bool + Send + 'static,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    std::thread::spawn(move || {
+        while let Ok(msg) = rx.recv() {
+            if !f(msg) {
+                break;
+            }
+        }
+    });
+
+    #[cfg(target_arch = "wasm32")]
+    frame::register(Box::new(move || {
+        let mut handled = false;
+        loop {
+            match rx.try_recv() {
+                Ok(msg) => {
+                    handled = true;
+                    if !f(msg) {
+                        return frame::Pumped {
+                            handled,
+                            alive: false,
+                        };
+                    }
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => {
+                    return frame::Pumped {
+                        handled,
+                        alive: true,
+                    };
+                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    return frame::Pumped {
+                        handled,
+                        alive: false,
+                    };
+                }
+            }
+        }
+    }));
+}
+
+/// Run pending listeners until no notifications are left. A no-op natively.
+pub(crate) fn tick() {
+    #[cfg(target_arch = "wasm32")]
+    frame::run();
+}
+
+#[cfg(target_arch = "wasm32")]
+mod frame {
+    use std::cell::RefCell;
+
+    /// Outcome of draining one listener's channel.
+    pub(super) struct Pumped {
+        pub(super) handled: bool,
+        pub(super) alive: bool,
+    }
+
+    type Pump = Box<dyn FnMut() -> Pumped>;
+
+    /// Upper bound on propagation rounds per tick, guarding against listener cycles.
+    const MAX_ROUNDS: usize = 64;
+
+    thread_local! {
+        static PUMPS: RefCell<Vec<Pump>> = RefCell::new(Vec::new());
+    }
+
+    pub(super) fn register(pump: Pump) {
+        PUMPS.with(|pumps| pumps.borrow_mut().push(pump));
+    }
+
+    pub(super) fn run() {
+        // A listener may set other values, so keep going until the graph settles
+        for _ in 0..MAX_ROUNDS {
+            // Take the pumps out so a listener can register new ones while they run
+            let mut pumps = PUMPS.with(|pumps| std::mem::take(&mut *pumps.borrow_mut()));
+            let mut handled = false;
+            pumps.retain_mut(|pump| {
+                let pumped = pump();
+                handled |= pumped.handled;
+                pumped.alive
+            });
+            PUMPS.with(|registered| {
+                let mut registered = registered.borrow_mut();
+                let added_meanwhile = std::mem::replace(&mut *registered, pumps);
+                handled |= !added_meanwhile.is_empty();
+                registered.extend(added_meanwhile);
+            });
+            if !handled {
+                break;
+            }
+        }
+    }
+}
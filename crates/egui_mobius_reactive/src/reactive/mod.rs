@@ -52,17 +52,33 @@
 //! All values in the reactive system are protected by `Arc<Mutex<T>>` for safe concurrent access.
 //! The system spawns dedicated background threads to monitor for changes and update derived values.
 //!
+//! # WebAssembly
+//!
+//! On `wasm32` targets no threads are spawned. Change listeners are queued and run by
+//! `SignalRegistry::tick`, which should be called once per frame (e.g. at the top of
+//! `eframe::App::update`); natively `tick` does nothing, so the call can stay in
+//! cross-platform code.
+//!
 //! # Performance Considerations
 //!
-//! - Change detection uses a polling approach with a 100ms interval
+//! - Change detection is notification based: each listener waits on a channel
 //! - Consider using `parking_lot::Mutex` instead of `std::sync::Mutex` for better performance
 //! - Derived values are only recomputed when their dependencies actually change
 pub mod core;
 pub mod derived;
 pub mod dynamic;
+#[cfg(feature = "chrono")]
+pub mod elapsed;
+#[cfg(feature = "widgets")]
+pub mod graph_view;
+pub(crate) mod listener;
 pub mod prelude;
 pub mod reactive_math;
 pub mod reactive_state;
 pub mod registry;
 #[cfg(feature = "widgets")]
+pub mod repaint;
+pub mod try_derived;
+pub mod validated;
+#[cfg(feature = "widgets")]
 pub mod widgets;
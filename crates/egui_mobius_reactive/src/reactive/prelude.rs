@@ -6,18 +6,37 @@
 //! ```
 
 pub use super::{
-    core::{ReactiveList, ReactiveValue, Subscribers},
+    core::{
+        IndexOutOfBounds, ItemSubscribers, ReactiveDep, ReactiveList, ReactiveValue, Subscribers,
+        dep,
+    },
     derived::Derived,
-    dynamic::{Dynamic, ValueExt},
-    reactive_math::{ReactiveListSum, ReactiveLogic, ReactiveMath, ReactiveString},
+    dynamic::{Dynamic, DynamicGuard, Subscription, ValueExt},
+    reactive_math::{
+        NumberFormat, ReactiveCheckedMath, ReactiveClamp, ReactiveCompare, ReactiveFormat,
+        ReactiveHistogram, ReactiveInteger, ReactiveListSum, ReactiveLogic, ReactiveMath,
+        ReactiveSignedInteger, ReactiveString, select,
+    },
     reactive_state::ReactiveWidgetRef,
-    registry::SignalRegistry,
+    registry::{CleanupFn, EffectHandle, GraphEdge, GraphNode, ReactiveGraph, SignalRegistry},
+    try_derived::TryDerived,
+    validated::ValidatedDynamic,
 };
 
+#[cfg(feature = "signals")]
+pub use super::reactive_math::ReactiveMovingAverage;
+
+#[cfg(feature = "chrono")]
+pub use super::elapsed::{ELAPSED_REFRESH, format_elapsed};
+
 #[cfg(feature = "widgets")]
 pub use super::{
     // Widgets
-    widgets::ReactiveSlider,
+    graph_view::ReactiveGraphView,
+    widgets::{
+        LedState, ReactiveButtonState, ReactiveLed, ReactiveSlider, bind_slider, bind_text_edit,
+        bind_validated_text_edit,
+    },
 };
 
 // Useful shared types
@@ -9,11 +9,24 @@
 //!
 //! - **Arithmetic Operations**: Add, Subtract, Multiply, Divide for `Dynamic` and `Derived` types.
 //! - **Mixed-Type Support**: Operations between `Dynamic` and `Derived` values.
-//! - **Boolean Logic**: Negation (`!`) for `Dynamic<bool>`.
+//! - **Boolean Logic**: Negation (`!`) for `Dynamic<bool>`, and `select`, a reactive
+//!   ternary picking one of two values by a condition, e.g. for theme switching.
+//! - **Comparisons**: `ReactiveCompare` yields `Derived<bool>` values such as `count.gt(&limit)`,
+//!   e.g. to drive a status indicator or enable a button once a threshold is crossed.
+//! - **Clamping**: `ReactiveClamp` keeps a value within bounds, either as a `Derived`
+//!   (`clamped`) or on write (`set_clamped`).
 //! - **String Operations**: Concatenation and appending for `Dynamic<String>`.
 //! - **Math Extensions**: Traits like `ReactiveMath` and `ReactiveMathF64` provide additional
 //!   mathematical operations such as `powi`, `powf`, `abs`, `min`, `max`, and `rem`.
+//! - **Unsigned Math**: `ReactiveMath` and the arithmetic operators also cover
+//!   `Dynamic<usize>` and `Dynamic<u64>` counters.
+//! - **Overflow-safe Integer Math**: `ReactiveCheckedMath` provides `checked_*` variants
+//!   yielding `Derived<Option<i32>>` and `saturating_*` variants clamping at the `i32` bounds.
 //! - **List Operations**: Sum for `ReactiveList` of `i32` and `f64`.
+//! - **Sample History**: `ReactiveMovingAverage` smooths a `Dynamic<HistoryBuffer<f64>>`
+//!   into a trailing average (`signals` feature).
+//! - **Number Formatting**: `ReactiveFormat` renders a `Dynamic<f64>` as a `Derived<String>`
+//!   with fixed precision and thousands separators, e.g. `12,345.68`.
 //!
 //! ## Examples
 //!
@@ -56,53 +69,49 @@
 //! assert_eq!(result.get(), "Hello, world!");
 //! ```
 
-use std::ops::{Add, Div, Mul, Not, Sub};
+use std::ops::{Add, Div, Mul, Neg, Not, Rem, Sub};
 use std::sync::Arc;
 
 use crate::{Derived, Dynamic, ReactiveValue};
+#[cfg(feature = "signals")]
+use egui_mobius::history::HistoryBuffer;
 
-// Math ops for i32 and f64, including mixed-type reactive arithmetic
-macro_rules! impl_math_ops {
-    ($t:ty) => {
-        impl Add for Dynamic<$t> {
-            type Output = Derived<$t>;
-            fn add(self, rhs: Self) -> Self::Output {
-                let a = Arc::new(self);
-                let b = Arc::new(rhs);
-                Derived::new(&[a.clone(), b.clone()], move || *a.lock() + *b.lock())
-            }
-        }
-
-        impl Sub for Dynamic<$t> {
-            type Output = Derived<$t>;
-            fn sub(self, rhs: Self) -> Self::Output {
-                let a = Arc::new(self);
-                let b = Arc::new(rhs);
-                Derived::new(&[a.clone(), b.clone()], move || *a.lock() - *b.lock())
-            }
-        }
+// Math ops for the integer types, generic so that unsuffixed literals still infer
+impl<T: ReactiveInteger> Add for Dynamic<T> {
+    type Output = Derived<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        let a = Arc::new(self);
+        let b = Arc::new(rhs);
+        Derived::new(&[a.clone(), b.clone()], move || *a.lock() + *b.lock())
+    }
+}
 
-        impl Mul for Dynamic<$t> {
-            type Output = Derived<$t>;
-            fn mul(self, rhs: Self) -> Self::Output {
-                let a = Arc::new(self);
-                let b = Arc::new(rhs);
-                Derived::new(&[a.clone(), b.clone()], move || *a.lock() * *b.lock())
-            }
-        }
+impl<T: ReactiveInteger> Sub for Dynamic<T> {
+    type Output = Derived<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        let a = Arc::new(self);
+        let b = Arc::new(rhs);
+        Derived::new(&[a.clone(), b.clone()], move || *a.lock() - *b.lock())
+    }
+}
 
-        impl Div for Dynamic<$t> {
-            type Output = Derived<$t>;
-            fn div(self, rhs: Self) -> Self::Output {
-                let a = Arc::new(self);
-                let b = Arc::new(rhs);
-                Derived::new(&[a.clone(), b.clone()], move || *a.lock() / *b.lock())
-            }
-        }
-    };
+impl<T: ReactiveInteger> Mul for Dynamic<T> {
+    type Output = Derived<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let a = Arc::new(self);
+        let b = Arc::new(rhs);
+        Derived::new(&[a.clone(), b.clone()], move || *a.lock() * *b.lock())
+    }
 }
 
-impl_math_ops!(i32);
+impl<T: ReactiveInteger> Div for Dynamic<T> {
+    type Output = Derived<T>;
+    fn div(self, rhs: Self) -> Self::Output {
+        let a = Arc::new(self);
+        let b = Arc::new(rhs);
+        Derived::new(&[a.clone(), b.clone()], move || *a.lock() / *b.lock())
+    }
+}
 
 // Mixed-type reactive math support for Dynamic + Derived and vice versa
 impl Add<Derived<i32>> for Dynamic<i32> {
@@ -238,77 +247,259 @@ impl Add for Dynamic<String> {
     }
 }
 
-// ReactiveMath for i32
-/// Provides additional mathematical operations for `Dynamic<i32>` values.
+// ReactiveMath for integers
+/// Integer types supported by `ReactiveMath`: `i32`, `usize` and `u64`.
+pub trait ReactiveInteger:
+    Copy
+    + Ord
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + 'static
+{
+    const TWO: Self;
+
+    fn pow(self, exp: u32) -> Self;
+}
+
+/// Signed integer types, which additionally support `ReactiveMath::negated` and `abs`.
+pub trait ReactiveSignedInteger: ReactiveInteger + Neg<Output = Self> {
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_reactive_integer {
+    ($($t:ty),*) => {
+        $(
+            impl ReactiveInteger for $t {
+                const TWO: Self = 2;
+
+                fn pow(self, exp: u32) -> Self {
+                    <$t>::pow(self, exp)
+                }
+            }
+        )*
+    };
+}
+
+impl_reactive_integer!(i32, usize, u64);
+
+impl ReactiveSignedInteger for i32 {
+    fn abs(self) -> Self {
+        i32::abs(self)
+    }
+}
+
+/// Provides additional mathematical operations for integer `Dynamic` values
+/// (`Dynamic<i32>`, `Dynamic<usize>` and `Dynamic<u64>`).
+///
+/// `negated` and `abs` are only available for signed types; calling them on an
+/// unsigned counter is a compile error.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius_reactive::{Dynamic, ReactiveMath};
+///
+/// let frames = Dynamic::new(12usize);
+/// assert_eq!(frames.doubled().get(), 24);
+/// assert_eq!(frames.rem(&Dynamic::new(5)).get(), 2);
+/// ```
 pub trait ReactiveMath {
-    /// Returns a `Derived<i32>` that is double the value of `self`.
-    fn doubled(&self) -> Derived<i32>;
+    /// The integer type of the value.
+    type Value: ReactiveInteger;
+
+    /// Returns a `Derived` that is double the value of `self`.
+    fn doubled(&self) -> Derived<Self::Value>;
 
-    /// Returns a `Derived<i32>` that is the negated value of `self`.
-    fn negated(&self) -> Derived<i32>;
+    /// Returns a `Derived` that is the negated value of `self`.
+    fn negated(&self) -> Derived<Self::Value>
+    where
+        Self::Value: ReactiveSignedInteger;
 
-    /// Returns a `Derived<i32>` that is `self` raised to the power of `exp`.
-    fn powi(&self, exp: u32) -> Derived<i32>;
+    /// Returns a `Derived` that is `self` raised to the power of `exp`.
+    fn powi(&self, exp: u32) -> Derived<Self::Value>;
 
-    /// Returns a `Derived<i32>` that is the absolute value of `self`.
-    fn abs(&self) -> Derived<i32>;
+    /// Returns a `Derived` that is the absolute value of `self`.
+    fn abs(&self) -> Derived<Self::Value>
+    where
+        Self::Value: ReactiveSignedInteger;
 
-    /// Returns a `Derived<i32>` that is the minimum of `self` and `other`.
-    fn min(&self, other: &Dynamic<i32>) -> Derived<i32>;
+    /// Returns a `Derived` that is the minimum of `self` and `other`.
+    fn min(&self, other: &Dynamic<Self::Value>) -> Derived<Self::Value>;
 
-    /// Returns a `Derived<i32>` that is the maximum of `self` and `other`.
-    fn max(&self, other: &Dynamic<i32>) -> Derived<i32>;
+    /// Returns a `Derived` that is the maximum of `self` and `other`.
+    fn max(&self, other: &Dynamic<Self::Value>) -> Derived<Self::Value>;
 
-    /// Returns a `Derived<i32>` that is the remainder of `self` divided by `other`.
-    fn rem(&self, other: &Dynamic<i32>) -> Derived<i32>;
+    /// Returns a `Derived` that is the remainder of `self` divided by `other`.
+    fn rem(&self, other: &Dynamic<Self::Value>) -> Derived<Self::Value>;
 }
 
-impl ReactiveMath for Dynamic<i32> {
-    fn doubled(&self) -> Derived<i32> {
+impl<T: ReactiveInteger> ReactiveMath for Dynamic<T> {
+    type Value = T;
+
+    fn doubled(&self) -> Derived<T> {
         let a = Arc::new(self.clone());
         Derived::new(&[a.clone() as Arc<dyn ReactiveValue>], move || {
-            *a.lock() * 2
+            *a.lock() * T::TWO
         })
     }
 
-    fn negated(&self) -> Derived<i32> {
+    fn negated(&self) -> Derived<T>
+    where
+        T: ReactiveSignedInteger,
+    {
         let a = Arc::new(self.clone());
         Derived::new(&[a.clone() as Arc<dyn ReactiveValue>], move || -*a.lock())
     }
 
-    fn powi(&self, exp: u32) -> Derived<i32> {
+    fn powi(&self, exp: u32) -> Derived<T> {
         let a = Arc::new(self.clone());
         Derived::new(&[a.clone() as Arc<dyn ReactiveValue>], move || {
-            a.lock().pow(exp)
+            ReactiveInteger::pow(*a.lock(), exp)
         })
     }
 
-    fn abs(&self) -> Derived<i32> {
+    fn abs(&self) -> Derived<T>
+    where
+        T: ReactiveSignedInteger,
+    {
         let a = Arc::new(self.clone());
         Derived::new(&[a.clone() as Arc<dyn ReactiveValue>], move || {
-            a.lock().abs()
+            ReactiveSignedInteger::abs(*a.lock())
         })
     }
 
-    fn min(&self, other: &Dynamic<i32>) -> Derived<i32> {
+    fn min(&self, other: &Dynamic<T>) -> Derived<T> {
         let a = Arc::new(self.clone());
         let b = Arc::new(other.clone());
-        Derived::new(&[a.clone(), b.clone()], move || a.lock().min(*b.lock()))
+        Derived::new(&[a.clone(), b.clone()], move || {
+            Ord::min(*a.lock(), *b.lock())
+        })
     }
 
-    fn max(&self, other: &Dynamic<i32>) -> Derived<i32> {
+    fn max(&self, other: &Dynamic<T>) -> Derived<T> {
         let a = Arc::new(self.clone());
         let b = Arc::new(other.clone());
-        Derived::new(&[a.clone(), b.clone()], move || a.lock().max(*b.lock()))
+        Derived::new(&[a.clone(), b.clone()], move || {
+            Ord::max(*a.lock(), *b.lock())
+        })
     }
 
-    fn rem(&self, other: &Dynamic<i32>) -> Derived<i32> {
+    fn rem(&self, other: &Dynamic<T>) -> Derived<T> {
         let a = Arc::new(self.clone());
         let b = Arc::new(other.clone());
         Derived::new(&[a.clone(), b.clone()], move || *a.lock() % *b.lock())
     }
 }
 
+// Checked and saturating i32 math
+/// Overflow-safe arithmetic for `Dynamic<i32>` values.
+///
+/// The plain operators and `ReactiveMath::powi` panic on overflow in debug builds and
+/// wrap in release builds. The `checked_*` variants yield `None` instead, and the
+/// `saturating_*` variants clamp to `i32::MIN`/`i32::MAX`.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius_reactive::{Dynamic, ReactiveCheckedMath};
+///
+/// let count = Dynamic::new(100);
+/// assert_eq!(count.checked_powi(5).get(), None);
+/// assert_eq!(count.saturating_powi(5).get(), i32::MAX);
+/// ```
+pub trait ReactiveCheckedMath {
+    /// Returns `self + other`, or `None` on overflow.
+    fn checked_add(&self, other: &Dynamic<i32>) -> Derived<Option<i32>>;
+
+    /// Returns `self - other`, or `None` on overflow.
+    fn checked_sub(&self, other: &Dynamic<i32>) -> Derived<Option<i32>>;
+
+    /// Returns `self * other`, or `None` on overflow.
+    fn checked_mul(&self, other: &Dynamic<i32>) -> Derived<Option<i32>>;
+
+    /// Returns `self` raised to the power of `exp`, or `None` on overflow.
+    fn checked_powi(&self, exp: u32) -> Derived<Option<i32>>;
+
+    /// Returns `self + other`, saturating at the numeric bounds.
+    fn saturating_add(&self, other: &Dynamic<i32>) -> Derived<i32>;
+
+    /// Returns `self - other`, saturating at the numeric bounds.
+    fn saturating_sub(&self, other: &Dynamic<i32>) -> Derived<i32>;
+
+    /// Returns `self * other`, saturating at the numeric bounds.
+    fn saturating_mul(&self, other: &Dynamic<i32>) -> Derived<i32>;
+
+    /// Returns `self` raised to the power of `exp`, saturating at the numeric bounds.
+    fn saturating_powi(&self, exp: u32) -> Derived<i32>;
+}
+
+impl ReactiveCheckedMath for Dynamic<i32> {
+    fn checked_add(&self, other: &Dynamic<i32>) -> Derived<Option<i32>> {
+        let a = Arc::new(self.clone());
+        let b = Arc::new(other.clone());
+        Derived::new(&[a.clone(), b.clone()], move || {
+            a.lock().checked_add(*b.lock())
+        })
+    }
+
+    fn checked_sub(&self, other: &Dynamic<i32>) -> Derived<Option<i32>> {
+        let a = Arc::new(self.clone());
+        let b = Arc::new(other.clone());
+        Derived::new(&[a.clone(), b.clone()], move || {
+            a.lock().checked_sub(*b.lock())
+        })
+    }
+
+    fn checked_mul(&self, other: &Dynamic<i32>) -> Derived<Option<i32>> {
+        let a = Arc::new(self.clone());
+        let b = Arc::new(other.clone());
+        Derived::new(&[a.clone(), b.clone()], move || {
+            a.lock().checked_mul(*b.lock())
+        })
+    }
+
+    fn checked_powi(&self, exp: u32) -> Derived<Option<i32>> {
+        let a = Arc::new(self.clone());
+        Derived::new(&[a.clone() as Arc<dyn ReactiveValue>], move || {
+            a.lock().checked_pow(exp)
+        })
+    }
+
+    fn saturating_add(&self, other: &Dynamic<i32>) -> Derived<i32> {
+        let a = Arc::new(self.clone());
+        let b = Arc::new(other.clone());
+        Derived::new(&[a.clone(), b.clone()], move || {
+            a.lock().saturating_add(*b.lock())
+        })
+    }
+
+    fn saturating_sub(&self, other: &Dynamic<i32>) -> Derived<i32> {
+        let a = Arc::new(self.clone());
+        let b = Arc::new(other.clone());
+        Derived::new(&[a.clone(), b.clone()], move || {
+            a.lock().saturating_sub(*b.lock())
+        })
+    }
+
+    fn saturating_mul(&self, other: &Dynamic<i32>) -> Derived<i32> {
+        let a = Arc::new(self.clone());
+        let b = Arc::new(other.clone());
+        Derived::new(&[a.clone(), b.clone()], move || {
+            a.lock().saturating_mul(*b.lock())
+        })
+    }
+
+    fn saturating_powi(&self, exp: u32) -> Derived<i32> {
+        let a = Arc::new(self.clone());
+        Derived::new(&[a.clone() as Arc<dyn ReactiveValue>], move || {
+            a.lock().saturating_pow(exp)
+        })
+    }
+}
+
 // ReactiveMathF64 for f64
 pub trait ReactiveMathF64 {
     fn powf(&self, exp: f64) -> Derived<f64>;
@@ -376,6 +567,332 @@ impl ReactiveListSum<f64> for crate::ReactiveList<f64> {
     }
 }
 
+// Histogram helper
+/// Reactive binning of sample data, ready to feed into a bar chart.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius_reactive::{Dynamic, ReactiveHistogram};
+///
+/// let samples = Dynamic::new(vec![0.0, 1.0, 1.5, 4.0]);
+/// let bins = samples.histogram(2);
+/// // Two bins of width 2.0 spanning 0.0..=4.0, keyed by their centers
+/// assert_eq!(bins.get(), vec![(1.0, 3), (3.0, 1)]);
+/// ```
+pub trait ReactiveHistogram {
+    /// Counts the samples in `bins` equal-width bins spanning the minimum to the
+    /// maximum sample, recomputed whenever the data changes.
+    ///
+    /// Each entry is `(bin center, count)`; the maximum sample is counted in the
+    /// last bin. Non-finite samples are ignored. Empty data (or `bins == 0`) yields
+    /// no bins, and when every sample is equal there is a single bin holding them all.
+    fn histogram(&self, bins: usize) -> Derived<Vec<(f64, usize)>>;
+}
+
+impl ReactiveHistogram for Dynamic<Vec<f64>> {
+    fn histogram(&self, bins: usize) -> Derived<Vec<(f64, usize)>> {
+        let data = Arc::new(self.clone());
+        Derived::new(&[data.clone() as Arc<dyn ReactiveValue>], move || {
+            bin_counts(&data.lock(), bins)
+        })
+    }
+}
+
+fn bin_counts(samples: &[f64], bins: usize) -> Vec<(f64, usize)> {
+    let finite = || samples.iter().copied().filter(|x| x.is_finite());
+    let Some(min) = finite().reduce(f64::min) else {
+        return Vec::new();
+    };
+    let max = finite().fold(min, f64::max);
+    if bins == 0 {
+        return Vec::new();
+    }
+    if max == min {
+        return vec![(min, finite().count())];
+    }
+    let width = (max - min) / bins as f64;
+
+    let mut counts = vec![0; bins];
+    for x in finite() {
+        let index = (((x - min) / width) as usize).min(bins - 1);
+        counts[index] += 1;
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (min + width * (i as f64 + 0.5), count))
+        .collect()
+}
+
+// Moving average helper
+#[cfg(feature = "signals")]
+/// Reactive trailing average over a sample history, e.g. to smooth a noisy plot trace.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius::history::HistoryBuffer;
+/// use egui_mobius_reactive::{Dynamic, ReactiveMovingAverage};
+///
+/// let mut samples = HistoryBuffer::new(100);
+/// samples.extend([1.0, 2.0, 3.0, 4.0]);
+/// let history = Dynamic::new(samples);
+/// let smoothed = history.moving_average(2);
+/// assert_eq!(smoothed.get(), 3.5);
+/// ```
+pub trait ReactiveMovingAverage {
+    /// Averages the last `window` samples, recomputed whenever the history changes.
+    ///
+    /// While fewer than `window` samples have been pushed, the average covers the
+    /// samples available. An empty history (or `window == 0`) yields `0.0`.
+    fn moving_average(&self, window: usize) -> Derived<f64>;
+}
+
+#[cfg(feature = "signals")]
+impl ReactiveMovingAverage for Dynamic<HistoryBuffer<f64>> {
+    fn moving_average(&self, window: usize) -> Derived<f64> {
+        let history = Arc::new(self.clone());
+        Derived::new(&[history.clone() as Arc<dyn ReactiveValue>], move || {
+            trailing_mean(&history.lock(), window)
+        })
+    }
+}
+
+#[cfg(feature = "signals")]
+fn trailing_mean(history: &HistoryBuffer<f64>, window: usize) -> f64 {
+    let count = window.min(history.len());
+    if count == 0 {
+        return 0.0;
+    }
+    history.iter().rev().take(count).sum::<f64>() / count as f64
+}
+
+// Number formatting helpers
+/// Separators used by `ReactiveFormat::formatted_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Inserted between groups of three integer digits, `None` for no grouping.
+    pub thousands_separator: Option<char>,
+    /// Placed between the integer and the fractional digits.
+    pub decimal_point: char,
+}
+
+impl NumberFormat {
+    /// `12,345.68`, the default.
+    pub const ENGLISH: Self = Self {
+        thousands_separator: Some(','),
+        decimal_point: '.',
+    };
+    /// `12.345,68`, as used in most of continental Europe.
+    pub const EUROPEAN: Self = Self {
+        thousands_separator: Some('.'),
+        decimal_point: ',',
+    };
+    /// `12345.68`, no grouping.
+    pub const PLAIN: Self = Self {
+        thousands_separator: None,
+        decimal_point: '.',
+    };
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
+
+/// Reactive display strings for numbers, so number formatting lives in one place
+/// instead of a `format!("{:.2}")` per label.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius_reactive::{Dynamic, NumberFormat, ReactiveFormat};
+///
+/// let price = Dynamic::new(12345.678);
+/// let text = price.formatted(2);
+/// assert_eq!(text.get(), "12,345.68");
+/// assert_eq!(price.formatted_with(1, NumberFormat::EUROPEAN).get(), "12.345,7");
+/// ```
+pub trait ReactiveFormat {
+    /// Renders the value with `precision` fractional digits and `,` between
+    /// thousands, re-rendered on every change.
+    fn formatted(&self, precision: usize) -> Derived<String>;
+
+    /// Like `formatted`, with the separators of `format`.
+    fn formatted_with(&self, precision: usize, format: NumberFormat) -> Derived<String>;
+}
+
+impl ReactiveFormat for Dynamic<f64> {
+    fn formatted(&self, precision: usize) -> Derived<String> {
+        self.formatted_with(precision, NumberFormat::default())
+    }
+
+    fn formatted_with(&self, precision: usize, format: NumberFormat) -> Derived<String> {
+        self.to_display(move |value| format_number(*value, precision, format))
+    }
+}
+
+fn format_number(value: f64, precision: usize, format: NumberFormat) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let fixed = format!("{value:.precision$}");
+    let (sign, unsigned) = match fixed.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", fixed.as_str()),
+    };
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+    let mut text = String::from(sign);
+    for (i, digit) in integer.chars().enumerate() {
+        let remaining = integer.len() - i;
+        if i > 0 && remaining % 3 == 0 {
+            text.extend(format.thousands_separator);
+        }
+        text.push(digit);
+    }
+    if !fraction.is_empty() {
+        text.push(format.decimal_point);
+        text.push_str(fraction);
+    }
+    text
+}
+
+// Comparison helpers
+/// Reactive comparisons between two `Dynamic` values, yielding a `Derived<bool>`
+/// that flips whenever either side crosses the other.
+///
+/// Implemented for every `Dynamic<T>` with `T: PartialOrd`, including `i32` and `f64`.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius_reactive::{Dynamic, ReactiveCompare};
+///
+/// let count = Dynamic::new(3);
+/// let limit = Dynamic::new(10);
+/// let over_limit = count.gt(&limit);
+///
+/// assert!(!over_limit.get());
+/// count.set(11);
+/// # std::thread::sleep(std::time::Duration::from_millis(50));
+/// assert!(over_limit.get());
+/// ```
+pub trait ReactiveCompare<T: Clone + Send + Sync + 'static> {
+    /// `true` while `self > other`.
+    fn gt(&self, other: &Dynamic<T>) -> Derived<bool>;
+
+    /// `true` while `self >= other`.
+    fn ge(&self, other: &Dynamic<T>) -> Derived<bool>;
+
+    /// `true` while `self < other`.
+    fn lt(&self, other: &Dynamic<T>) -> Derived<bool>;
+
+    /// `true` while `self <= other`.
+    fn le(&self, other: &Dynamic<T>) -> Derived<bool>;
+
+    /// `true` while `self == other`. Named `eq_dyn` so it does not shadow
+    /// `PartialEq::eq`, which compares the current values once.
+    fn eq_dyn(&self, other: &Dynamic<T>) -> Derived<bool>;
+}
+
+impl<T> ReactiveCompare<T> for Dynamic<T>
+where
+    T: PartialOrd + Clone + Send + Sync + 'static,
+{
+    fn gt(&self, other: &Dynamic<T>) -> Derived<bool> {
+        compare(self, other, |a, b| a > b)
+    }
+
+    fn ge(&self, other: &Dynamic<T>) -> Derived<bool> {
+        compare(self, other, |a, b| a >= b)
+    }
+
+    fn lt(&self, other: &Dynamic<T>) -> Derived<bool> {
+        compare(self, other, |a, b| a < b)
+    }
+
+    fn le(&self, other: &Dynamic<T>) -> Derived<bool> {
+        compare(self, other, |a, b| a <= b)
+    }
+
+    fn eq_dyn(&self, other: &Dynamic<T>) -> Derived<bool> {
+        compare(self, other, |a, b| a == b)
+    }
+}
+
+fn compare<T>(a: &Dynamic<T>, b: &Dynamic<T>, op: fn(&T, &T) -> bool) -> Derived<bool>
+where
+    T: PartialOrd + Clone + Send + Sync + 'static,
+{
+    let a = Arc::new(a.clone());
+    let b = Arc::new(b.clone());
+    Derived::new(&[a.clone(), b.clone()], move || op(&a.lock(), &b.lock()))
+}
+
+// Clamping helpers
+/// Keeps reactive values within a range, e.g. so a gauge never shows a reading
+/// beyond its scale.
+///
+/// Implemented for every `Dynamic<T>` with `T: PartialOrd`, including `i32` and `f64`.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius_reactive::{Dynamic, ReactiveClamp};
+///
+/// let temperature = Dynamic::new(20.0);
+/// let gauge = temperature.clamped(0.0, 150.0);
+///
+/// temperature.set(180.0);
+/// # std::thread::sleep(std::time::Duration::from_millis(50));
+/// assert_eq!(gauge.get(), 150.0);
+///
+/// temperature.set_clamped(0.0, 150.0, -5.0);
+/// assert_eq!(temperature.get(), 0.0);
+/// ```
+pub trait ReactiveClamp<T: Clone + Send + Sync + 'static> {
+    /// The value restricted to `min..=max`, recomputed whenever it changes.
+    ///
+    /// The source keeps its out-of-range value; only the derived is clamped.
+    ///
+    /// # Panics
+    /// Panics if `min > max`.
+    fn clamped(&self, min: T, max: T) -> Derived<T>;
+
+    /// Sets `value` restricted to `min..=max`.
+    ///
+    /// # Panics
+    /// Panics if `min > max`.
+    fn set_clamped(&self, min: T, max: T, value: T);
+}
+
+impl<T> ReactiveClamp<T> for Dynamic<T>
+where
+    T: PartialOrd + Clone + Send + Sync + 'static,
+{
+    fn clamped(&self, min: T, max: T) -> Derived<T> {
+        assert!(min <= max, "clamped requires min <= max");
+        let a = Arc::new(self.clone());
+        Derived::new(&[a.clone() as Arc<dyn ReactiveValue>], move || {
+            clamp(a.get(), &min, &max)
+        })
+    }
+
+    fn set_clamped(&self, min: T, max: T, value: T) {
+        assert!(min <= max, "set_clamped requires min <= max");
+        self.set(clamp(value, &min, &max));
+    }
+}
+
+/// `value` restricted to `min..=max`; values unordered with the bounds (NaN) pass through.
+fn clamp<T: PartialOrd + Clone>(value: T, min: &T, max: &T) -> T {
+    if value < *min {
+        min.clone()
+    } else if value > *max {
+        max.clone()
+    } else {
+        value
+    }
+}
+
 // Logic and String helpers
 pub trait ReactiveLogic {
     fn not(&self) -> Derived<bool>;
@@ -388,6 +905,41 @@ impl ReactiveLogic for Dynamic<bool> {
     }
 }
 
+/// Yields `a` while `cond` is true and `b` otherwise.
+///
+/// The result is recomputed when any of the three changes, so it follows both
+/// the condition and whichever source is currently selected.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius_reactive::{Dynamic, select};
+///
+/// let dark_mode = Dynamic::new(false);
+/// let dark = Dynamic::new("#202020".to_string());
+/// let light = Dynamic::new("#f0f0f0".to_string());
+/// let background = select(&dark_mode, &dark, &light);
+///
+/// dark_mode.set(true);
+/// # std::thread::sleep(std::time::Duration::from_millis(50));
+/// assert_eq!(background.get(), "#202020");
+/// ```
+pub fn select<T>(cond: &Dynamic<bool>, a: &Dynamic<T>, b: &Dynamic<T>) -> Derived<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let cond = Arc::new(cond.clone());
+    let a = Arc::new(a.clone());
+    let b = Arc::new(b.clone());
+    Derived::new(
+        &[
+            cond.clone() as Arc<dyn ReactiveValue>,
+            a.clone() as Arc<dyn ReactiveValue>,
+            b.clone() as Arc<dyn ReactiveValue>,
+        ],
+        move || if cond.get() { a.get() } else { b.get() },
+    )
+}
+
 pub trait ReactiveString {
     fn append(&self, other: &Dynamic<String>) -> Derived<String>;
 }
@@ -420,6 +972,66 @@ mod tests {
         assert_eq!(a.rem(&b).get(), 2);
     }
 
+    #[test]
+    fn test_usize_math_extensions() {
+        let a = Dynamic::new(5usize);
+        let b = Dynamic::new(3usize);
+
+        assert_eq!(a.doubled().get(), 10);
+        assert_eq!(a.powi(3).get(), 125);
+        assert_eq!(a.min(&b).get(), 3);
+        assert_eq!(a.max(&b).get(), 5);
+        assert_eq!(a.rem(&b).get(), 2);
+
+        assert_eq!((a.clone() + b.clone()).get(), 8);
+        assert_eq!((a.clone() - b.clone()).get(), 2);
+        assert_eq!((a.clone() * b.clone()).get(), 15);
+        assert_eq!((a / b).get(), 1);
+    }
+
+    #[test]
+    fn test_u64_math_extensions() {
+        let big = Dynamic::new(u64::from(u32::MAX));
+        let two = Dynamic::new(2u64);
+
+        assert_eq!(big.doubled().get(), 8_589_934_590);
+        assert_eq!(two.powi(40).get(), 1 << 40);
+        assert_eq!(big.min(&two).get(), 2);
+        assert_eq!((big + two).get(), 4_294_967_297);
+    }
+
+    #[test]
+    fn test_checked_and_saturating_i32_math_at_overflow() {
+        let a = Dynamic::new(i32::MAX - 1);
+        let one = Dynamic::new(1);
+        let two = Dynamic::new(2);
+
+        let checked_sum = a.checked_add(&one);
+        let saturated_sum = a.saturating_add(&two);
+        assert_eq!(checked_sum.get(), Some(i32::MAX));
+        assert_eq!(saturated_sum.get(), i32::MAX);
+        assert_eq!(a.checked_add(&two).get(), None);
+
+        let low = Dynamic::new(i32::MIN);
+        assert_eq!(low.checked_sub(&one).get(), None);
+        assert_eq!(low.saturating_sub(&one).get(), i32::MIN);
+        assert_eq!(low.checked_mul(&two).get(), None);
+        assert_eq!(low.saturating_mul(&two).get(), i32::MIN);
+
+        // 73^5 fits in an i32, 74^5 does not
+        let count = Dynamic::new(73);
+        let checked_fifth = count.checked_powi(5);
+        let saturated_fifth = count.saturating_powi(5);
+        assert_eq!(checked_fifth.get(), Some(2_073_071_593));
+        count.set(74);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(checked_fifth.get(), None);
+        assert_eq!(saturated_fifth.get(), i32::MAX);
+        count.set(-74);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(saturated_fifth.get(), i32::MIN);
+    }
+
     #[test]
     fn test_f64_math_extensions() {
         let x = Dynamic::new(-2.5);
@@ -432,6 +1044,80 @@ mod tests {
         assert_eq!(y.rem(&x).get(), 0.5);
     }
 
+    #[test]
+    fn test_histogram_counts_and_edges() {
+        let samples = Dynamic::new(vec![2.0, 0.0, 10.0, 4.9, 5.0, f64::NAN, 7.5]);
+        let bins = samples.histogram(4);
+        assert_eq!(bins.get(), vec![(1.25, 2), (3.75, 1), (6.25, 1), (8.75, 2)]);
+
+        samples.set(vec![3.0, 3.0]);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(bins.get(), vec![(3.0, 2)]);
+
+        samples.set(Vec::new());
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(bins.get().is_empty());
+        assert!(Dynamic::new(vec![1.0]).histogram(0).get().is_empty());
+    }
+
+    #[cfg(feature = "signals")]
+    #[test]
+    fn test_moving_average_tracks_each_push() {
+        let history = Dynamic::new(HistoryBuffer::new(10));
+        let average = history.moving_average(3);
+        assert_eq!(average.get(), 0.0);
+
+        let expected = [2.0, 3.0, 4.0, 6.0, 8.0];
+        for (sample, expected) in [2.0, 4.0, 6.0, 8.0, 10.0].into_iter().zip(expected) {
+            let mut samples = history.get();
+            samples.push(sample);
+            history.set(samples);
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            assert_eq!(average.get(), expected);
+        }
+        assert_eq!(history.moving_average(0).get(), 0.0);
+    }
+
+    #[test]
+    fn test_comparisons_flip_across_threshold() {
+        let count = Dynamic::new(9);
+        let threshold = Dynamic::new(10);
+        let above = count.gt(&threshold);
+        let below = count.lt(&threshold);
+        let at = count.eq_dyn(&threshold);
+        let at_least = count.ge(&threshold);
+        assert_eq!(
+            (above.get(), below.get(), at.get(), at_least.get()),
+            (false, true, false, false)
+        );
+
+        count.set(10);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(
+            (above.get(), below.get(), at.get(), at_least.get()),
+            (false, false, true, true)
+        );
+
+        count.set(11);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(
+            (above.get(), below.get(), at.get(), at_least.get()),
+            (true, false, false, true)
+        );
+
+        // Moving the threshold flips the result too
+        threshold.set(20);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(!above.get() && count.le(&threshold).get());
+
+        let level = Dynamic::new(0.5);
+        let limit = Dynamic::new(0.75);
+        let high = level.gt(&limit);
+        level.set(0.8);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(high.get());
+    }
+
     #[test]
     fn test_boolean_not() {
         let flag = Dynamic::new(true);
@@ -510,10 +1196,69 @@ mod tests {
         assert_eq!(rem.get(), 0.0);
     }
 
+    #[test]
+    fn test_clamped_reports_bounds() {
+        let temperature = Dynamic::new(20);
+        let gauge = temperature.clamped(0, 150);
+        assert_eq!(gauge.get(), 20);
+
+        temperature.set(180);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(gauge.get(), 150);
+        assert_eq!(temperature.get(), 180);
+
+        temperature.set_clamped(0, 150, -5);
+        assert_eq!(temperature.get(), 0);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(gauge.get(), 0);
+    }
+
+    #[test]
+    fn test_formatted_groups_thousands_and_follows_changes() {
+        let price = Dynamic::new(12345.678);
+        let text = price.formatted(2);
+        let plain = price.formatted_with(0, NumberFormat::PLAIN);
+        assert_eq!(text.get(), "12,345.68");
+        assert_eq!(plain.get(), "12346");
+
+        price.set(-1234567.0);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(text.get(), "-1,234,567.00");
+        assert_eq!(
+            price.formatted_with(3, NumberFormat::EUROPEAN).get(),
+            "-1.234.567,000"
+        );
+
+        price.set(999.5);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(text.get(), "999.50");
+    }
+
     #[test]
     fn test_reactive_logic_trait() {
         let val = Dynamic::new(false);
         let toggled = val.not();
         assert!(toggled.get());
     }
+
+    #[test]
+    fn test_select_follows_condition_and_active_source() {
+        let cond = Dynamic::new(true);
+        let a = Dynamic::new(1);
+        let b = Dynamic::new(2);
+        let selected = select(&cond, &a, &b);
+        assert_eq!(selected.get(), 1);
+
+        a.set(10);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(selected.get(), 10);
+
+        cond.set(false);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(selected.get(), 2);
+
+        b.set(20);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(selected.get(), 20);
+    }
 }
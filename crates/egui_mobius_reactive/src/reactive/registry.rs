@@ -5,6 +5,9 @@ use std::sync::{Arc, Mutex};
 /// Alias for shared reactive signal type
 pub type SharedReactive = Arc<dyn ErasedReactiveValue>;
 
+/// Teardown closure returned by an effect body, see `SignalRegistry::effect_with_cleanup`.
+pub type CleanupFn = Box<dyn FnOnce() + Send>;
+
 /// Trait alias for ReactiveValue + Any
 pub trait ErasedReactiveValue: ReactiveValue + Any {}
 impl<T: ReactiveValue + Any> ErasedReactiveValue for T {}
@@ -42,6 +45,60 @@ impl SignalRegistry {
         self.signals.lock().unwrap().clone()
     }
 
+    /// Snapshot of the registered signals and the dependencies between them.
+    ///
+    /// Every registered signal becomes a node. An edge is reported when a
+    /// registered `Derived` was computed directly from another registered value;
+    /// dependencies on unregistered values are left out.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::{Dynamic, ReactiveMath, SignalRegistry, reactive};
+    ///
+    /// let registry = SignalRegistry::new();
+    /// let count = Dynamic::new(2);
+    /// let doubled = count.powi(2);
+    /// reactive!(registry, "count" => count, "doubled" => doubled);
+    ///
+    /// let graph = registry.graph();
+    /// assert_eq!(graph.nodes.len(), 2);
+    /// assert_eq!(graph.dependencies_of("doubled").collect::<Vec<_>>(), ["count"]);
+    /// ```
+    pub fn graph(&self) -> ReactiveGraph {
+        let signals = self.list_signals();
+        let nodes = signals
+            .iter()
+            .map(|(name, signal)| GraphNode {
+                name: name.clone(),
+                type_name: signal.type_name(),
+            })
+            .collect();
+        let mut edges = Vec::new();
+        for (to, signal) in &signals {
+            for source in signal.dependency_ids() {
+                for (from, candidate) in &signals {
+                    if candidate.node_id() == Some(source) {
+                        edges.push(GraphEdge {
+                            from: from.clone(),
+                            to: to.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        ReactiveGraph { nodes, edges }
+    }
+
+    /// Propagate pending changes to `on_change` listeners, effects and `Derived` values.
+    ///
+    /// On `wasm32` targets there are no listener threads, so call this once per frame
+    /// (e.g. at the top of `eframe::App::update`). It runs listeners until no
+    /// notifications are left. Natively listeners run on their own threads and this
+    /// does nothing.
+    pub fn tick(&self) {
+        crate::reactive::listener::tick();
+    }
+
     /// Attach an effect that runs whenever any of the given dependencies change.
     ///
     /// # Notes on `'static` bound for dependencies:
@@ -70,6 +127,181 @@ impl SignalRegistry {
             f();
         }
     }
+
+    /// Attach an effect whose body returns a cleanup closure, like React's
+    /// `useEffect` teardown.
+    ///
+    /// The cleanup returned by one invocation runs right before the next
+    /// invocation's body, and once more when the effect is unregistered through
+    /// the returned `EffectHandle`. This makes it suitable for effects that spawn
+    /// resources such as timers or threads which must be cancelled on re-run.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui_mobius_reactive::{Dynamic, SignalRegistry};
+    /// use std::sync::Arc;
+    ///
+    /// let registry = SignalRegistry::new();
+    /// let items = Dynamic::new(0);
+    ///
+    /// let handle = registry.effect_with_cleanup(&[Arc::new(items.clone())], || {
+    ///     println!("start animation");
+    ///     Box::new(|| println!("cancel animation"))
+    /// });
+    ///
+    /// handle.unregister(); // prints "cancel animation"
+    /// ```
+    pub fn effect_with_cleanup<F>(&self, deps: &[SharedReactive], f: F) -> EffectHandle
+    where
+        F: Fn() -> CleanupFn + 'static + Send + Sync,
+    {
+        let handle = EffectHandle {
+            state: Arc::new(Mutex::new(EffectState {
+                active: true,
+                cleanup: None,
+            })),
+        };
+        let f = Arc::new(f);
+
+        let run = {
+            let state = handle.state.clone();
+            Arc::new(move || {
+                // Holding the state lock serializes invocations, so a cleanup
+                // always completes before the next body starts.
+                if let Ok(mut state) = state.lock() {
+                    if !state.active {
+                        return;
+                    }
+                    if let Some(cleanup) = state.cleanup.take() {
+                        cleanup();
+                    }
+                    state.cleanup = Some(f());
+                }
+            })
+        };
+
+        for dep in deps {
+            let run = run.clone();
+            dep.subscribe(Box::new(move || run()));
+        }
+
+        // Run once initially
+        run();
+        handle
+    }
+}
+
+/// The dependency graph of a `SignalRegistry`, as returned by `SignalRegistry::graph`.
+///
+/// Plain data, so tools can render it however they like, e.g. as Graphviz or in
+/// an egui node view.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReactiveGraph {
+    /// The registered signals, in registration order.
+    pub nodes: Vec<GraphNode>,
+    /// The dependencies between registered signals.
+    pub edges: Vec<GraphEdge>,
+}
+
+impl ReactiveGraph {
+    /// Names of the signals `name` is computed from.
+    pub fn dependencies_of<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.to == name)
+            .map(|edge| edge.from.as_str())
+    }
+
+    /// Names of the signals computed from `name`.
+    pub fn dependents_of<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.from == name)
+            .map(|edge| edge.to.as_str())
+    }
+}
+
+/// A registered signal in a `ReactiveGraph`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphNode {
+    /// The name the signal was registered under.
+    pub name: String,
+    /// The signal's concrete type, as given by `std::any::type_name`.
+    pub type_name: &'static str,
+}
+
+/// A dependency in a `ReactiveGraph`: `to` is computed from `from`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphEdge {
+    /// Name of the source signal.
+    pub from: String,
+    /// Name of the signal computed from it.
+    pub to: String,
+}
+
+/// Handle to an effect created with `SignalRegistry::effect_with_cleanup`.
+///
+/// Dropping the handle leaves the effect running; call `unregister` to stop it.
+#[derive(Clone)]
+pub struct EffectHandle {
+    state: Arc<Mutex<EffectState>>,
+}
+
+struct EffectState {
+    active: bool,
+    cleanup: Option<CleanupFn>,
+}
+
+impl EffectHandle {
+    /// Stop the effect and run the cleanup of its last invocation.
+    ///
+    /// Later dependency changes no longer trigger the effect. Calling this
+    /// more than once is a no-op.
+    pub fn unregister(&self) {
+        let cleanup = match self.state.lock() {
+            Ok(mut state) => {
+                state.active = false;
+                state.cleanup.take()
+            }
+            Err(_) => None,
+        };
+        if let Some(cleanup) = cleanup {
+            cleanup();
+        }
+    }
+
+    /// Returns `true` until `unregister` has been called.
+    pub fn is_active(&self) -> bool {
+        self.state.lock().map(|state| state.active).unwrap_or(false)
+    }
+}
+
+/// Register several reactive values with a `SignalRegistry` in one go.
+///
+/// Each `name => value` pair expands to
+/// `registry.register_named_signal(name, Arc::new(value.clone()))`, so the values
+/// stay usable afterwards.
+///
+/// # Example
+/// ```rust
+/// use egui_mobius_reactive::{Dynamic, SignalRegistry, reactive};
+///
+/// let registry = SignalRegistry::new();
+/// let count = Dynamic::new(0);
+/// let label = Dynamic::new("count".to_string());
+///
+/// reactive!(registry, "count" => count, "label" => label);
+/// assert_eq!(registry.list_signals().len(), 2);
+/// count.set(1);
+/// ```
+#[macro_export]
+macro_rules! reactive {
+    ($registry:expr, $($name:expr => $value:expr),+ $(,)?) => {{
+        let registry = &$registry;
+        $(
+            registry.register_named_signal($name, ::std::sync::Arc::new(($value).clone()));
+        )+
+    }};
 }
 
 #[cfg(test)]
@@ -79,6 +311,82 @@ mod tests {
     use std::thread;
     use std::time::Duration;
 
+    #[test]
+    fn test_derived_updates_after_tick() {
+        let registry = SignalRegistry::new();
+        let count = Dynamic::new(1);
+        let count_for_compute = count.clone();
+        let doubled = Derived::new(&[Arc::new(count.clone())], move || {
+            *count_for_compute.lock() * 2
+        });
+
+        count.set(21);
+        registry.tick();
+        // Natively listeners run on their own threads rather than in `tick`
+        #[cfg(not(target_arch = "wasm32"))]
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(doubled.get(), 42);
+    }
+
+    #[test]
+    fn test_reactive_macro_registers_every_pair() {
+        let registry = SignalRegistry::new();
+        let count = Dynamic::new(3);
+        let count_for_compute = count.clone();
+        let doubled = Derived::new(&[Arc::new(count.clone())], move || {
+            *count_for_compute.lock() * 2
+        });
+
+        crate::reactive!(registry, "count" => count, "doubled" => doubled,);
+
+        let names: Vec<String> = registry
+            .list_signals()
+            .into_iter()
+            .map(|(n, _)| n)
+            .collect();
+        assert_eq!(names, vec!["count", "doubled"]);
+        let registered = &registry.list_signals()[1].1;
+        assert_eq!(
+            registered
+                .as_any()
+                .downcast_ref::<Derived<i32>>()
+                .unwrap()
+                .get(),
+            6
+        );
+    }
+
+    #[test]
+    fn test_graph_reports_dependencies_between_registered_signals() {
+        use crate::ReactiveMath;
+
+        let registry = SignalRegistry::new();
+        let count = Dynamic::new(1);
+        let doubled: Derived<i32> = count.powi(2);
+        let sum: Derived<i32> = count.clone() + doubled.clone();
+        crate::reactive!(registry, "count" => count, "doubled" => doubled, "sum" => sum);
+
+        let graph = registry.graph();
+        let names: Vec<&str> = graph.nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, ["count", "doubled", "sum"]);
+        assert!(graph.nodes[0].type_name.ends_with("Dynamic<i32>"));
+        assert!(graph.nodes[1].type_name.ends_with("Derived<i32>"));
+
+        assert_eq!(graph.dependencies_of("count").count(), 0);
+        assert_eq!(
+            graph.dependencies_of("doubled").collect::<Vec<_>>(),
+            ["count"]
+        );
+        assert_eq!(
+            graph.dependencies_of("sum").collect::<Vec<_>>(),
+            ["count", "doubled"]
+        );
+        assert_eq!(
+            graph.dependents_of("count").collect::<Vec<_>>(),
+            ["doubled", "sum"]
+        );
+    }
+
     #[test]
     fn test_registry_keeps_signals_alive() {
         let registry = SignalRegistry::new();
@@ -99,4 +407,42 @@ mod tests {
         thread::sleep(Duration::from_millis(50));
         assert_eq!(doubled.get(), 10);
     }
+
+    #[test]
+    fn test_effect_cleanup_runs_before_next_invocation() {
+        let registry = SignalRegistry::new();
+        let trigger = Dynamic::new(0);
+        let log = Arc::new(Mutex::new(Vec::<String>::new()));
+        let run = Arc::new(Mutex::new(0));
+
+        let handle = registry.effect_with_cleanup(&[Arc::new(trigger.clone())], {
+            let log = log.clone();
+            move || {
+                let n = {
+                    let mut run = run.lock().unwrap();
+                    *run += 1;
+                    *run - 1
+                };
+                log.lock().unwrap().push(format!("run {n}"));
+                let log = log.clone();
+                Box::new(move || log.lock().unwrap().push(format!("cleanup {n}")))
+            }
+        });
+
+        trigger.set(1);
+        thread::sleep(Duration::from_millis(50));
+        trigger.set(2);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["run 0", "cleanup 0", "run 1", "cleanup 1", "run 2"]
+        );
+
+        handle.unregister();
+        assert!(!handle.is_active());
+        trigger.set(3);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(log.lock().unwrap().last().unwrap(), "cleanup 2");
+        assert_eq!(log.lock().unwrap().len(), 6);
+    }
 }
@@ -0,0 +1,177 @@
+//! Repaint integration – request an egui repaint whenever reactive state changes.
+//!
+//! Instead of tracking an `update_needed` flag and calling `ctx.request_repaint()`
+//! by hand, bind the `egui::Context` once and let change notifications drive repaints:
+//!
+//! ```rust
+//! use egui_mobius_reactive::Dynamic;
+//!
+//! let ctx = egui::Context::default();
+//! let temperature = Dynamic::new(20.0_f64);
+//! temperature.bind_repaint(&ctx);
+//!
+//! // Any thread may now update the value; the UI repaints on its own.
+//! std::thread::spawn(move || temperature.set(21.5));
+//! ```
+//!
+//! Values written faster than the display refreshes can use `bind_repaint_throttled`,
+//! which coalesces every change within an interval into one repaint request. An
+//! expensive view of a `Derived`, such as a rebuilt plot, can wait for the value to
+//! settle instead with `Derived::bind_repaint_debounced`.
+use crate::reactive::derived::Derived;
+use crate::reactive::dynamic::{Dynamic, ValueExt};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+impl<T: Clone + Send + Sync + PartialEq + 'static> Dynamic<T> {
+    /// Requests a repaint of `ctx` every time this value changes.
+    ///
+    /// The request is issued from the change-notification thread, so setting the
+    /// value from a background thread wakes the UI without any extra plumbing.
+    /// The binding lives as long as the `Dynamic` (or any of its clones).
+    ///
+    /// # Arguments
+    /// * `ctx` - The egui context to repaint.
+    pub fn bind_repaint(&self, ctx: &egui::Context) {
+        let ctx = ctx.clone();
+        self.on_change(move || ctx.request_repaint());
+    }
+
+    /// Like `bind_repaint`, but requests at most one repaint per `min_interval`.
+    ///
+    /// The first change schedules a repaint `min_interval` later; further changes
+    /// before the next egui pass begins are covered by that repaint and request
+    /// nothing. This keeps a fast producer from causing a repaint storm.
+    ///
+    /// # Arguments
+    /// * `ctx` - The egui context to repaint.
+    /// * `min_interval` - Delay of the coalesced repaint.
+    pub fn bind_repaint_throttled(&self, ctx: &egui::Context, min_interval: Duration) {
+        let scheduled = Arc::new(AtomicBool::new(false));
+        let painted = scheduled.clone();
+        // The pass that starts reads the latest value, so later changes need a new repaint
+        ctx.on_begin_pass(
+            "egui_mobius throttled repaint",
+            Arc::new(move |_| painted.store(false, Ordering::Release)),
+        );
+        let ctx = ctx.clone();
+        self.on_change(move || {
+            if !scheduled.swap(true, Ordering::AcqRel) {
+                ctx.request_repaint_after(min_interval);
+            }
+        });
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Derived<T> {
+    /// Requests a repaint of `ctx` once this value has stopped changing for `quiet`.
+    ///
+    /// Every change restarts the wait, so a burst of recomputations results in a
+    /// single repaint after the last one. The wait runs on a background thread
+    /// that ends with the value. On `wasm32`, without threads, each change
+    /// schedules a repaint `quiet` later instead, which egui merges with the
+    /// pending one.
+    ///
+    /// # Arguments
+    /// * `ctx` - The egui context to repaint.
+    /// * `quiet` - How long the value must stay unchanged.
+    pub fn bind_repaint_debounced(&self, ctx: &egui::Context, quiet: Duration) {
+        let ctx = ctx.clone();
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let (changed, changes) = std::sync::mpsc::channel();
+            std::thread::spawn(move || {
+                // Wait for a change, then until none arrives for a full `quiet`
+                while changes.recv().is_ok() {
+                    loop {
+                        match changes.recv_timeout(quiet) {
+                            Ok(()) => continue,
+                            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => break,
+                            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => return,
+                        }
+                    }
+                    ctx.request_repaint();
+                }
+            });
+            self.on_change(Box::new(move || {
+                let _ = changed.send(());
+            }));
+        }
+        #[cfg(target_arch = "wasm32")]
+        self.on_change(Box::new(move || ctx.request_repaint_after(quiet)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::thread;
+
+    #[test]
+    fn test_background_set_requests_one_repaint() {
+        let ctx = egui::Context::default();
+        let repaints = Arc::new(AtomicUsize::new(0));
+        let repaints_clone = repaints.clone();
+        ctx.set_request_repaint_callback(move |_| {
+            repaints_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let value = Dynamic::new(0);
+        value.bind_repaint(&ctx);
+
+        let writer = value.clone();
+        thread::spawn(move || writer.set(1)).join().unwrap();
+        thread::sleep(Duration::from_millis(50));
+
+        assert_eq!(repaints.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_burst_of_sets_requests_one_throttled_repaint() {
+        let ctx = egui::Context::default();
+        let delays = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let delays_clone = delays.clone();
+        ctx.set_request_repaint_callback(move |info| {
+            delays_clone.lock().unwrap().push(info.delay);
+        });
+
+        let value = Dynamic::new(0);
+        value.bind_repaint_throttled(&ctx, Duration::from_millis(200));
+
+        let writer = value.clone();
+        thread::spawn(move || (1..=20).for_each(|n| writer.set(n)))
+            .join()
+            .unwrap();
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(delays.lock().unwrap().len(), 1);
+        // egui shortens the requested delay by the expected frame time
+        assert!(delays.lock().unwrap()[0] <= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_rapid_derived_changes_request_one_debounced_repaint() {
+        let ctx = egui::Context::default();
+        let repaints = Arc::new(AtomicUsize::new(0));
+        let repaints_clone = repaints.clone();
+        ctx.set_request_repaint_callback(move |_| {
+            repaints_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let samples = Dynamic::new(0);
+        let source = samples.clone();
+        let plot = Derived::new(&[crate::dep(&samples)], move || source.get() * 2);
+        plot.bind_repaint_debounced(&ctx, Duration::from_millis(100));
+
+        for n in 1..=10 {
+            samples.set(n);
+            thread::sleep(Duration::from_millis(20));
+        }
+        assert_eq!(repaints.load(Ordering::SeqCst), 0);
+
+        thread::sleep(Duration::from_millis(250));
+        assert_eq!(repaints.load(Ordering::SeqCst), 1);
+        assert_eq!(plot.get(), 20);
+    }
+}
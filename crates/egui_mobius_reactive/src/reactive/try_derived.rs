@@ -0,0 +1,132 @@
+//! TryDerived – a derived value computed by a fallible closure.
+//!
+//! The computation returns `Result<T, E>`. Successes and failures are exposed as two
+//! separate `Derived` signals: `value` keeps the last good result while a later
+//! computation fails, and `error` holds that failure until the next success. A view
+//! can then keep showing the last price it parsed and the reason the current
+//! response was rejected, instead of tracking an `Option<T>` and an error message
+//! by hand.
+//!
+//! # Example
+//! ```rust
+//! use egui_mobius_reactive::{Dynamic, TryDerived, dep};
+//!
+//! let response = Dynamic::new(r#"{"price": 1.25}"#.to_string());
+//! let source = response.clone();
+//! let price = TryDerived::new(&[dep(&response)], move || {
+//!     let body = source.get();
+//!     body.trim_end_matches('}')
+//!         .rsplit(':')
+//!         .next()
+//!         .and_then(|price| price.trim().parse::<f64>().ok())
+//!         .ok_or(format!("no price in '{body}'"))
+//! });
+//! assert_eq!(price.get(), Some(1.25));
+//!
+//! response.set("rate limited".to_string());
+//! # std::thread::sleep(std::time::Duration::from_millis(50));
+//! assert_eq!(price.get(), Some(1.25)); // the last good value is kept
+//! assert_eq!(price.error().get().as_deref(), Some("no price in 'rate limited'"));
+//! ```
+
+use crate::reactive::core::ReactiveDep;
+use crate::reactive::derived::{Derived, node_ids, subscribe_all};
+use std::sync::Arc;
+
+/// A reactive value whose computation can fail, keeping the last success.
+///
+/// Clones share the value and error signals.
+#[derive(Clone)]
+pub struct TryDerived<T, E>
+where
+    T: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    value: Derived<Option<T>>,
+    error: Derived<Option<E>>,
+}
+
+impl<T, E> TryDerived<T, E>
+where
+    T: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    /// Creates a value recomputed with `compute` whenever one of `deps` changes.
+    ///
+    /// `compute` runs once right away; if that first run fails, `value` is `None`.
+    pub fn new<F>(deps: &[ReactiveDep], compute: F) -> Self
+    where
+        F: Fn() -> Result<T, E> + Send + Sync + 'static,
+    {
+        let sources = node_ids(deps);
+        let value = Derived::detached(None).with_sources(&sources);
+        let error = Derived::detached(None).with_sources(&sources);
+
+        let (value_target, error_target) = (value.clone(), error.clone());
+        let refresh: Arc<dyn Fn() + Send + Sync> = Arc::new(move || match compute() {
+            Ok(new_value) => {
+                value_target.replace(Some(new_value));
+                error_target.replace(None);
+            }
+            Err(new_error) => error_target.replace(Some(new_error)),
+        });
+        refresh();
+        subscribe_all(deps, &refresh);
+
+        Self { value, error }
+    }
+
+    /// The result of the last successful computation, `None` if there was none yet.
+    pub fn value(&self) -> &Derived<Option<T>> {
+        &self.value
+    }
+
+    /// The error of the latest computation, `None` once it succeeds again.
+    pub fn error(&self) -> &Derived<Option<E>> {
+        &self.error
+    }
+
+    /// The last good value.
+    pub fn get(&self) -> Option<T> {
+        self.value.get()
+    }
+
+    /// `true` unless the latest computation failed.
+    pub fn is_ok(&self) -> bool {
+        self.error.get().is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reactive::core::dep;
+    use crate::reactive::dynamic::Dynamic;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_error_keeps_last_good_value() {
+        let input = Dynamic::new("oops".to_string());
+        let source = input.clone();
+        let parsed = TryDerived::new(&[dep(&input)], move || source.get().parse::<i32>());
+        assert_eq!(parsed.get(), None);
+        assert!(!parsed.is_ok());
+
+        input.set("42".to_string());
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(parsed.get(), Some(42));
+        assert_eq!(parsed.error().get(), None);
+
+        input.set("4x2".to_string());
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(parsed.value().get(), Some(42));
+        assert!(parsed.error().get().is_some());
+        assert!(!parsed.is_ok());
+
+        input.set("7".to_string());
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(parsed.get(), Some(7));
+        assert!(parsed.is_ok());
+    }
+}
@@ -0,0 +1,137 @@
+//! ValidatedDynamic – a `Dynamic` paired with a validator, for form fields.
+//!
+//! The validity of the value and the validator's message are exposed as `Derived`
+//! signals, recomputed on every edit, so a form can disable its submit button or
+//! show the error without checking the input itself. With the `widgets` feature,
+//! `bind_validated_text_edit` draws a text field that turns red while invalid.
+//!
+//! # Example
+//! ```rust
+//! use egui_mobius_reactive::ValidatedDynamic;
+//!
+//! let project = ValidatedDynamic::new("my_app".to_string(), |name: &String| {
+//!     if name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+//!         Ok(())
+//!     } else {
+//!         Err("only letters, digits and '_' are allowed".to_string())
+//!     }
+//! });
+//! assert!(project.is_valid().get());
+//!
+//! project.set("my app".to_string());
+//! # std::thread::sleep(std::time::Duration::from_millis(50));
+//! assert!(!project.is_valid().get());
+//! assert_eq!(
+//!     project.error().get().as_deref(),
+//!     Some("only letters, digits and '_' are allowed")
+//! );
+//! ```
+
+use crate::reactive::core::dep;
+use crate::reactive::derived::Derived;
+use crate::reactive::dynamic::Dynamic;
+use std::sync::Arc;
+
+/// Checks a value, returning the message to show when it is invalid.
+type Validator<T> = Arc<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+
+/// A reactive value with a validator whose verdict is itself reactive.
+///
+/// Clones share the value and the derived signals.
+#[derive(Clone)]
+pub struct ValidatedDynamic<T: Clone + PartialEq + Send + Sync + 'static> {
+    value: Dynamic<T>,
+    validator: Validator<T>,
+    is_valid: Derived<bool>,
+    error: Derived<Option<String>>,
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> ValidatedDynamic<T> {
+    /// Creates a validated value; `validator` returns `Err(message)` for invalid values.
+    pub fn new(
+        initial: T,
+        validator: impl Fn(&T) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        let value = Dynamic::new(initial);
+        let validator: Validator<T> = Arc::new(validator);
+
+        let (source, check) = (value.clone(), validator.clone());
+        let is_valid = Derived::new(&[dep(&value)], move || check(&source.get()).is_ok());
+        let (source, check) = (value.clone(), validator.clone());
+        let error = Derived::new(&[dep(&value)], move || check(&source.get()).err());
+
+        Self {
+            value,
+            validator,
+            is_valid,
+            error,
+        }
+    }
+
+    /// The underlying value, e.g. to derive from it or register it.
+    pub fn value(&self) -> &Dynamic<T> {
+        &self.value
+    }
+
+    /// The current value.
+    pub fn get(&self) -> T {
+        self.value.get()
+    }
+
+    /// Stores `value`, valid or not; the derived signals follow.
+    pub fn set(&self, value: T) {
+        self.value.set(value);
+    }
+
+    /// `true` while the value passes the validator.
+    pub fn is_valid(&self) -> &Derived<bool> {
+        &self.is_valid
+    }
+
+    /// The validator's message while the value is invalid, `None` otherwise.
+    pub fn error(&self) -> &Derived<Option<String>> {
+        &self.error
+    }
+
+    /// Runs the validator on the current value right now.
+    ///
+    /// Unlike `is_valid` and `error`, which are updated by the reactive graph
+    /// shortly after a change, this sees an edit made in the same frame.
+    pub fn validate(&self) -> Result<(), String> {
+        (self.validator)(&self.value.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validity_and_error_follow_edits() {
+        let port = ValidatedDynamic::new("8080".to_string(), |text: &String| {
+            text.parse::<u16>()
+                .map(|_| ())
+                .map_err(|_| format!("'{text}' is not a port number"))
+        });
+        assert!(port.is_valid().get());
+        assert_eq!(port.error().get(), None);
+
+        port.set("80a".to_string());
+        assert_eq!(
+            port.validate(),
+            Err("'80a' is not a port number".to_string())
+        );
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(!port.is_valid().get());
+        assert_eq!(
+            port.error().get(),
+            Some("'80a' is not a port number".to_string())
+        );
+
+        port.set("80".to_string());
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(port.is_valid().get());
+        assert_eq!(port.error().get(), None);
+        assert_eq!(port.get(), "80");
+    }
+}
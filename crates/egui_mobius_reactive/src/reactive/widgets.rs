@@ -1,7 +1,10 @@
 //! ReactiveWidgets – retained-style reactive Widgets for immediate-mode UI
+use crate::reactive::derived::Derived;
 use crate::reactive::dynamic::Dynamic;
 use crate::reactive::reactive_state::ReactiveWidgetRef;
-use egui::Ui;
+use crate::reactive::validated::ValidatedDynamic;
+use egui::{Color32, CornerRadius, Sense, Stroke, Ui, Vec2};
+use egui_mobius_widgets::{MobiusTheme, StyledButton};
 use std::ops::RangeInclusive;
 
 pub struct ReactiveSlider<'a, T> {
@@ -10,6 +13,7 @@ pub struct ReactiveSlider<'a, T> {
     display_value: bool,
     logarithmic: bool,
     text: Option<String>,
+    theme: Option<MobiusTheme>,
     // more configuration options...
 }
 
@@ -23,6 +27,7 @@ impl<'a, T: Send + Sync + Clone + Into<f64> + From<f64> + std::fmt::Display + 's
             display_value: false,
             logarithmic: false,
             text: None,
+            theme: None,
         }
     }
 
@@ -46,7 +51,32 @@ impl<'a, T: Send + Sync + Clone + Into<f64> + From<f64> + std::fmt::Display + 's
         self
     }
 
-    pub fn show(self, ui: &mut Ui) -> egui::Response {
+    /// Styles the slider from a shared `MobiusTheme`: the accent color fills the
+    /// slider rail and the theme's rounding applies to the handle and rail.
+    pub fn apply_theme(mut self, theme: &MobiusTheme) -> Self {
+        self.theme = Some(theme.clone());
+        self
+    }
+
+    pub fn show(mut self, ui: &mut Ui) -> egui::Response {
+        match self.theme.take() {
+            Some(theme) => {
+                ui.scope(|ui| {
+                    let visuals = ui.visuals_mut();
+                    visuals.selection.bg_fill = theme.accent_color;
+                    let rounding = CornerRadius::from(theme.rounding);
+                    visuals.widgets.inactive.corner_radius = rounding;
+                    visuals.widgets.hovered.corner_radius = rounding;
+                    visuals.widgets.active.corner_radius = rounding;
+                    self.show_slider(ui, true)
+                })
+                .inner
+            }
+            None => self.show_slider(ui, false),
+        }
+    }
+
+    fn show_slider(self, ui: &mut Ui, trailing_fill: bool) -> egui::Response {
         // Create the widget reference (avoids double Arc)
         let mut widget_ref = ReactiveWidgetRef::from_dynamic(self.value);
 
@@ -78,6 +108,10 @@ impl<'a, T: Send + Sync + Clone + Into<f64> + From<f64> + std::fmt::Display + 's
             slider = slider.logarithmic(true);
         }
 
+        if trailing_fill {
+            slider = slider.trailing_fill(true);
+        }
+
         // Add the slider and handle response
         let response = ui.add(slider);
 
@@ -98,3 +132,436 @@ impl<'a, T: Send + Sync + Clone + Into<f64> + From<f64> + std::fmt::Display + 's
         response
     }
 }
+
+impl Derived<String> {
+    /// Shows the current text as a label, e.g. one built by `Dynamic::to_display`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use egui_mobius_reactive::{Derived, Dynamic};
+    ///
+    /// fn counter(ui: &mut egui::Ui, text: &Derived<String>) {
+    ///     text.bind_label(ui);
+    /// }
+    ///
+    /// let count = Dynamic::new(0);
+    /// let text = count.to_display(|c| format!("Count: {c}"));
+    /// ```
+    pub fn bind_label(&self, ui: &mut Ui) -> egui::Response {
+        ui.label(self.get())
+    }
+}
+
+impl<T: Clone + Send + 'static> Dynamic<T> {
+    /// Maps this value to a color that is recomputed on every `set`, for
+    /// data-driven tinting of labels, plots or indicators.
+    ///
+    /// # Arguments
+    /// * `f` - Picks the color for a value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use egui::Color32;
+    /// use egui_mobius_reactive::Dynamic;
+    ///
+    /// let temperature = Dynamic::new(20.0_f32);
+    /// let tint = temperature.map_color(|celsius| {
+    ///     let t = ((celsius - 20.0) / 80.0).clamp(0.0, 1.0);
+    ///     Color32::LIGHT_BLUE.lerp_to_gamma(Color32::RED, t)
+    /// });
+    ///
+    /// temperature.set(100.0);
+    /// std::thread::sleep(std::time::Duration::from_millis(50));
+    /// assert_eq!(tint.get(), Color32::RED);
+    ///
+    /// fn reading(ui: &mut egui::Ui, temperature: &Dynamic<f32>, tint: &egui_mobius_reactive::Derived<Color32>) {
+    ///     ui.colored_label(tint.get(), format!("{:.1} °C", temperature.get()));
+    /// }
+    /// ```
+    pub fn map_color<F>(&self, f: F) -> Derived<Color32>
+    where
+        F: Fn(&T) -> Color32 + Send + 'static,
+    {
+        let derived = Derived::detached(f(&self.lock())).with_sources(&[Some(self.id())]);
+        let target = derived.clone();
+        self.watch(move |value| target.replace(f(&value)));
+        derived
+    }
+}
+
+/// Shows a standard egui slider over `range` for `value`, writing edits straight
+/// back into the `Dynamic`.
+///
+/// Returns `true` on the frame the slider changed the value; anything derived from
+/// `value` recomputes as for any other `set`.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_mobius_reactive::{Dynamic, bind_slider};
+///
+/// fn volume_control(ui: &mut egui::Ui, volume: &Dynamic<f32>) {
+///     if bind_slider(ui, volume, 0.0..=1.0) {
+///         println!("volume is now {}", volume.get());
+///     }
+/// }
+/// ```
+pub fn bind_slider<T>(ui: &mut Ui, value: &Dynamic<T>, range: RangeInclusive<T>) -> bool
+where
+    T: egui::emath::Numeric + Send + Sync + 'static,
+{
+    let mut edited = value.get();
+    let changed = ui.add(egui::Slider::new(&mut edited, range)).changed();
+    write_back(value, edited, changed)
+}
+
+/// Shows a single-line egui text field for `value`, writing edits straight back
+/// into the `Dynamic`.
+///
+/// Returns `true` on the frame the text was edited; anything derived from `value`
+/// recomputes as for any other `set`.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_mobius_reactive::{Dynamic, bind_text_edit};
+///
+/// let name = Dynamic::new(String::new());
+/// let greeting = name.to_display(|name| format!("Hello, {name}!"));
+///
+/// fn name_field(ui: &mut egui::Ui, name: &Dynamic<String>) {
+///     bind_text_edit(ui, name);
+/// }
+/// ```
+pub fn bind_text_edit(ui: &mut Ui, value: &Dynamic<String>) -> bool {
+    let mut edited = value.get();
+    let changed = ui.text_edit_singleline(&mut edited).changed();
+    write_back(value, edited, changed)
+}
+
+/// A single-line text field bound to a `ValidatedDynamic<String>`.
+///
+/// While the text fails validation, the field gets an error-colored border and the
+/// validator's message is shown below it. Returns `true` on the frame the text
+/// was edited.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_mobius_reactive::{ValidatedDynamic, bind_validated_text_edit};
+///
+/// fn project_form(ui: &mut egui::Ui, name: &ValidatedDynamic<String>) {
+///     ui.label("Project name");
+///     bind_validated_text_edit(ui, name);
+///     ui.add_enabled(name.is_valid().get(), egui::Button::new("Create"));
+/// }
+/// ```
+pub fn bind_validated_text_edit(ui: &mut Ui, field: &ValidatedDynamic<String>) -> bool {
+    let mut edited = field.get();
+    let response = ui.text_edit_singleline(&mut edited);
+    let changed = write_back(field.value(), edited, response.changed());
+    // Validated directly so the border reacts on the frame of the edit
+    if let Err(message) = field.validate() {
+        let color = ui.visuals().error_fg_color;
+        ui.painter().rect_stroke(
+            response.rect,
+            ui.visuals().widgets.inactive.corner_radius,
+            Stroke::new(1.5, color),
+            egui::StrokeKind::Outside,
+        );
+        ui.colored_label(color, message);
+    }
+    changed
+}
+
+/// Stores the widget's copy of the value when the widget reported an edit.
+fn write_back<T: Clone + Send + Sync + 'static>(
+    value: &Dynamic<T>,
+    edited: T,
+    changed: bool,
+) -> bool {
+    if changed {
+        value.set(edited);
+    }
+    changed
+}
+
+/// Reactive enabled and visible state for `StyledButton`.
+///
+/// The condition is read when the button is built, and buttons are built every
+/// frame, so the button follows the reactive graph without manual checks.
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_mobius_reactive::{Derived, ReactiveButtonState};
+/// use egui_mobius_widgets::StyledButton;
+///
+/// fn reset_button(ui: &mut egui::Ui, over_limit: &Derived<bool>) {
+///     if StyledButton::new("Reset").enabled_when(over_limit).show(ui).clicked() {
+///         println!("reset");
+///     }
+/// }
+/// ```
+pub trait ReactiveButtonState {
+    /// Renders the button disabled while `condition` is `false`.
+    fn enabled_when(self, condition: &Derived<bool>) -> Self;
+    /// Hides the button, keeping its space, while `condition` is `false`.
+    fn visible_when(self, condition: &Derived<bool>) -> Self;
+}
+
+impl ReactiveButtonState for StyledButton {
+    fn enabled_when(self, condition: &Derived<bool>) -> Self {
+        self.enabled(condition.get())
+    }
+
+    fn visible_when(self, condition: &Derived<bool>) -> Self {
+        self.visible(condition.get())
+    }
+}
+
+/// What a `ReactiveLed` shows for the current value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LedState {
+    /// Lit with the LED's `on_color`.
+    On,
+    /// Unlit, drawn with the LED's `off_color`.
+    Off,
+    /// Lit with a specific color, for multi-state indicators.
+    Color(Color32),
+}
+
+/// A status indicator drawing a colored circle for the current value of a `Dynamic`
+/// or a `Derived<bool>`.
+///
+/// The value is read on every frame, so changes made from background threads show
+/// up on the next repaint (see `Dynamic::bind_repaint` to trigger that repaint).
+///
+/// # Example
+/// ```rust,no_run
+/// use egui_mobius_reactive::{Dynamic, LedState, ReactiveLed};
+///
+/// #[derive(Clone)]
+/// enum Link { Up, Degraded, Down }
+///
+/// fn status_bar(ui: &mut egui::Ui, running: &Dynamic<bool>, link: &Dynamic<Link>) {
+///     ReactiveLed::new(running).label("Running").blink(true).show(ui);
+///     ReactiveLed::with_states(link, |link| match link {
+///         Link::Up => LedState::On,
+///         Link::Degraded => LedState::Color(egui::Color32::YELLOW),
+///         Link::Down => LedState::Color(egui::Color32::RED),
+///     })
+///     .label("Link")
+///     .show(ui);
+/// }
+/// ```
+pub struct ReactiveLed<'a> {
+    /// Reads the current value and maps it to a `LedState`.
+    state: Box<dyn Fn() -> LedState + 'a>,
+    on_color: Color32,
+    off_color: Color32,
+    radius: f32,
+    blink: bool,
+    label: Option<String>,
+}
+
+impl<'a> ReactiveLed<'a> {
+    /// Creates an LED that is lit while `value` is `true`.
+    pub fn new(value: &'a Dynamic<bool>) -> Self {
+        Self::with_states(value, |on| lit_when(*on))
+    }
+
+    /// Creates an LED that is lit while a derived value is `true`, such as the
+    /// result of `ReactiveCompare::gt`.
+    pub fn from_derived(value: &'a Derived<bool>) -> Self {
+        Self::from_reader(move || lit_when(value.get()))
+    }
+
+    /// Creates a multi-state LED, mapping each value to a `LedState`.
+    pub fn with_states<T>(value: &'a Dynamic<T>, state: impl Fn(&T) -> LedState + 'a) -> Self {
+        Self::from_reader(move || state(&value.lock()))
+    }
+
+    fn from_reader(state: impl Fn() -> LedState + 'a) -> Self {
+        Self {
+            state: Box::new(state),
+            on_color: Color32::GREEN,
+            off_color: Color32::from_gray(90),
+            radius: 6.0,
+            blink: false,
+            label: None,
+        }
+    }
+
+    /// Sets the color used for `LedState::On`.
+    pub fn on_color(mut self, color: Color32) -> Self {
+        self.on_color = color;
+        self
+    }
+
+    /// Sets the color used for `LedState::Off`.
+    pub fn off_color(mut self, color: Color32) -> Self {
+        self.off_color = color;
+        self
+    }
+
+    /// Sets the radius of the LED in points.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Makes a lit LED blink at 2 Hz. An unlit LED never blinks.
+    pub fn blink(mut self, blink: bool) -> Self {
+        self.blink = blink;
+        self
+    }
+
+    /// Shows a label to the right of the LED.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> egui::Response {
+        let time = ui.input(|i| i.time);
+        // Read once, so the lit check and the color agree within a frame
+        let state = (self.state)();
+        let color = self.color_at(state, time);
+        if self.blink && state != LedState::Off {
+            ui.ctx()
+                .request_repaint_after(std::time::Duration::from_millis(250));
+        }
+
+        ui.horizontal(|ui| {
+            let size = Vec2::splat(self.radius * 2.0);
+            let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+            let painter = ui.painter();
+            painter.circle_filled(rect.center(), self.radius, color);
+            painter.circle_stroke(
+                rect.center(),
+                self.radius,
+                Stroke::new(1.0, Color32::from_black_alpha(160)),
+            );
+            if let Some(label) = &self.label {
+                ui.label(label);
+            }
+            response
+        })
+        .inner
+    }
+
+    /// Color to draw for `state` at egui time `time`, taking blinking into account.
+    fn color_at(&self, state: LedState, time: f64) -> Color32 {
+        let blink_off = self.blink && (time * 4.0) as u64 % 2 == 1;
+        match state {
+            LedState::Off => self.off_color,
+            _ if blink_off => self.off_color,
+            LedState::On => self.on_color,
+            LedState::Color(color) => color,
+        }
+    }
+}
+
+fn lit_when(on: bool) -> LedState {
+    if on { LedState::On } else { LedState::Off }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_color_follows_value() {
+        let level = Dynamic::new(0u8);
+        let tint = level.map_color(|&level| match level {
+            0..=49 => Color32::GREEN,
+            50..=89 => Color32::YELLOW,
+            _ => Color32::RED,
+        });
+        assert_eq!(tint.get(), Color32::GREEN);
+
+        level.set(75);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(tint.get(), Color32::YELLOW);
+
+        level.set(95);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(tint.get(), Color32::RED);
+    }
+
+    #[test]
+    fn test_bound_edit_updates_dynamic_and_derived() {
+        let name = Dynamic::new("Ada".to_string());
+        let greeting = name.to_display(|name| format!("Hello, {name}!"));
+
+        assert!(!write_back(&name, "ignored".to_string(), false));
+        assert_eq!(name.get(), "Ada");
+
+        assert!(write_back(&name, "Grace".to_string(), true));
+        assert_eq!(name.get(), "Grace");
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(greeting.get(), "Hello, Grace!");
+    }
+
+    /// The color `led` shows at egui time `time` for its current value.
+    fn color(led: &ReactiveLed, time: f64) -> Color32 {
+        led.color_at((led.state)(), time)
+    }
+
+    #[test]
+    fn test_led_follows_dynamic_and_blinks() {
+        let running = Dynamic::new(false);
+        let led = ReactiveLed::new(&running)
+            .on_color(Color32::GREEN)
+            .off_color(Color32::RED)
+            .blink(true);
+        assert_eq!(color(&led, 0.0), Color32::RED);
+
+        // A change from another thread is visible on the next read
+        let writer = running.clone();
+        std::thread::spawn(move || writer.set(true)).join().unwrap();
+        assert_eq!(color(&led, 0.0), Color32::GREEN);
+        assert_eq!(color(&led, 0.3), Color32::RED);
+    }
+
+    #[test]
+    fn test_led_multi_state() {
+        let level = Dynamic::new(2u8);
+        let led = ReactiveLed::with_states(&level, |level| match level {
+            0 => LedState::Off,
+            1 => LedState::On,
+            _ => LedState::Color(Color32::YELLOW),
+        });
+        assert_eq!(color(&led, 0.0), Color32::YELLOW);
+        level.set(0);
+        assert_eq!(color(&led, 0.0), Color32::from_gray(90));
+    }
+
+    #[test]
+    fn test_led_follows_derived_comparison() {
+        use crate::ReactiveCompare;
+
+        let count = Dynamic::new(3);
+        let over_ten = count.gt(&Dynamic::new(10));
+        let led = ReactiveLed::from_derived(&over_ten);
+        assert_eq!(color(&led, 0.0), Color32::from_gray(90));
+
+        count.set(11);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert_eq!(color(&led, 0.0), Color32::GREEN);
+    }
+
+    #[test]
+    fn test_button_enabled_when_derived_holds() {
+        use crate::ReactiveCompare;
+
+        let count = Dynamic::new(3);
+        let over_ten = count.gt(&Dynamic::new(10));
+        assert!(!StyledButton::new("Go").enabled_when(&over_ten).is_enabled());
+
+        count.set(11);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        let button = StyledButton::new("Go")
+            .enabled_when(&over_ten)
+            .visible_when(&over_ten);
+        assert!(button.is_enabled());
+        assert!(button.is_visible());
+    }
+}
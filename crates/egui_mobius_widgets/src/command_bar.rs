@@ -0,0 +1,134 @@
+//! A row of labeled buttons that each send an event through a `Signal<E>`.
+//!
+//! `CommandBar` replaces the "generate buttons from a command list" convenience of the
+//! removed `AsCommand` derive: list the commands once as `(label, event)` pairs and the
+//! bar renders one button per command, sending a clone of its event on click. The
+//! receiving `Slot<E>` handles the commands exactly like any other signal/slot traffic.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use egui_mobius::factory::create_signal_slot;
+//! use egui_mobius_widgets::CommandBar;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Command {
+//!     Start,
+//!     Stop,
+//!     ClearLog,
+//! }
+//!
+//! let (signal, mut slot) = create_signal_slot::<Command>();
+//! slot.start(|command| println!("backend got {command:?}")).unwrap();
+//!
+//! let commands = CommandBar::new(
+//!     signal,
+//!     vec![("Start", Command::Start), ("Stop", Command::Stop), ("Clear", Command::ClearLog)],
+//! );
+//!
+//! fn toolbar(ui: &mut egui::Ui, commands: &CommandBar<Command>) {
+//!     commands.show(ui);
+//! }
+//! ```
+
+use crate::{MobiusTheme, StyledButton};
+use egui::Ui;
+use egui_mobius::Signal;
+
+/// Renders one button per command and sends the command's event when clicked.
+pub struct CommandBar<E> {
+    signal: Signal<E>,
+    commands: Vec<(String, E)>,
+    theme: Option<MobiusTheme>,
+}
+
+impl<E> CommandBar<E>
+where
+    E: Clone + Send + 'static,
+{
+    /// Creates a bar sending the events of `commands` through `signal`.
+    ///
+    /// Buttons appear in the order of `commands`.
+    pub fn new(signal: Signal<E>, commands: Vec<(&str, E)>) -> Self {
+        Self {
+            signal,
+            commands: commands
+                .into_iter()
+                .map(|(label, event)| (label.to_string(), event))
+                .collect(),
+            theme: None,
+        }
+    }
+
+    /// Draws the buttons as themed `StyledButton`s instead of plain egui buttons.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn apply_theme(mut self, theme: &MobiusTheme) -> Self {
+        self.theme = Some(theme.clone());
+        self
+    }
+
+    /// The labels of the commands, in display order.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.commands.iter().map(|(label, _)| label.as_str())
+    }
+
+    /// Shows the buttons in a wrapping row.
+    ///
+    /// Returns the event sent this frame, if a button was clicked. A send that
+    /// fails because the slot is gone is reported on stderr by `Signal::send`.
+    pub fn show(&self, ui: &mut Ui) -> Option<E> {
+        let mut clicked = None;
+        ui.horizontal_wrapped(|ui| {
+            for (index, (label, _)) in self.commands.iter().enumerate() {
+                let response = match &self.theme {
+                    Some(theme) => StyledButton::new(label.as_str())
+                        .apply_theme(theme)
+                        .show(ui),
+                    None => ui.button(label.as_str()),
+                };
+                if response.clicked() {
+                    clicked = Some(index);
+                }
+            }
+        });
+        clicked.and_then(|index| self.trigger(index))
+    }
+
+    /// Sends the event of the command at `index`, as a click on its button does.
+    fn trigger(&self, index: usize) -> Option<E> {
+        let (_, event) = self.commands.get(index)?;
+        let _ = self.signal.send(event.clone());
+        Some(event.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui_mobius::factory::create_signal_slot;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Command {
+        Start,
+        Stop,
+    }
+
+    #[test]
+    fn test_command_bar_sends_clicked_event() {
+        let (signal, slot) = create_signal_slot::<Command>();
+        let bar = CommandBar::new(
+            signal,
+            vec![("Start", Command::Start), ("Stop", Command::Stop)],
+        );
+        assert_eq!(bar.labels().collect::<Vec<_>>(), ["Start", "Stop"]);
+
+        assert_eq!(bar.trigger(1), Some(Command::Stop));
+        assert_eq!(bar.trigger(2), None);
+        let receiver = slot.receiver.lock().unwrap();
+        assert_eq!(receiver.try_recv(), Ok(Command::Stop));
+        assert!(receiver.try_recv().is_err());
+    }
+}
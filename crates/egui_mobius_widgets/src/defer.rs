@@ -0,0 +1,112 @@
+//! Signal sends deferred to the end of the egui pass.
+//!
+//! UI code often sends an event while it still holds a `Value` guard on the
+//! application state. `defer_signal` queues the send on a per-context
+//! `DeferredDispatch` instead, and the queue is flushed by egui once the pass has
+//! ended, when every guard taken during the frame has been dropped.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use egui_mobius::Signal;
+//! use egui_mobius::types::Value;
+//! use egui_mobius_widgets::DeferSignal;
+//!
+//! fn ui(ui: &mut egui::Ui, state: &Value<u32>, signal: &Signal<u32>) {
+//!     if ui.button("Increment").clicked() {
+//!         let mut count = state.lock().unwrap();
+//!         *count += 1;
+//!         // Sent after the frame, so the slot can lock `state` freely
+//!         ui.defer_signal(signal, *count);
+//!     }
+//! }
+//! ```
+
+use egui::{Context, Id, Ui};
+use egui_mobius::{DeferredDispatch, Signal};
+use std::sync::Arc;
+
+/// Key of the context's `DeferredDispatch` in egui's temporary data.
+fn dispatch_id() -> Id {
+    Id::new("egui_mobius_deferred_dispatch")
+}
+
+/// The `DeferredDispatch` flushed at the end of every pass of `ctx`.
+///
+/// Created on first use, which also registers the end-of-pass flush.
+pub fn frame_dispatch(ctx: &Context) -> DeferredDispatch {
+    let (dispatch, created) =
+        ctx.data_mut(
+            |data| match data.get_temp::<DeferredDispatch>(dispatch_id()) {
+                Some(dispatch) => (dispatch, false),
+                None => {
+                    let dispatch = DeferredDispatch::new();
+                    data.insert_temp(dispatch_id(), dispatch.clone());
+                    (dispatch, true)
+                }
+            },
+        );
+    if created {
+        let queue = dispatch.clone();
+        ctx.on_end_pass(
+            "egui_mobius deferred dispatch",
+            Arc::new(move |ctx| {
+                queue.flush();
+                // Sends that found a full queue are retried at the end of the next pass
+                if !queue.is_empty() {
+                    ctx.request_repaint();
+                }
+            }),
+        );
+    }
+    dispatch
+}
+
+/// Queue a signal send until the current egui pass has ended.
+pub trait DeferSignal {
+    /// Send `msg` through `signal` once the pass is over, after any lock held
+    /// by the caller has been released.
+    fn defer_signal<T: Send + 'static>(&self, signal: &Signal<T>, msg: T);
+}
+
+impl DeferSignal for Context {
+    fn defer_signal<T: Send + 'static>(&self, signal: &Signal<T>, msg: T) {
+        frame_dispatch(self).defer(signal, msg);
+    }
+}
+
+impl DeferSignal for Ui {
+    fn defer_signal<T: Send + 'static>(&self, signal: &Signal<T>, msg: T) {
+        self.ctx().defer_signal(signal, msg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui_mobius::factory::create_signal_slot;
+    use egui_mobius::types::Value;
+
+    #[test]
+    fn test_deferred_sends_flush_at_end_of_pass() {
+        let ctx = Context::default();
+        let state = Value::new(0u32);
+        let (signal, slot) = create_signal_slot::<u32>();
+        let receiver = slot.receiver.clone();
+
+        let _ = ctx.run(Default::default(), |ctx| {
+            let mut count = state.lock().unwrap();
+            for _ in 0..2 {
+                *count += 1;
+                ctx.defer_signal(&signal, *count);
+            }
+            assert_eq!(frame_dispatch(ctx).len(), 2);
+            assert!(receiver.lock().unwrap().try_recv().is_err());
+        });
+
+        let receiver = receiver.lock().unwrap();
+        assert_eq!(receiver.try_recv(), Ok(1));
+        assert_eq!(receiver.try_recv(), Ok(2));
+        assert!(frame_dispatch(&ctx).is_empty());
+    }
+}
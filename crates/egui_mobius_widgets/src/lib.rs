@@ -9,6 +9,19 @@
 //!   visually consistent and appealing UIs.
 //! - **StatefulButton**: A button that maintains its state between frames with customizable colors
 //!   and behavior, ideal for toggle switches and start/stop controls.
+//! - **ToastHost**: A stack of auto-dismissing notification toasts fed by a `Signal<Toast>`, for
+//!   reporting errors and status from background tasks.
+//! - **CommandBar**: A row of buttons generated from a `(label, event)` list, each sending its
+//!   event through a `Signal<E>`; the successor of the removed `AsCommand` derive.
+//! - **MobiusTheme**: Shared colors and radii applied to every widget via `apply_theme`, so one
+//!   theme change restyles a whole toolbar.
+//! - **DeferSignal**: `ui.defer_signal(signal, msg)` queues a send until the end of the egui
+//!   pass, so events raised while holding a state lock are delivered once it is released.
+//! - **ButtonTheme** (feature `theme`): Button styling loaded from RON or YAML files, so themes
+//!   can be tweaked without recompiling.
+//! - **ThemeWatcher** (feature `theme-watch`): Reloads a `ButtonTheme` or `MobiusTheme` file
+//!   whenever it is saved and sends it through a `Signal`, so the UI restyles live while the
+//!   file is edited.
 //!
 //! ## Basic Example
 //!
@@ -70,3 +83,25 @@ pub use styled_button::StyledButton;
 
 pub mod stateful_button;
 pub use stateful_button::StatefulButton;
+
+pub mod toast;
+pub use toast::{Toast, ToastHost, ToastLevel};
+
+pub mod command_bar;
+pub use command_bar::CommandBar;
+
+pub mod mobius_theme;
+pub use mobius_theme::MobiusTheme;
+
+pub mod defer;
+pub use defer::{DeferSignal, frame_dispatch};
+
+#[cfg(feature = "theme")]
+pub mod theme;
+#[cfg(feature = "theme")]
+pub use theme::{ButtonTheme, ThemeError};
+
+#[cfg(feature = "theme-watch")]
+pub mod theme_watch;
+#[cfg(feature = "theme-watch")]
+pub use theme_watch::ThemeWatcher;
@@ -0,0 +1,75 @@
+//! A shared theme that restyles every egui_mobius widget consistently.
+//!
+//! Instead of repeating colors and radii on each builder, create one `MobiusTheme`
+//! and pass it to `apply_theme` on each widget. Changing the theme then restyles a
+//! whole toolbar at once.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use egui_mobius_widgets::{MobiusTheme, StatefulButton, StyledButton};
+//!
+//! fn toolbar(ui: &mut egui::Ui, theme: &MobiusTheme, run: &mut StatefulButton) {
+//!     if StyledButton::new("Reset").apply_theme(theme).show(ui).clicked() {
+//!         println!("reset");
+//!     }
+//!     run.show(ui);
+//! }
+//!
+//! let theme = MobiusTheme {
+//!     accent_color: egui::Color32::from_rgb(255, 170, 0),
+//!     rounding: 2.0,
+//!     ..Default::default()
+//! };
+//! let run = StatefulButton::new().apply_theme(&theme);
+//! ```
+
+use egui::{Color32, Vec2};
+
+/// Colors and radii shared by all egui_mobius widgets.
+///
+/// With the `theme` feature it can also be loaded from a RON or YAML file, see
+/// `MobiusTheme::from_file`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "theme",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct MobiusTheme {
+    /// Highlight color: hover borders and slider fills.
+    #[cfg_attr(feature = "theme", serde(with = "crate::theme::hex"))]
+    pub accent_color: Color32,
+    /// Border color of widgets at rest.
+    #[cfg_attr(feature = "theme", serde(with = "crate::theme::hex"))]
+    pub border_color: Color32,
+    /// Text color of widget labels.
+    #[cfg_attr(feature = "theme", serde(with = "crate::theme::hex"))]
+    pub text_color: Color32,
+    /// Color of a `StatefulButton` in the RUN state.
+    #[cfg_attr(feature = "theme", serde(with = "crate::theme::hex"))]
+    pub run_color: Color32,
+    /// Color of a `StatefulButton` in the STOP state.
+    #[cfg_attr(feature = "theme", serde(with = "crate::theme::hex"))]
+    pub stop_color: Color32,
+    /// Corner radius in pixels.
+    pub rounding: f32,
+    /// Horizontal and vertical margin around buttons.
+    #[cfg_attr(feature = "theme", serde(with = "crate::theme::vec2"))]
+    pub margin: Vec2,
+}
+
+impl Default for MobiusTheme {
+    /// The default theme matches the default look of `StyledButton`.
+    fn default() -> Self {
+        Self {
+            accent_color: Color32::from_rgb(100, 200, 255),
+            border_color: Color32::from_gray(128),
+            text_color: Color32::WHITE,
+            run_color: Color32::GREEN,
+            stop_color: Color32::RED,
+            rounding: 5.0,
+            margin: Vec2::new(10.0, 5.0),
+        }
+    }
+}
@@ -5,6 +5,8 @@
 //! - Customizable colors for each state
 //! - Adjustable corner rounding and margins
 //! - Hover effect with outer stroke
+//! - Optional momentary (press-and-hold) mode for jog-style controls
+//! - Optional callback fired on every state transition
 //! - Default implementation for easy instantiation
 //!
 //! # Example
@@ -25,9 +27,15 @@
 //! }
 //! ```
 
+use crate::MobiusTheme;
+#[cfg(feature = "theme")]
+use crate::theme::{ButtonTheme, ThemeError};
 use egui::epaint::StrokeKind;
 use egui::{Color32, CornerRadius, Response, Stroke, Ui, Vec2};
 
+/// Callback receiving the new state on every transition, see `on_state_change`.
+type StateChangeCallback = Box<dyn FnMut(bool) + Send>;
+
 /// A button that maintains its state (started/stopped) and changes appearance accordingly.
 ///
 /// The button supports:
@@ -45,7 +53,6 @@ use egui::{Color32, CornerRadius, Response, Stroke, Ui, Vec2};
 /// use egui_mobius_widgets::StatefulButton;
 /// use eframe::egui;
 ///
-#[derive(Debug)]
 pub struct StatefulButton {
     started: bool,
     margin: Vec2,
@@ -53,6 +60,22 @@ pub struct StatefulButton {
     min_size: Vec2,
     run_color: Color32,
     stop_color: Color32,
+    momentary: bool,
+    on_state_change: Option<StateChangeCallback>,
+}
+
+impl std::fmt::Debug for StatefulButton {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatefulButton")
+            .field("started", &self.started)
+            .field("margin", &self.margin)
+            .field("rounding", &self.rounding)
+            .field("min_size", &self.min_size)
+            .field("run_color", &self.run_color)
+            .field("stop_color", &self.stop_color)
+            .field("momentary", &self.momentary)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for StatefulButton {
@@ -72,6 +95,7 @@ impl StatefulButton {
     /// * `min_size` - Vec2::ZERO
     /// * `run_color` - Color32::GREEN
     /// * `stop_color` - Color32::RED
+    /// * `momentary` - false (latching toggle)
     pub fn new() -> Self {
         Self {
             started: false,
@@ -80,6 +104,8 @@ impl StatefulButton {
             min_size: Vec2::new(0.0, 0.0),
             run_color: Color32::GREEN,
             stop_color: Color32::RED,
+            momentary: false,
+            on_state_change: None,
         }
     }
 
@@ -153,10 +179,104 @@ impl StatefulButton {
         self
     }
 
+    /// Switches between latching and momentary behavior.
+    ///
+    /// A latching button (the default) toggles its state on every click. A momentary
+    /// button is started only while the pointer is held down on it and stops again on
+    /// release, which suits jog controls.
+    ///
+    /// # Arguments
+    ///
+    /// * `momentary` - `true` for press-and-hold behavior
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn momentary(mut self, momentary: bool) -> Self {
+        self.momentary = momentary;
+        self
+    }
+
+    /// Sets a callback fired with the new state whenever a click (or, in
+    /// momentary mode, a press or release) changes it.
+    ///
+    /// The callback runs once per transition rather than every frame, which makes
+    /// it a natural place to emit a start/stop signal. Changes made with
+    /// `set_started` do not fire it.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - Receives `true` when the button starts and `false` when it stops
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn on_state_change(mut self, callback: impl FnMut(bool) + Send + 'static) -> Self {
+        self.on_state_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Styles the button from a shared `MobiusTheme`.
+    ///
+    /// Uses the theme's run/stop colors, rounding and margin.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The theme to apply
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn apply_theme(mut self, theme: &MobiusTheme) -> Self {
+        self.run_color = theme.run_color;
+        self.stop_color = theme.stop_color;
+        self.rounding = theme.rounding;
+        self.margin = theme.margin;
+        self
+    }
+
+    /// Creates a stateful button whose styling is loaded from a RON or YAML theme file.
+    ///
+    /// Requires the `theme` feature. Fields missing from the file keep their defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to a `.ron`, `.yaml` or `.yml` `ButtonTheme` document
+    ///
+    /// # Returns
+    ///
+    /// Returns the stateful button, or a `ThemeError` if the file cannot be loaded
+    #[cfg(feature = "theme")]
+    pub fn from_style_file(path: impl AsRef<std::path::Path>) -> Result<Self, ThemeError> {
+        Ok(Self::new().theme(&ButtonTheme::from_file(path)?))
+    }
+
+    /// Applies every field set in a `ButtonTheme` to this button.
+    ///
+    /// Requires the `theme` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The theme to apply; `None` fields are left untouched
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    #[cfg(feature = "theme")]
+    pub fn theme(mut self, theme: &ButtonTheme) -> Self {
+        self.run_color = theme.run_color.unwrap_or(self.run_color);
+        self.stop_color = theme.stop_color.unwrap_or(self.stop_color);
+        self.rounding = theme.rounding.unwrap_or(self.rounding);
+        self.margin = theme.margin_vec().unwrap_or(self.margin);
+        self.min_size = theme.min_size_vec().unwrap_or(self.min_size);
+        self
+    }
+
     /// Shows the button in the UI and returns the response.
     ///
     /// The button's text will automatically switch between "RUN" and "STOP"
-    /// based on its current state. Clicking the button will toggle its state.
+    /// based on its current state. Clicking the button will toggle its state,
+    /// or, in momentary mode, holding it down keeps it started.
     ///
     /// # Arguments
     ///
@@ -204,13 +324,26 @@ impl StatefulButton {
             })
             .inner;
 
-        if response.clicked() {
-            self.started = !self.started;
-        }
+        self.handle_pointer(response.clicked(), response.is_pointer_button_down_on());
 
         response
     }
 
+    /// Updates the state from this frame's pointer interaction with the button.
+    fn handle_pointer(&mut self, clicked: bool, held: bool) {
+        let was_started = self.started;
+        if self.momentary {
+            self.started = held;
+        } else if clicked {
+            self.started = !self.started;
+        }
+        if self.started != was_started
+            && let Some(callback) = &mut self.on_state_change
+        {
+            callback(self.started);
+        }
+    }
+
     /// Returns the current state of the button.
     ///
     /// # Returns
@@ -275,6 +408,90 @@ mod tests {
         assert!(!button.is_started());
     }
 
+    #[test]
+    fn test_stateful_button_momentary_follows_hold() {
+        let mut button = StatefulButton::new().momentary(true);
+        assert!(!button.is_started());
+
+        // Press, hold for a few frames, then release (which also reports a click)
+        button.handle_pointer(false, true);
+        assert!(button.is_started());
+        button.handle_pointer(false, true);
+        assert!(button.is_started());
+        button.handle_pointer(true, false);
+        assert!(!button.is_started());
+
+        // The latching default toggles on the click and ignores the hold
+        let mut latching = StatefulButton::new();
+        latching.handle_pointer(false, true);
+        assert!(!latching.is_started());
+        latching.handle_pointer(true, false);
+        assert!(latching.is_started());
+        latching.handle_pointer(false, false);
+        assert!(latching.is_started());
+    }
+
+    #[test]
+    fn test_stateful_button_state_change_fires_once_per_transition() {
+        let changes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = changes.clone();
+        let mut button = StatefulButton::new()
+            .on_state_change(move |started| seen.lock().unwrap().push(started));
+
+        // Idle frames and a click toggling twice
+        button.handle_pointer(false, false);
+        button.handle_pointer(true, false);
+        button.handle_pointer(false, false);
+        button.handle_pointer(true, false);
+        // Programmatic changes stay silent
+        button.set_started(true);
+        assert_eq!(*changes.lock().unwrap(), vec![true, false]);
+
+        let seen = changes.clone();
+        let mut jog = StatefulButton::new()
+            .momentary(true)
+            .on_state_change(move |started| seen.lock().unwrap().push(started));
+        changes.lock().unwrap().clear();
+        for held in [true, true, true, false, false] {
+            jog.handle_pointer(!held, held);
+        }
+        assert_eq!(*changes.lock().unwrap(), vec![true, false]);
+    }
+
+    #[test]
+    fn test_stateful_button_apply_theme() {
+        let theme = MobiusTheme {
+            run_color: Color32::LIGHT_GREEN,
+            rounding: 3.0,
+            ..Default::default()
+        };
+        let button = StatefulButton::new().apply_theme(&theme);
+
+        assert_eq!(button.run_color, Color32::LIGHT_GREEN);
+        assert_eq!(button.stop_color, Color32::RED);
+        assert_eq!(button.rounding, 3.0);
+        assert_eq!(button.margin, theme.margin);
+    }
+
+    #[cfg(feature = "theme")]
+    #[test]
+    fn test_stateful_button_from_style_file() {
+        let path = std::env::temp_dir().join("egui_mobius_stateful_button_theme.yaml");
+        std::fs::write(
+            &path,
+            "run_color: '#00ff00'\nstop_color: '#ff0000'\nmin_size: [80.0, 24.0]\n",
+        )
+        .unwrap();
+
+        let button = StatefulButton::from_style_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(button.run_color, Color32::GREEN);
+        assert_eq!(button.stop_color, Color32::RED);
+        assert_eq!(button.min_size, Vec2::new(80.0, 24.0));
+        assert_eq!(button.rounding, 8.0);
+    }
+
     #[test]
     fn test_stateful_button_min_size() {
         let button = StatefulButton::new().min_size(Vec2::new(100.0, 50.0));
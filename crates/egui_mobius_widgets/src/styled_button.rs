@@ -5,6 +5,7 @@
 //! - Adjustable corner rounding
 //! - Configurable margins and minimum size
 //! - Hover effect with outer stroke
+//! - Enabled and visible state, e.g. driven by a reactive `Derived<bool>`
 //!
 //! # Example
 //!
@@ -25,6 +26,9 @@
 //! }
 //! ```
 
+use crate::MobiusTheme;
+#[cfg(feature = "theme")]
+use crate::theme::{ButtonTheme, ThemeError};
 use egui::epaint::StrokeKind;
 use egui::{Color32, CornerRadius, Response, Stroke, Ui, Vec2};
 
@@ -35,6 +39,7 @@ use egui::{Color32, CornerRadius, Response, Stroke, Ui, Vec2};
 /// - Hover and normal colors
 /// - Corner rounding
 /// - Margin and minimum size settings
+/// - Enabled and visible state
 #[derive(Debug)]
 pub struct StyledButton {
     text: String,
@@ -44,6 +49,8 @@ pub struct StyledButton {
     rounding: f32,
     margin: Vec2,
     min_size: Vec2,
+    enabled: bool,
+    visible: bool,
 }
 
 impl Default for StyledButton {
@@ -66,6 +73,7 @@ impl StyledButton {
     /// * `rounding` - 5.0 pixels
     /// * `margin` - Vec2::new(10.0, 5.0)
     /// * `min_size` - Vec2::ZERO
+    /// * `enabled` / `visible` - true
     pub fn new(text: impl Into<String>) -> Self {
         Self {
             text: text.into(),
@@ -75,6 +83,8 @@ impl StyledButton {
             rounding: 5.0,
             margin: Vec2::new(10.0, 5.0),
             min_size: Vec2::new(0.0, 0.0),
+            enabled: true,
+            visible: true,
         }
     }
 
@@ -162,6 +172,111 @@ impl StyledButton {
         self
     }
 
+    /// Sets whether the button can be clicked. A disabled button is grayed out.
+    ///
+    /// With the `widgets` feature of `egui_mobius_reactive`, `enabled_when` sets
+    /// this from a `Derived<bool>` each frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - `false` renders the button disabled
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets whether the button is drawn. A hidden button still takes up its space,
+    /// so the layout does not jump when it reappears.
+    ///
+    /// # Arguments
+    ///
+    /// * `visible` - `false` hides the button
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Returns `true` unless the button was disabled with `enabled(false)`.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns `true` unless the button was hidden with `visible(false)`.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Styles the button from a shared `MobiusTheme`.
+    ///
+    /// The theme's accent color becomes the hover color and its border color the
+    /// normal color; text color, rounding and margin are taken as-is.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The theme to apply
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn apply_theme(mut self, theme: &MobiusTheme) -> Self {
+        self.hover_color = theme.accent_color;
+        self.normal_color = theme.border_color;
+        self.text_color = theme.text_color;
+        self.rounding = theme.rounding;
+        self.margin = theme.margin;
+        self
+    }
+
+    /// Creates a styled button whose styling is loaded from a RON or YAML theme file.
+    ///
+    /// Requires the `theme` feature. Fields missing from the file keep their defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to display on the button
+    /// * `path` - Path to a `.ron`, `.yaml` or `.yml` `ButtonTheme` document
+    ///
+    /// # Returns
+    ///
+    /// Returns the styled button, or a `ThemeError` if the file cannot be loaded
+    #[cfg(feature = "theme")]
+    pub fn from_style_file(
+        text: impl Into<String>,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, ThemeError> {
+        Ok(Self::new(text).theme(&ButtonTheme::from_file(path)?))
+    }
+
+    /// Applies every field set in a `ButtonTheme` to this button.
+    ///
+    /// Requires the `theme` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The theme to apply; `None` fields are left untouched
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    #[cfg(feature = "theme")]
+    pub fn theme(mut self, theme: &ButtonTheme) -> Self {
+        self.hover_color = theme.hover_color.unwrap_or(self.hover_color);
+        self.normal_color = theme.normal_color.unwrap_or(self.normal_color);
+        self.text_color = theme.text_color.unwrap_or(self.text_color);
+        self.rounding = theme.rounding.unwrap_or(self.rounding);
+        self.margin = theme.margin_vec().unwrap_or(self.margin);
+        self.min_size = theme.min_size_vec().unwrap_or(self.min_size);
+        self
+    }
+
     /// Shows the button in the UI and returns the response.
     ///
     /// # Arguments
@@ -180,6 +295,8 @@ impl StyledButton {
             rounding,
             margin,
             min_size,
+            enabled,
+            visible,
         } = self;
 
         ui.add_space(margin.y);
@@ -190,20 +307,21 @@ impl StyledButton {
                 .corner_radius(CornerRadius::from(rounding))
                 .min_size(min_size);
 
-            let response = ui.add(button);
+            if !enabled {
+                ui.disable();
+            }
+            let response = ui.add_visible(visible, button);
 
-            if response.hovered() {
+            if visible {
+                let border_color = if response.hovered() {
+                    hover_color
+                } else {
+                    normal_color
+                };
                 ui.painter().rect_stroke(
                     response.rect,
                     CornerRadius::from(rounding),
-                    Stroke::new(1.0, hover_color),
-                    StrokeKind::Outside,
-                );
-            } else {
-                ui.painter().rect_stroke(
-                    response.rect,
-                    CornerRadius::from(rounding),
-                    Stroke::new(1.0, normal_color),
+                    Stroke::new(1.0, border_color),
                     StrokeKind::Outside,
                 );
             }
@@ -235,4 +353,41 @@ mod tests {
         assert_eq!(button.rounding, 10.0);
         assert_eq!(button.margin, Vec2::new(10.0, 5.0));
     }
+
+    #[test]
+    fn test_styled_button_apply_theme() {
+        let theme = MobiusTheme {
+            accent_color: Color32::YELLOW,
+            rounding: 2.0,
+            ..Default::default()
+        };
+        let button = StyledButton::new("Themed").apply_theme(&theme);
+
+        assert_eq!(button.hover_color, Color32::YELLOW);
+        assert_eq!(button.normal_color, theme.border_color);
+        assert_eq!(button.text_color, theme.text_color);
+        assert_eq!(button.rounding, 2.0);
+        assert_eq!(button.margin, theme.margin);
+    }
+
+    #[cfg(feature = "theme")]
+    #[test]
+    fn test_styled_button_from_style_file() {
+        let path = std::env::temp_dir().join("egui_mobius_styled_button_theme.ron");
+        std::fs::write(
+            &path,
+            r##"(hover_color: "#ff0000", normal_color: "#0000ff", rounding: 12.0)"##,
+        )
+        .unwrap();
+
+        let button = StyledButton::from_style_file("Themed", &path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(button.text, "Themed");
+        assert_eq!(button.hover_color, Color32::RED);
+        assert_eq!(button.normal_color, Color32::BLUE);
+        assert_eq!(button.rounding, 12.0);
+        // Not in the file, so the default is kept
+        assert_eq!(button.text_color, Color32::WHITE);
+    }
 }
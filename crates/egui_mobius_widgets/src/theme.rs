@@ -0,0 +1,292 @@
+//! Config-driven button styling loaded from RON or YAML files.
+//!
+//! A `ButtonTheme` maps onto the builder fields of `StyledButton` and `StatefulButton`,
+//! so designers can tweak colors, rounding and margins without recompiling. Every
+//! field is optional; anything left out keeps the widget's default. Colors are
+//! written as hex strings (`"#RRGGBB"` or `"#RRGGBBAA"`).
+//!
+//! A `MobiusTheme` loads from the same formats, with every field written out or
+//! left at its default.
+//!
+//! Requires the `theme` feature.
+//!
+//! # Example
+//!
+//! A `button_theme.ron` file:
+//!
+//! ```ron
+//! (
+//!     hover_color: "#64c8ff",
+//!     normal_color: "#808080",
+//!     text_color: "#ffffff",
+//!     rounding: 6.0,
+//!     margin: (12.0, 6.0),
+//! )
+//! ```
+//!
+//! ```rust,no_run
+//! use egui_mobius_widgets::StyledButton;
+//!
+//! let button = StyledButton::from_style_file("Save", "button_theme.ron")
+//!     .expect("valid button theme");
+//! ```
+
+use crate::MobiusTheme;
+use egui::{Color32, Vec2};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Button styling that can be loaded from a RON or YAML document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ButtonTheme {
+    /// Border color when hovered (`StyledButton`).
+    #[serde(with = "hex_color", skip_serializing_if = "Option::is_none")]
+    pub hover_color: Option<Color32>,
+    /// Border color in the normal state (`StyledButton`).
+    #[serde(with = "hex_color", skip_serializing_if = "Option::is_none")]
+    pub normal_color: Option<Color32>,
+    /// Text color (`StyledButton`).
+    #[serde(with = "hex_color", skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<Color32>,
+    /// Color of the RUN state (`StatefulButton`).
+    #[serde(with = "hex_color", skip_serializing_if = "Option::is_none")]
+    pub run_color: Option<Color32>,
+    /// Color of the STOP state (`StatefulButton`).
+    #[serde(with = "hex_color", skip_serializing_if = "Option::is_none")]
+    pub stop_color: Option<Color32>,
+    /// Corner radius in pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rounding: Option<f32>,
+    /// Horizontal and vertical margin.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin: Option<(f32, f32)>,
+    /// Minimum width and height.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_size: Option<(f32, f32)>,
+}
+
+impl ButtonTheme {
+    /// Loads a theme from a file, picking the format from its extension
+    /// (`.ron`, `.yaml` or `.yml`).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the theme file
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed theme, or a `ThemeError` if the file cannot be read or parsed
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        load_file(path.as_ref())
+    }
+
+    /// Parses a theme from a RON document. Optional fields may be written
+    /// without a `Some(..)` wrapper.
+    pub fn from_ron_str(text: &str) -> Result<Self, ThemeError> {
+        parse_ron(text)
+    }
+
+    /// Parses a theme from a YAML document.
+    pub fn from_yaml_str(text: &str) -> Result<Self, ThemeError> {
+        parse_yaml(text)
+    }
+
+    pub(crate) fn margin_vec(&self) -> Option<Vec2> {
+        self.margin.map(|(x, y)| Vec2::new(x, y))
+    }
+
+    pub(crate) fn min_size_vec(&self) -> Option<Vec2> {
+        self.min_size.map(|(x, y)| Vec2::new(x, y))
+    }
+}
+
+impl MobiusTheme {
+    /// Loads a theme from a file, picking the format from its extension
+    /// (`.ron`, `.yaml` or `.yml`). Fields left out keep their default.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the theme file
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed theme, or a `ThemeError` if the file cannot be read or parsed
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        load_file(path.as_ref())
+    }
+
+    /// Parses a theme from a RON document.
+    pub fn from_ron_str(text: &str) -> Result<Self, ThemeError> {
+        parse_ron(text)
+    }
+
+    /// Parses a theme from a YAML document.
+    pub fn from_yaml_str(text: &str) -> Result<Self, ThemeError> {
+        parse_yaml(text)
+    }
+}
+
+/// Reads and parses any theme type, picking the format from the file extension.
+pub(crate) fn load_file<T: DeserializeOwned>(path: &Path) -> Result<T, ThemeError> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+    let parse: fn(&str) -> Result<T, ThemeError> = match extension.as_deref() {
+        Some("ron") => parse_ron,
+        Some("yaml" | "yml") => parse_yaml,
+        _ => return Err(ThemeError::UnsupportedFormat(path.to_path_buf())),
+    };
+    let text = std::fs::read_to_string(path).map_err(ThemeError::Io)?;
+    parse(&text)
+}
+
+fn parse_ron<T: DeserializeOwned>(text: &str) -> Result<T, ThemeError> {
+    ron::Options::default()
+        .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+        .from_str(text)
+        .map_err(|e| ThemeError::Parse(e.to_string()))
+}
+
+fn parse_yaml<T: DeserializeOwned>(text: &str) -> Result<T, ThemeError> {
+    serde_yaml::from_str(text).map_err(|e| ThemeError::Parse(e.to_string()))
+}
+
+/// Errors raised while loading a `ButtonTheme` or `MobiusTheme`.
+#[derive(Debug)]
+pub enum ThemeError {
+    /// The theme file could not be read.
+    Io(std::io::Error),
+    /// The document is not a valid theme.
+    Parse(String),
+    /// The file extension is neither RON nor YAML.
+    UnsupportedFormat(PathBuf),
+    /// The theme file could not be watched for changes.
+    #[cfg(feature = "theme-watch")]
+    Watch(notify::Error),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Io(e) => write!(f, "failed to read theme file: {e}"),
+            ThemeError::Parse(e) => write!(f, "invalid theme: {e}"),
+            ThemeError::UnsupportedFormat(path) => write!(
+                f,
+                "unsupported theme format for {}, expected .ron, .yaml or .yml",
+                path.display()
+            ),
+            #[cfg(feature = "theme-watch")]
+            ThemeError::Watch(e) => write!(f, "failed to watch theme file: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+/// Serde helpers storing `Option<Color32>` as a hex string.
+mod hex_color {
+    use egui::Color32;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(color: &Option<Color32>, s: S) -> Result<S::Ok, S::Error> {
+        match color {
+            Some(color) => s.serialize_some(&color.to_hex()),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Color32>, D::Error> {
+        Option::<String>::deserialize(d)?
+            .map(|hex| {
+                Color32::from_hex(&hex)
+                    .map_err(|e| D::Error::custom(format!("invalid hex color {hex:?}: {e:?}")))
+            })
+            .transpose()
+    }
+}
+
+/// Serde helpers storing a `Color32` as a hex string, for `MobiusTheme`.
+pub(crate) mod hex {
+    use egui::Color32;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(color: &Color32, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&color.to_hex())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Color32, D::Error> {
+        let hex = String::deserialize(d)?;
+        Color32::from_hex(&hex)
+            .map_err(|e| D::Error::custom(format!("invalid hex color {hex:?}: {e:?}")))
+    }
+}
+
+/// Serde helpers storing a `Vec2` as an `(x, y)` tuple, for `MobiusTheme`.
+pub(crate) mod vec2 {
+    use egui::Vec2;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(v: &Vec2, s: S) -> Result<S::Ok, S::Error> {
+        (v.x, v.y).serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec2, D::Error> {
+        let (x, y) = <(f32, f32)>::deserialize(d)?;
+        Ok(Vec2::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_from_ron_hex_colors() {
+        let theme = ButtonTheme::from_ron_str(
+            r##"(hover_color: "#ff0000", text_color: "#00ff0080", margin: (12.0, 6.0))"##,
+        )
+        .unwrap();
+
+        assert_eq!(theme.hover_color, Some(Color32::RED));
+        assert_eq!(
+            theme.text_color,
+            Some(Color32::from_rgba_unmultiplied(0, 255, 0, 128))
+        );
+        assert_eq!(theme.margin_vec(), Some(Vec2::new(12.0, 6.0)));
+        assert_eq!(theme.normal_color, None);
+    }
+
+    #[test]
+    fn test_theme_from_yaml_and_errors() {
+        let theme = ButtonTheme::from_yaml_str("run_color: '#00ff00'\nrounding: 3.5\n").unwrap();
+        assert_eq!(theme.run_color, Some(Color32::GREEN));
+        assert_eq!(theme.rounding, Some(3.5));
+
+        assert!(ButtonTheme::from_ron_str(r#"(hover_color: "red")"#).is_err());
+        assert!(matches!(
+            ButtonTheme::from_file("theme.toml"),
+            Err(ThemeError::UnsupportedFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_mobius_theme_from_ron_and_yaml() {
+        let theme = MobiusTheme::from_ron_str(
+            r##"(accent_color: "#ff0000", rounding: 2.0, margin: (8.0, 4.0))"##,
+        )
+        .unwrap();
+        assert_eq!(theme.accent_color, Color32::RED);
+        assert_eq!(theme.rounding, 2.0);
+        assert_eq!(theme.margin, Vec2::new(8.0, 4.0));
+        // Fields left out keep the default look
+        assert_eq!(theme.border_color, MobiusTheme::default().border_color);
+
+        let theme = MobiusTheme::from_yaml_str("run_color: '#00ff00'\n").unwrap();
+        assert_eq!(theme.run_color, Color32::GREEN);
+        assert!(MobiusTheme::from_ron_str(r#"(text_color: "white")"#).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//! Live reloading of a `ButtonTheme` or `MobiusTheme` file while it is being edited.
+//!
+//! `ButtonTheme::watch_file` and `MobiusTheme::watch_file` load a RON or YAML theme
+//! and watch it with `notify`. Every time the file is saved with a valid theme, the
+//! new theme is sent through a `Signal`, so a designer can tune colors in the file
+//! and watch the running application restyle without a rebuild. A save that does
+//! not parse is reported on stderr and the previous theme stays in place.
+//!
+//! Requires the `theme-watch` feature.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use egui_mobius::factory::create_signal_slot;
+//! use egui_mobius::types::Value;
+//! use egui_mobius_widgets::{ButtonTheme, StyledButton};
+//!
+//! fn setup(ctx: &egui::Context) -> (Value<ButtonTheme>, egui_mobius_widgets::ThemeWatcher) {
+//!     let theme = Value::new(ButtonTheme::default());
+//!     let (signal, mut slot) = create_signal_slot::<ButtonTheme>();
+//!     let (current, ctx) = (theme.clone(), ctx.clone());
+//!     slot.start(move |reloaded| {
+//!         *current.lock().unwrap() = reloaded;
+//!         ctx.request_repaint(); // restyle on the next frame
+//!     })
+//!     .unwrap();
+//!
+//!     // Keep the watcher alive for as long as the file should be followed
+//!     let watcher = ButtonTheme::watch_file("button_theme.ron", signal).expect("theme file");
+//!     (theme, watcher)
+//! }
+//!
+//! fn toolbar(ui: &mut egui::Ui, theme: &Value<ButtonTheme>) {
+//!     let theme = theme.lock().unwrap().clone();
+//!     StyledButton::new("Save").theme(&theme).show(ui);
+//! }
+//! ```
+
+use crate::MobiusTheme;
+use crate::theme::{self, ButtonTheme, ThemeError};
+use egui_mobius::Signal;
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+
+/// Keeps a theme file watched; dropping it stops the reloading.
+pub struct ThemeWatcher {
+    _watcher: notify::RecommendedWatcher,
+    path: PathBuf,
+}
+
+impl ThemeWatcher {
+    /// The watched theme file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl ButtonTheme {
+    /// Loads the theme at `path`, sends it through `signal`, and sends it again
+    /// each time the file is saved with a changed, valid theme.
+    ///
+    /// The directory containing the file is watched rather than the file itself,
+    /// so editors that save by replacing the file are followed as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to a `.ron`, `.yaml` or `.yml` theme file
+    /// * `signal` - Receives the initial theme and every reloaded one
+    ///
+    /// # Returns
+    ///
+    /// Returns the watcher, or a `ThemeError` if the file cannot be loaded initially
+    /// or watched
+    pub fn watch_file(
+        path: impl AsRef<Path>,
+        signal: Signal<ButtonTheme>,
+    ) -> Result<ThemeWatcher, ThemeError> {
+        watch_theme_file(path.as_ref(), signal)
+    }
+}
+
+impl MobiusTheme {
+    /// Loads the theme at `path`, sends it through `signal`, and sends it again
+    /// each time the file is saved with a changed, valid theme.
+    ///
+    /// Works like `ButtonTheme::watch_file`, for the theme shared by every widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to a `.ron`, `.yaml` or `.yml` theme file
+    /// * `signal` - Receives the initial theme and every reloaded one
+    ///
+    /// # Returns
+    ///
+    /// Returns the watcher, or a `ThemeError` if the file cannot be loaded initially
+    /// or watched
+    pub fn watch_file(
+        path: impl AsRef<Path>,
+        signal: Signal<MobiusTheme>,
+    ) -> Result<ThemeWatcher, ThemeError> {
+        watch_theme_file(path.as_ref(), signal)
+    }
+}
+
+/// The `watch_file` of every theme type.
+fn watch_theme_file<T>(path: &Path, signal: Signal<T>) -> Result<ThemeWatcher, ThemeError>
+where
+    T: DeserializeOwned + PartialEq + Clone + Send + 'static,
+{
+    let path = path.to_path_buf();
+    let mut current: T = theme::load_file(&path)?;
+    let _ = signal.send(current.clone());
+
+    let file_name = path.file_name().map(ToOwned::to_owned);
+    let reload_path = path.clone();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        let touched = matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+            && event
+                .paths
+                .iter()
+                .any(|changed| changed.file_name() == file_name.as_deref());
+        // Editors often truncate the file before writing it, and an empty
+        // document would load as a default theme
+        let empty = std::fs::metadata(&reload_path).is_ok_and(|meta| meta.len() == 0);
+        if !touched || empty {
+            return;
+        }
+        match theme::load_file::<T>(&reload_path) {
+            // One save often raises several events; only real changes are sent
+            Ok(theme) if theme != current => {
+                current = theme.clone();
+                let _ = signal.send(theme);
+            }
+            Ok(_) => {}
+            // Also hit while an editor is halfway through writing the file
+            Err(e) => eprintln!("Keeping the previous theme: {e}"),
+        }
+    })
+    .map_err(ThemeError::Watch)?;
+
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    watcher
+        .watch(directory, RecursiveMode::NonRecursive)
+        .map_err(ThemeError::Watch)?;
+
+    Ok(ThemeWatcher {
+        _watcher: watcher,
+        path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::Color32;
+    use egui_mobius::factory::create_signal_slot;
+    use std::time::Duration;
+
+    #[test]
+    fn test_saving_the_file_sends_the_new_theme() {
+        let directory = std::env::temp_dir().join(format!("theme-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("buttons.ron");
+        std::fs::write(&path, r##"(hover_color: "#ff0000")"##).unwrap();
+
+        let (signal, slot) = create_signal_slot::<ButtonTheme>();
+        let watcher = ButtonTheme::watch_file(&path, signal).unwrap();
+        assert_eq!(watcher.path(), path);
+        let receiver = slot.receiver.lock().unwrap();
+        let initial = receiver.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(initial.hover_color, Some(Color32::RED));
+
+        std::fs::write(&path, r##"(hover_color: "#00ff00", rounding: 2.0)"##).unwrap();
+        let reloaded = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(reloaded.hover_color, Some(Color32::GREEN));
+        assert_eq!(reloaded.rounding, Some(2.0));
+
+        drop(watcher);
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn test_saving_a_mobius_theme_sends_the_new_theme() {
+        let directory =
+            std::env::temp_dir().join(format!("mobius-theme-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("theme.yaml");
+        std::fs::write(&path, "accent_color: '#ff0000'\n").unwrap();
+
+        let (signal, slot) = create_signal_slot::<MobiusTheme>();
+        let watcher = MobiusTheme::watch_file(&path, signal).unwrap();
+        let receiver = slot.receiver.lock().unwrap();
+        let initial = receiver.recv_timeout(Duration::from_secs(1)).unwrap();
+        assert_eq!(initial.accent_color, Color32::RED);
+
+        std::fs::write(&path, "accent_color: '#00ff00'\n").unwrap();
+        let reloaded = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(reloaded.accent_color, Color32::GREEN);
+        assert_eq!(reloaded.rounding, MobiusTheme::default().rounding);
+
+        drop(watcher);
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
@@ -0,0 +1,332 @@
+//! Transient notification toasts delivered over a `Signal<Toast>`.
+//!
+//! The `ToastHost` owns the receiving `Slot<Toast>` and renders incoming toasts as a
+//! stack of cards in the top-right corner of the window:
+//! - Newest toast on top
+//! - Automatic dismissal once a toast's `ttl` has elapsed, with a fade-out
+//! - Manual dismissal by clicking a card
+//!
+//! Producers anywhere in the application (including background threads) only need a
+//! clone of the `Signal<Toast>`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use egui_mobius_widgets::{Toast, ToastHost};
+//! use std::time::Duration;
+//!
+//! let (toast_signal, mut toasts) = ToastHost::create();
+//!
+//! // e.g. from a background fetch
+//! toast_signal.send(Toast::error("Kraken API unreachable")).unwrap();
+//! toast_signal
+//!     .send(Toast::info("Prices refreshed").with_ttl(Duration::from_secs(2)))
+//!     .unwrap();
+//!
+//! fn ui(ctx: &egui::Context, toasts: &mut ToastHost) {
+//!     toasts.show(ctx);
+//! }
+//! ```
+
+use egui::{Align2, Color32, Context, CornerRadius, Id, Sense, Stroke, Vec2};
+use egui_mobius::factory::create_signal_slot;
+use egui_mobius::{Signal, Slot};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Severity of a toast, selecting its accent color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl ToastLevel {
+    fn color(self) -> Color32 {
+        match self {
+            ToastLevel::Info => Color32::from_rgb(100, 200, 255),
+            ToastLevel::Warn => Color32::from_rgb(255, 190, 0),
+            ToastLevel::Error => Color32::from_rgb(255, 80, 80),
+        }
+    }
+}
+
+/// A notification sent to a `ToastHost`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub level: ToastLevel,
+    pub text: String,
+    /// How long the toast stays visible, including its fade-out.
+    pub ttl: Duration,
+}
+
+impl Toast {
+    /// Default time a toast stays on screen.
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(4);
+
+    /// Creates a toast with the given level, text and the default ttl.
+    pub fn new(level: ToastLevel, text: impl Into<String>) -> Self {
+        Self {
+            level,
+            text: text.into(),
+            ttl: Self::DEFAULT_TTL,
+        }
+    }
+
+    /// Creates an informational toast.
+    pub fn info(text: impl Into<String>) -> Self {
+        Self::new(ToastLevel::Info, text)
+    }
+
+    /// Creates a warning toast.
+    pub fn warn(text: impl Into<String>) -> Self {
+        Self::new(ToastLevel::Warn, text)
+    }
+
+    /// Creates an error toast.
+    pub fn error(text: impl Into<String>) -> Self {
+        Self::new(ToastLevel::Error, text)
+    }
+
+    /// Sets how long the toast stays visible.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+}
+
+/// A toast on screen, with the egui time (in seconds) at which it appeared.
+#[derive(Debug)]
+struct ActiveToast {
+    toast: Toast,
+    shown_at: f64,
+}
+
+/// Renders a stack of toasts received on its `Slot<Toast>`.
+pub struct ToastHost {
+    // Kept so the slot's worker thread and channel stay tied to the host.
+    _slot: Slot<Toast>,
+    incoming: Arc<Mutex<Vec<Toast>>>,
+    repaint: Arc<Mutex<Option<Context>>>,
+    /// Newest first.
+    active: Vec<ActiveToast>,
+    fade: Duration,
+    width: f32,
+}
+
+impl ToastHost {
+    /// Creates a host receiving toasts on `slot`.
+    ///
+    /// The slot is started immediately; toasts sent before the first `show`
+    /// appear on the next frame.
+    pub fn new(mut slot: Slot<Toast>) -> Self {
+        let incoming = Arc::new(Mutex::new(Vec::new()));
+        let repaint: Arc<Mutex<Option<Context>>> = Arc::new(Mutex::new(None));
+
+        let incoming_clone = incoming.clone();
+        let repaint_clone = repaint.clone();
+        if let Err(e) = slot.start(move |toast| {
+            incoming_clone.lock().unwrap().push(toast);
+            if let Some(ctx) = repaint_clone.lock().unwrap().as_ref() {
+                ctx.request_repaint();
+            }
+        }) {
+            eprintln!("ToastHost could not start its slot: {e}");
+        }
+
+        Self {
+            _slot: slot,
+            incoming,
+            repaint,
+            active: Vec::new(),
+            fade: Duration::from_millis(500),
+            width: 260.0,
+        }
+    }
+
+    /// Creates a connected `Signal<Toast>` and `ToastHost` pair.
+    pub fn create() -> (Signal<Toast>, Self) {
+        let (signal, slot) = create_signal_slot::<Toast>();
+        (signal, Self::new(slot))
+    }
+
+    /// Sets the length of the fade-out at the end of each toast's ttl.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn fade_duration(mut self, fade: Duration) -> Self {
+        self.fade = fade;
+        self
+    }
+
+    /// Sets the width of the toast cards.
+    ///
+    /// # Returns
+    ///
+    /// Returns self for method chaining
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Number of toasts currently on screen.
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    /// Returns `true` when no toast is on screen.
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// Removes all toasts from the screen.
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    /// Shows the toast stack as an overlay in the top-right corner.
+    ///
+    /// Call once per frame. Clicking a card dismisses it.
+    pub fn show(&mut self, ctx: &Context) {
+        self.repaint
+            .lock()
+            .unwrap()
+            .get_or_insert_with(|| ctx.clone());
+
+        let now = ctx.input(|i| i.time);
+        self.update(now);
+        if self.active.is_empty() {
+            return;
+        }
+
+        let mut dismissed = None;
+        egui::Area::new(Id::new("egui_mobius_toast_host"))
+            .anchor(Align2::RIGHT_TOP, Vec2::new(-10.0, 10.0))
+            .interactable(true)
+            .show(ctx, |ui| {
+                ui.set_width(self.width);
+                for (index, active) in self.active.iter().enumerate() {
+                    let alpha = self.alpha(active, now);
+                    let accent = active.toast.level.color().gamma_multiply(alpha);
+                    let response = egui::Frame::new()
+                        .fill(Color32::from_black_alpha(220).gamma_multiply(alpha))
+                        .stroke(Stroke::new(1.0, accent))
+                        .corner_radius(CornerRadius::from(5.0))
+                        .inner_margin(Vec2::new(10.0, 6.0))
+                        .show(ui, |ui| {
+                            ui.set_width(self.width - 20.0);
+                            ui.colored_label(
+                                Color32::WHITE.gamma_multiply(alpha),
+                                &active.toast.text,
+                            );
+                        })
+                        .response;
+                    if ui
+                        .interact(response.rect, response.id.with("dismiss"), Sense::click())
+                        .clicked()
+                    {
+                        dismissed = Some(index);
+                    }
+                }
+            });
+
+        if let Some(index) = dismissed {
+            self.active.remove(index);
+        }
+
+        // Keep animating while a toast is fading, otherwise wake for the next fade
+        let next_wake = self
+            .active
+            .iter()
+            .map(|active| (self.fade_start(active) - now).max(0.0))
+            .fold(f64::INFINITY, f64::min);
+        if next_wake.is_finite() {
+            ctx.request_repaint_after(Duration::from_secs_f64(next_wake));
+        }
+    }
+
+    /// Moves newly received toasts on screen and drops expired ones.
+    fn update(&mut self, now: f64) {
+        for toast in self.incoming.lock().unwrap().drain(..) {
+            self.active.insert(
+                0,
+                ActiveToast {
+                    toast,
+                    shown_at: now,
+                },
+            );
+        }
+        self.active
+            .retain(|active| now - active.shown_at < active.toast.ttl.as_secs_f64());
+    }
+
+    /// Time at which a toast starts fading out.
+    fn fade_start(&self, active: &ActiveToast) -> f64 {
+        let visible = active.toast.ttl.saturating_sub(self.fade);
+        active.shown_at + visible.as_secs_f64()
+    }
+
+    /// Opacity of a toast, ramping from 1 to 0 over the fade-out.
+    fn alpha(&self, active: &ActiveToast, now: f64) -> f32 {
+        let fade = self.fade.as_secs_f64();
+        if fade <= 0.0 {
+            return 1.0;
+        }
+        let faded = (now - self.fade_start(active)) / fade;
+        (1.0 - faded).clamp(0.0, 1.0) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wait_for_slot() {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_toasts_stack_newest_first_and_expire() {
+        let (signal, mut host) = ToastHost::create();
+        signal.send(Toast::info("first")).unwrap();
+        signal
+            .send(Toast::error("second").with_ttl(Duration::from_secs(1)))
+            .unwrap();
+        wait_for_slot();
+
+        host.update(10.0);
+        let texts: Vec<_> = host.active.iter().map(|a| a.toast.text.as_str()).collect();
+        assert_eq!(texts, ["second", "first"]);
+
+        // The error toast expires after its 1s ttl, the info toast stays
+        host.update(11.5);
+        assert_eq!(host.len(), 1);
+        assert_eq!(host.active[0].toast.text, "first");
+
+        host.update(14.0);
+        assert!(host.is_empty());
+    }
+
+    #[test]
+    fn test_toast_fades_out_at_end_of_ttl() {
+        let (signal, mut host) = ToastHost::create();
+        let host_fade = Duration::from_millis(500);
+        host = host.fade_duration(host_fade);
+        signal
+            .send(Toast::warn("fading").with_ttl(Duration::from_secs(2)))
+            .unwrap();
+        wait_for_slot();
+
+        host.update(0.0);
+        let active = &host.active[0];
+        assert_eq!(host.alpha(active, 1.0), 1.0);
+        assert!((host.alpha(active, 1.75) - 0.5).abs() < 1e-6);
+        assert_eq!(host.alpha(active, 2.0), 0.0);
+    }
+}
@@ -51,7 +51,8 @@ pub fn wire_backend() -> (Signal<WorkRequest>, Slot<WorkResponse>, BackendHandle
         work_slot,
         result_signal,
         |req| async move { work(req).await },
-    );
+    )
+    .expect("the work slot is attached only once");
 
     (
         work_signal,
@@ -71,18 +71,20 @@ impl App {
         let in_flight = state.in_flight.clone();
         let log = state.log.clone();
         let ctx = cc.egui_ctx.clone();
-        result_slot.start(move |resp| {
-            last_result.set(resp.value);
-            in_flight.set(false);
-            dispatcher::append_log(
-                &log,
-                format!(
-                    "[backend] result: value={:.4} elapsed_ms={}",
-                    resp.value, resp.elapsed_ms,
-                ),
-            );
-            ctx.request_repaint();
-        });
+        result_slot
+            .start(move |resp| {
+                last_result.set(resp.value);
+                in_flight.set(false);
+                dispatcher::append_log(
+                    &log,
+                    format!(
+                        "[backend] result: value={:.4} elapsed_ms={}",
+                        resp.value, resp.elapsed_ms,
+                    ),
+                );
+                ctx.request_repaint();
+            })
+            .unwrap();
 
         dispatcher::append_log(&state.log, "[INFO] citizen_signal_async started".into());
 
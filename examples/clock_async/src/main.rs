@@ -1,7 +1,6 @@
 use eframe::egui;
 use egui_mobius::dispatching::AsyncDispatcher;
 use egui_mobius::factory;
-use egui_mobius::signals::Signal;
 use egui_mobius::slot::Slot;
 use egui_taffy::TuiBuilderLogic;
 use egui_taffy::{taffy, tui};
@@ -26,17 +25,19 @@ impl UiApp {
         let combo_ref = state.combo_value.clone();
         let repaint = state.repaint.clone();
 
-        response_slot.start(move |response| {
-            match response {
-                Response::SliderProcessed(val) => {
-                    *slider_ref.lock().unwrap() = val;
-                }
-                Response::ComboProcessed(choice) => {
-                    *combo_ref.lock().unwrap() = choice;
+        response_slot
+            .start(move |response| {
+                match response {
+                    Response::SliderProcessed(val) => {
+                        *slider_ref.lock().unwrap() = val;
+                    }
+                    Response::ComboProcessed(choice) => {
+                        *combo_ref.lock().unwrap() = choice;
+                    }
                 }
-            }
-            repaint.request_repaint();
-        });
+                repaint.request_repaint();
+            })
+            .unwrap();
 
         Self { state }
     }
@@ -104,20 +105,6 @@ impl eframe::App for UiApp {
     }
 }
 
-fn background_generator_thread(clock_signal: Signal<ClockMessage>, _ctx: egui::Context) {
-    std::thread::spawn(move || {
-        loop {
-            let now = chrono::Local::now();
-            if let Err(e) = clock_signal.send(ClockMessage::TimeUpdated(
-                now.format("%H:%M:%S").to_string(),
-            )) {
-                eprintln!("Failed to send TimeUpdated message: {e:?}");
-            }
-            std::thread::sleep(Duration::from_secs(1));
-        }
-    });
-}
-
 fn main() {
     let config = {
         let local_dir = std::path::Path::new(".local");
@@ -171,12 +158,39 @@ fn main() {
                 }
             }
         },
-    );
+    )
+    .expect("the event slot is attached only once");
+
+    // Save config changes in the background, on a runtime of our own
+    let runtime = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
+    let (save_signal, mut save_slot) = factory::create_signal_slot::<Config>();
+    save_slot
+        .start_async_on(runtime.handle(), |config: Config| async move {
+            let Ok(json_data) = serde_json::to_string_pretty(&config) else {
+                return;
+            };
+            let local_dir = std::path::Path::new(".local");
+            let _ = tokio::fs::create_dir_all(local_dir).await;
+            if let Err(e) = tokio::fs::write(local_dir.join("config.json"), json_data).await {
+                eprintln!("Failed to save config: {e}");
+            }
+        })
+        .unwrap();
 
     // Set up clock updates
     let (clock_signal, clock_slot) = factory::create_signal_slot::<ClockMessage>();
     let now = chrono::Local::now().format("%H:%M:%S").to_string();
     let _ = clock_signal.send(ClockMessage::TimeUpdated(now));
+    let (_tick_signal, mut tick_slot, _clock_timer) =
+        factory::create_interval_signal(Duration::from_secs(1));
+    tick_slot
+        .start(move |_| {
+            let now = chrono::Local::now().format("%H:%M:%S").to_string();
+            if let Err(e) = clock_signal.send(ClockMessage::TimeUpdated(now)) {
+                eprintln!("Failed to send TimeUpdated message: {e:?}");
+            }
+        })
+        .unwrap();
 
     let native_options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
@@ -192,13 +206,11 @@ fn main() {
         Box::new(move |cc| {
             let ctx = cc.egui_ctx.clone();
 
-            // Start clock updates with UI context
-            background_generator_thread(clock_signal, ctx.clone());
-
             // Create app state
             let app_state = AppState::new(ctx.clone(), config.clone());
             app_state.set_clock_slot(clock_slot);
             app_state.set_event_signal(event_signal.clone());
+            app_state.set_save_signal(save_signal.clone());
 
             Ok(Box::new(UiApp::new(app_state, response_slot)))
         }),
@@ -20,6 +20,7 @@ pub struct AppState {
     pub button_colors: Value<ButtonColors>,
     pub button_started: Value<bool>,
     pub use_24h: Value<bool>,
+    pub save_signal: Value<Option<Signal<Config>>>,
 }
 
 impl AppState {
@@ -37,6 +38,7 @@ impl AppState {
             button_colors: Value::new(config.button_colors),
             button_started: Value::new(false),
             use_24h: Value::new(config.time_format == "24h"),
+            save_signal: Value::new(None),
         }
     }
 
@@ -44,6 +46,10 @@ impl AppState {
         *self.event_signal.lock().unwrap() = Some(signal);
     }
 
+    pub fn set_save_signal(&self, signal: Signal<Config>) {
+        *self.save_signal.lock().unwrap() = Some(signal);
+    }
+
     pub fn set_clock_slot(&self, mut slot: Slot<ClockMessage>) {
         let ctx = self.repaint.clone();
         let current_time = self.current_time.clone();
@@ -73,7 +79,8 @@ impl AppState {
                 color: Some(egui::Color32::from_rgb(100, 200, 255)), // Light Blue
             });
             ctx.request_repaint();
-        });
+        })
+        .unwrap();
     }
 
     pub fn save_config(&self) {
@@ -90,19 +97,10 @@ impl AppState {
             button_colors: self.button_colors.lock().unwrap().clone(),
         };
 
-        // Move config saving to background thread to avoid blocking UI
-        std::thread::spawn(move || {
-            if let Ok(json_data) = serde_json::to_string_pretty(&config) {
-                let local_dir = std::path::Path::new(".local");
-                if !local_dir.exists() {
-                    let _ = std::fs::create_dir_all(local_dir);
-                }
-                let config_path = local_dir.join("config.json");
-                if let Err(e) = std::fs::write(&config_path, json_data) {
-                    eprintln!("Failed to save config: {e}");
-                }
-            }
-        });
+        // Config is written by the async save slot to avoid blocking UI
+        if let Some(signal) = self.save_signal.lock().unwrap().as_ref() {
+            let _ = signal.send(config);
+        }
     }
 
     pub fn log(&self, source: &str, message: String) {
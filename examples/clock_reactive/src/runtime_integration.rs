@@ -9,8 +9,9 @@ use crate::types::LogEntry;
 use crate::{state::AppState, types::ClockMessage};
 use chrono::Local;
 use eframe::egui;
-use egui_mobius::{EventRoute, MobiusHandle, MobiusRuntime};
+use egui_mobius::{factory, EventRoute, MobiusHandle, MobiusRuntime, TimerHandle};
 use std::sync::{mpsc, Arc};
+use std::time::Duration;
 use tokio::sync::Notify;
 
 impl EventRoute for ClockMessage {
@@ -27,6 +28,7 @@ impl EventRoute for ClockMessage {
 pub struct RuntimeManager {
     runtime: Option<tokio::task::JoinHandle<()>>,
     handle: Option<Arc<MobiusHandle<ClockMessage>>>,
+    clock_timer: Option<TimerHandle>,
     shutdown: Arc<Notify>,
     state: Arc<AppState>,
 }
@@ -36,6 +38,7 @@ impl RuntimeManager {
         Self {
             runtime: None,
             handle: None,
+            clock_timer: None,
             shutdown: Arc::new(Notify::new()),
             state,
         }
@@ -51,16 +54,17 @@ impl RuntimeManager {
         let _handle_clone = handle.clone();
         let shutdown = self.shutdown.clone();
 
-        // Start clock updates in a separate tokio task
+        // Start clock updates on an interval timer ticking every second
         // Since the clock is updated here, format the time for the UI
         // and then the reactive state management will take care of the
         // rest.
         let current_time = self.state.current_time.clone().to_owned(); // Create owned Dynamic
         let use_24h = self.state.use_24h.clone().to_owned(); // Create owned Dynamic
         let logs = self.state.logs.clone().to_owned(); // Create owned Dynamic
-        tokio::spawn(async move {
-            loop {
-                tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        let (_tick_signal, mut tick_slot, clock_timer) =
+            factory::create_interval_signal(Duration::from_secs(1));
+        tick_slot
+            .start(move |_| {
                 let now = chrono::Local::now();
                 let time_str = if use_24h.get() {
                     now.format("%H:%M:%S").to_string()
@@ -83,8 +87,9 @@ impl RuntimeManager {
                     color: Some(egui::Color32::from_rgb(100, 200, 255)), // Light Blue
                 });
                 logs.set(current_logs);
-            }
-        });
+            })
+            .expect("Failed to start clock updates");
+        self.clock_timer = Some(clock_timer);
 
         // The code below registers the message handlers for the runtime,
         // and there is a placeholder for this messages in state.rs
@@ -127,7 +132,9 @@ impl RuntimeManager {
 
         // Start runtime
         let rt = tokio::spawn(async move {
-            runtime.run().await;
+            if let Err(e) = runtime.run().await {
+                eprintln!("Runtime stopped: {e}");
+            }
         });
 
         // Optional Control - Start background clock (presently not used)
@@ -148,6 +155,8 @@ impl RuntimeManager {
     }
 
     pub fn stop(&mut self) {
+        // Dropping the timer ends the clock updates
+        self.clock_timer = None;
         if let Some(handle) = self.handle.take() {
             handle.shutdown();
         }
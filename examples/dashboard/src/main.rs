@@ -3,6 +3,7 @@ use egui_mobius::dispatching::{Dispatcher, SignalDispatcher};
 use egui_mobius::factory;
 use egui_mobius::signals::Signal;
 use egui_mobius::slot::Slot;
+use egui_mobius::topology::TopologyRegistry;
 use egui_mobius::types::Value;
 
 use chrono::{DateTime, Local};
@@ -40,16 +41,23 @@ impl Default for Response {
 /// - Sending `Response` values back to the UI thread
 pub fn run_backend(mut event_slot: Slot<Event>, response_signal: Signal<Response>) {
     // spin up a slot to handle the events
-    event_slot.start(move |event| {
-        let response = process(event);
-        if let Err(e) = response_signal.send(response) {
-            eprintln!("Failed to send response: {e:?}");
-        }
-    });
+    event_slot
+        .start(move |event| {
+            let response = process(event);
+            if let Err(e) = response_signal.send(response) {
+                eprintln!("Failed to send response: {e:?}");
+            }
+        })
+        .unwrap();
 }
 
 fn main() {
-    let (response_signal, response_slot) = factory::create_signal_slot::<Response>();
+    let topology = TopologyRegistry::new();
+    let (response_signal, response_slot) = factory::create_named_signal_slot::<Response>(
+        &topology,
+        "responses",
+        "Backend responses shown in the dashboard log",
+    );
 
     let dispatcher = Dispatcher::<Event>::new();
 
@@ -107,9 +115,11 @@ impl UiApp {
 
         // Initialize the response listener exactly once
         let state_clone = state.clone();
-        response_slot.start(move |response| {
-            state_clone.lock().unwrap().handle_response(response);
-        });
+        response_slot
+            .start(move |response| {
+                state_clone.lock().unwrap().handle_response(response);
+            })
+            .unwrap();
 
         Self { state, dispatcher }
     }
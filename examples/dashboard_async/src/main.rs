@@ -21,6 +21,11 @@ use egui_mobius::factory;
 use egui_mobius::signals::*;
 use egui_mobius::slot::*;
 use egui_mobius::types::*;
+use egui_mobius_widgets::DeferSignal;
+use std::time::Duration;
+
+/// How long a price request may take before the UI reports a timeout.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[derive(Clone)]
 pub enum Event {
@@ -38,6 +43,7 @@ pub enum Processed {
     SolanaPrice(f64),
     StellarPrice(f64),
     SuiPrice(f64),
+    TimedOut,
 }
 
 /// Updatable Trait
@@ -121,6 +127,10 @@ impl Updatable<Processed> for AppState {
                     self.error_message = Some("Failed to retrieve a valid SUI price.".to_string());
                 }
             }
+            Processed::TimedOut => {
+                self.loading_coin = None;
+                self.error_message = Some("Price request timed out.".to_string());
+            }
         }
     }
 }
@@ -196,9 +206,11 @@ impl UiMainWindow {
         let state = Value::new(AppState::new(event_signal.clone()));
 
         let state_clone = state.clone();
-        response_slot.start(move |response| {
-            state_clone.lock().unwrap().update(response);
-        });
+        response_slot
+            .start(move |response| {
+                state_clone.lock().unwrap().update(response);
+            })
+            .unwrap();
 
         Self {
             state,
@@ -220,14 +232,14 @@ impl eframe::App for UiMainWindow {
             if ui.button("Fetch Bitcoin Price").clicked() {
                 let mut state = self.state.lock().unwrap();
                 state.loading_coin = Some("BTC".to_string());
-                let _ = self.event_signal.send(Event::FetchBitcoin);
+                ui.defer_signal(&self.event_signal, Event::FetchBitcoin);
             }
             ui.add_space(10.0);
 
             if ui.button("Fetch Kaspa Price").clicked() {
                 let mut state = self.state.lock().unwrap();
                 state.loading_coin = Some("KAS".to_string());
-                let _ = self.event_signal.send(Event::FetchKaspa);
+                ui.defer_signal(&self.event_signal, Event::FetchKaspa);
             }
 
             ui.add_space(10.0);
@@ -235,7 +247,7 @@ impl eframe::App for UiMainWindow {
             if ui.button("Fetch Solana Price").clicked() {
                 let mut state = self.state.lock().unwrap();
                 state.loading_coin = Some("SOL".to_string());
-                let _ = self.event_signal.send(Event::FetchSolana);
+                ui.defer_signal(&self.event_signal, Event::FetchSolana);
             }
 
             ui.add_space(10.0);
@@ -243,7 +255,7 @@ impl eframe::App for UiMainWindow {
             if ui.button("Fetch Stellar Price").clicked() {
                 let mut state = self.state.lock().unwrap();
                 state.loading_coin = Some("XLM".to_string());
-                let _ = self.event_signal.send(Event::FetchStellar);
+                ui.defer_signal(&self.event_signal, Event::FetchStellar);
             }
 
             ui.add_space(10.0);
@@ -251,7 +263,7 @@ impl eframe::App for UiMainWindow {
             if ui.button("Fetch SUI Price").clicked() {
                 let mut state = self.state.lock().unwrap();
                 state.loading_coin = Some("SUI".to_string());
-                let _ = self.event_signal.send(Event::FetchSui);
+                ui.defer_signal(&self.event_signal, Event::FetchSui);
             }
 
             ui.separator();
@@ -302,9 +314,6 @@ impl eframe::App for UiMainWindow {
             } else if let Some(ref err) = state.error_message {
                 ui.colored_label(egui::Color32::RED, err);
             }
-
-            // TODO : add a timeout for the loading spinner, i.e. if the request takes too long
-            // then show an error message
         });
     }
 }
@@ -324,30 +333,46 @@ fn main() {
     let dispatcher = AsyncDispatcher::<Event, Processed>::new();
     let signal_to_ui = signal_to_ui.clone();
 
-    dispatcher.attach_async(slot_from_ui, signal_to_ui.clone(), |event| async move {
-        match event {
-            Event::FetchBitcoin => {
-                let price = fetch_price("BTCUSD").await;
-                Processed::BitcoinPrice(price)
-            }
-            Event::FetchKaspa => {
-                let price = fetch_price("KASUSD").await;
-                Processed::KaspaPrice(price)
-            }
-            Event::FetchSolana => {
-                let price = fetch_price("SOLUSD").await;
-                Processed::SolanaPrice(price)
-            }
-            Event::FetchStellar => {
-                let price = fetch_price("XLMUSD").await;
-                Processed::StellarPrice(price)
-            }
-            Event::FetchSui => {
-                let price = fetch_price("SUIUSD").await;
-                Processed::SuiPrice(price)
+    // Requests that take too long are reported to the UI instead of spinning forever
+    let (timeout_signal, mut timeout_slot) = factory::create_signal_slot::<Event>();
+    let timeout_to_ui = signal_to_ui.clone();
+    timeout_slot
+        .start(move |_event| {
+            let _ = timeout_to_ui.send(Processed::TimedOut);
+        })
+        .unwrap();
+
+    dispatcher.attach_async_timeout(
+        slot_from_ui,
+        signal_to_ui.clone(),
+        timeout_signal,
+        REQUEST_TIMEOUT,
+        |event| async move {
+            match event {
+                Event::FetchBitcoin => {
+                    let price = fetch_price("BTCUSD").await;
+                    Processed::BitcoinPrice(price)
+                }
+                Event::FetchKaspa => {
+                    let price = fetch_price("KASUSD").await;
+                    Processed::KaspaPrice(price)
+                }
+                Event::FetchSolana => {
+                    let price = fetch_price("SOLUSD").await;
+                    Processed::SolanaPrice(price)
+                }
+                Event::FetchStellar => {
+                    let price = fetch_price("XLMUSD").await;
+                    Processed::StellarPrice(price)
+                }
+                Event::FetchSui => {
+                    let price = fetch_price("SUIUSD").await;
+                    Processed::SuiPrice(price)
+                }
             }
-        }
-    });
+        },
+    )
+    .expect("the UI event slot is attached only once");
 
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
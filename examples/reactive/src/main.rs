@@ -1,6 +1,7 @@
 use eframe::NativeOptions;
 use egui_mobius::factory;
 use egui_mobius::signals::Signal;
+use egui_mobius::slot::Slot;
 use egui_mobius_reactive::reactive::registry::ErasedReactiveValue;
 use egui_mobius_reactive::*;
 use std::sync::Arc;
@@ -15,33 +16,37 @@ pub enum Event {
 pub struct AppState {
     pub registry: SignalRegistry,
     count: Dynamic<i32>,
+    count_text: Derived<String>,
     label: Dynamic<String>,
     doubled: Derived<i32>,
     quad: Derived<i32>,
     fifth: Derived<i32>,
     sum_derived: Derived<i32>,
+    over_ten: Derived<bool>,
     list_sum: Derived<i32>,
     list: ReactiveList<i32>,
+    clicks: Dynamic<u32>,
     signal: Signal<Event>,
+    graph_view: ReactiveGraphView,
 }
 
 impl AppState {
-    pub fn new(registry: SignalRegistry, signal: Signal<Event>) -> Self {
+    pub fn new(registry: SignalRegistry, signal: Signal<Event>, slot: Slot<Event>) -> Self {
         let count = Dynamic::new(0);
         let label = Dynamic::new("Click to increment".to_string());
-        registry.register_named_signal("count", Arc::new(count.clone()));
-        registry.register_named_signal("label", Arc::new(label.clone()));
+
+        let count_text = count.to_display(|count| count.to_string());
 
         // Use ReactiveMath
         let doubled: Derived<i32> = count.powi(2);
         let quad = count.powi(4);
-        let fifth = count.powi(5);
+        // Saturates instead of overflowing once count^5 exceeds i32::MAX
+        let fifth = count.saturating_powi(5);
         let sum_derived: Derived<i32> = count.clone() + doubled.clone();
 
-        registry.register_named_signal("doubled", Arc::new(doubled.clone()));
-        registry.register_named_signal("quad", Arc::new(quad.clone()));
-        registry.register_named_signal("fifth", Arc::new(fifth.clone()));
-        registry.register_named_signal("sum_derived", Arc::new(sum_derived.clone()));
+        // Lights the indicator once the count passes the threshold
+        let threshold = Dynamic::new(10);
+        let over_ten = count.gt(&threshold);
 
         registry.effect(&[Arc::new(sum_derived.clone())], move || {
             println!("💥 sum_derived changed");
@@ -52,31 +57,50 @@ impl AppState {
         list.push(7);
         list.push(13);
 
-        let list_arc = Arc::new(list.clone());
-        registry.register_named_signal("list", list_arc.clone());
-
         let list_clone = list.clone();
         registry.effect(
-            &[list_arc.clone() as Arc<dyn ErasedReactiveValue>],
+            &[Arc::new(list.clone()) as Arc<dyn ErasedReactiveValue>],
             move || {
                 println!("📋 list changed: {:?}", list_clone.get_all());
             },
         );
 
         let list_sum = list.clone().sum();
-        registry.register_named_signal("list_sum", Arc::new(list_sum.clone()));
+
+        reactive!(registry,
+            "count" => count,
+            "label" => label,
+            "doubled" => doubled,
+            "quad" => quad,
+            "fifth" => fifth,
+            "sum_derived" => sum_derived,
+            "list" => list,
+            "list_sum" => list_sum,
+        );
+
+        // Fold the event stream into reactive state
+        let clicks = Dynamic::from_signal(slot, 0, |clicks, event| {
+            if let Event::IncrementClicked = event {
+                *clicks += 1;
+            }
+        })
+        .expect("the event slot is started only here");
 
         Self {
             registry,
             count,
+            count_text,
             label,
             doubled,
             quad,
             fifth,
             sum_derived,
+            over_ten,
             list_sum,
             list,
+            clicks,
             signal,
+            graph_view: ReactiveGraphView::new(),
         }
     }
 }
@@ -100,6 +124,19 @@ impl eframe::App for AppState {
                                 eprintln!("Failed to send increment event: {e}");
                             }
                         }
+                        ReactiveLed::from_derived(&self.over_ten)
+                            .label("count > 10")
+                            .show(ui);
+                    });
+
+                    // Bound widgets write edits straight back into the Dynamic
+                    ui.horizontal(|ui| {
+                        ui.label("Set count:");
+                        bind_slider(ui, &self.count, 0..=20);
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Button label:");
+                        bind_text_edit(ui, &self.label);
                     });
 
                     egui::Grid::new("counter_grid")
@@ -107,7 +144,7 @@ impl eframe::App for AppState {
                         .spacing([40.0, 4.0])
                         .show(ui, |ui| {
                             ui.label("Count:");
-                            ui.label(format!("{}", self.count.get()));
+                            self.count_text.bind_label(ui);
                             ui.end_row();
 
                             ui.label("Doubled:");
@@ -125,6 +162,10 @@ impl eframe::App for AppState {
                             ui.label("Sum:");
                             ui.label(format!("{}", self.sum_derived.get()));
                             ui.end_row();
+
+                            ui.label("Clicks (from signal):");
+                            ui.label(format!("{}", self.clicks.get()));
+                            ui.end_row();
                         });
                 });
 
@@ -155,8 +196,23 @@ impl eframe::App for AppState {
                         .fill(ui.visuals().extreme_bg_color)
                         .inner_margin(8.0)
                         .show(ui, |ui| {
-                            for item in self.list.get_all() {
-                                ui.label(format!("• {item}"));
+                            for (index, item) in self.list.get_all().into_iter().enumerate() {
+                                ui.horizontal(|ui| {
+                                    ui.label(format!("• {item}"));
+                                    if ui.small_button("+1").clicked() {
+                                        let _ = self.list.update(index, |item| *item += 1);
+                                    }
+                                    if ui.small_button("-1").clicked() {
+                                        let _ = self.list.update(index, |item| *item -= 1);
+                                    }
+                                    if ui.small_button("⬆").clicked() && index > 0 {
+                                        let _ = self.list.move_item(index, index - 1);
+                                    }
+                                    if ui.small_button("⬇").clicked() {
+                                        // Out of bounds on the last item, which stays put
+                                        let _ = self.list.move_item(index, index + 1);
+                                    }
+                                });
                             }
                             ui.separator();
                             ui.strong(format!("Sum: {}", self.list_sum.get()));
@@ -164,28 +220,19 @@ impl eframe::App for AppState {
                 });
         });
 
-        egui::Window::new("🔍 Reactive Graph Debug").show(ui.ctx(), |ui| {
-            ui.label("⚙️ Registered Signals:");
-            for (name, signal) in self.registry.list_signals() {
-                let any = signal.as_any();
-                if let Some(val) = any.downcast_ref::<Dynamic<i32>>() {
-                    ui.label(format!("- {}: {}", name, val.get()));
-                } else if let Some(val) = any.downcast_ref::<Derived<i32>>() {
-                    ui.label(format!("- {} (derived): {}", name, val.get()));
-                } else if let Some(val) = any.downcast_ref::<ReactiveList<i32>>() {
-                    ui.label(format!("- {} (list): {:?}", name, val.get_all()));
-                } else if let Some(val) = any.downcast_ref::<Dynamic<String>>() {
-                    ui.label(format!("- {}: \"{}\"", name, val.get()));
-                } else {
-                    ui.label(format!("- {name} (?)"));
+        egui::Window::new("🔍 Reactive Graph Debug")
+            .default_size([520.0, 360.0])
+            .show(ui.ctx(), |ui| {
+                if ui.button("Reset layout").clicked() {
+                    self.graph_view.reset_layout();
                 }
-            }
-        });
+                self.graph_view.show(ui, &self.registry);
+            });
     }
 }
 
 fn main() -> eframe::Result<()> {
-    let (event_signal, _event_slot) = factory::create_signal_slot::<Event>();
+    let (event_signal, event_slot) = factory::create_signal_slot::<Event>();
 
     eframe::run_native(
         "egui_mobius Reactive Example",
@@ -193,7 +240,7 @@ fn main() -> eframe::Result<()> {
         Box::new(move |cc| {
             let _ctx = cc.egui_ctx.clone();
             let registry = SignalRegistry::new();
-            let app_state = AppState::new(registry, event_signal.clone());
+            let app_state = AppState::new(registry, event_signal.clone(), event_slot);
             Ok(Box::new(app_state))
         }),
     )
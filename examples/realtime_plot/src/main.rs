@@ -23,18 +23,20 @@ use eframe::{
     egui::{self, Vec2},
     epaint::ColorImage,
 };
+use egui_mobius::history::HistoryBuffer;
 use egui_mobius::types::Value;
-use egui_mobius::{factory, signals::Signal, slot::Slot};
+use egui_mobius::{MobiusError, TimerHandle, factory, signals::Signal, slot::Slot};
+use egui_mobius_reactive::{Derived, Dynamic, ReactiveMovingAverage};
 use egui_plot::{Legend, Line, Plot, PlotPoints};
-use std::thread;
 use std::time::Duration;
 
 // Define some global constants
 const MAX_HISTORY_LEN: usize = 300;
+const SMOOTHING_WINDOW: usize = 20;
+const Y_MARGIN: f64 = 5.0; // °C around the plotted temperature range
 
 // Thermal simulation constants
 const THERMAL_TIME_CONSTANT: f64 = 20.0; // seconds
-const MAX_HEATSINK_TEMP: f64 = 100.0; // °C
 const MIN_HEATSINK_TEMP: f64 = 25.0; // °C
 const POWER_DISSIPATION: f64 = 100.0; // Watts per MOSFET
 const THERMAL_RESISTANCE: f64 = 0.5; // °C/W
@@ -57,6 +59,17 @@ enum Event {
     },
 }
 
+//----------------------------------------------------------------------------
+// **Command Type**
+//----------------------------------------------------------------------------
+// Commands from the UI travel on their own signal/slot pair, so they are never
+// coalesced with the high-frequency temperature events.
+//----------------------------------------------------------------------------
+#[derive(Debug, Clone)]
+enum Command {
+    ExportCsv,
+}
+
 //----------------------------------------------------------------------------
 // **UiApp Struct**
 //----------------------------------------------------------------------------
@@ -68,16 +81,22 @@ struct UiApp {
     fabric_data: Fabric,
     ui_signal: Signal<Event>,
     ui_slot: Slot<Event>,
+    command_signal: Signal<Command>,
     circuit_texture: Option<egui::TextureHandle>,
 }
 struct Fabric {
     inlet_temp: Value<f64>,
     exhaust_temp: Value<f64>,
     ambient_temp: Value<f64>,
-    inlet_history: Value<Vec<f64>>,
-    exhaust_history: Value<Vec<f64>>,
-    ambient_history: Value<Vec<f64>>,
-    y_bounds: Value<(f64, f64)>,
+    inlet_history: Dynamic<HistoryBuffer<f64>>,
+    exhaust_history: Dynamic<HistoryBuffer<f64>>,
+    ambient_history: Dynamic<HistoryBuffer<f64>>,
+    inlet_average: Derived<f64>,
+    exhaust_average: Derived<f64>,
+    ambient_average: Derived<f64>,
+    coldest_sample: Dynamic<f64>,
+    hottest_sample: Dynamic<f64>,
+    y_bounds: (Derived<f64>, Derived<f64>),
 }
 //----------------------------------------------------------------------------
 // **UiApp Implementation*
@@ -87,25 +106,49 @@ struct Fabric {
 // The eframe::App trait is implemented for the UiApp struct.
 //----------------------------------------------------------------------------
 impl UiApp {
-    fn new(ui_signal: Signal<Event>, ui_slot: Slot<Event>) -> Self {
+    fn new(
+        ui_signal: Signal<Event>,
+        ui_slot: Slot<Event>,
+        command_signal: Signal<Command>,
+    ) -> Self {
         // Load the circuit image
         let circuit_texture = None; // Will be loaded on first frame
+        let inlet_history = Dynamic::new(initial_history());
+        let exhaust_history = Dynamic::new(initial_history());
+        let ambient_history = Dynamic::new(initial_history());
+        let coldest_sample = Dynamic::new(MIN_HEATSINK_TEMP);
+        let hottest_sample = Dynamic::new(MIN_HEATSINK_TEMP);
+        // The plot scales to the historical range of the data
+        let y_bounds = (coldest_sample.running_min(), hottest_sample.running_max());
         Self {
             fabric_data: Fabric {
                 inlet_temp: Value::new(MIN_HEATSINK_TEMP),
                 exhaust_temp: Value::new(MIN_HEATSINK_TEMP),
                 ambient_temp: Value::new(MIN_HEATSINK_TEMP),
-                inlet_history: Value::new(vec![MIN_HEATSINK_TEMP; MAX_HISTORY_LEN]),
-                exhaust_history: Value::new(vec![MIN_HEATSINK_TEMP; MAX_HISTORY_LEN]),
-                ambient_history: Value::new(vec![MIN_HEATSINK_TEMP; MAX_HISTORY_LEN]),
-                y_bounds: Value::new((0.0, MAX_HEATSINK_TEMP + 20.0)), // Add margin to max temp
+                inlet_average: inlet_history.moving_average(SMOOTHING_WINDOW),
+                exhaust_average: exhaust_history.moving_average(SMOOTHING_WINDOW),
+                ambient_average: ambient_history.moving_average(SMOOTHING_WINDOW),
+                inlet_history,
+                exhaust_history,
+                ambient_history,
+                coldest_sample,
+                hottest_sample,
+                y_bounds,
             },
             ui_signal,
             ui_slot,
+            command_signal,
             circuit_texture,
         }
     }
 }
+
+/// A full history at the minimum temperature, so the plot starts flat.
+fn initial_history() -> HistoryBuffer<f64> {
+    let mut history = HistoryBuffer::new(MAX_HISTORY_LEN);
+    history.extend(std::iter::repeat_n(MIN_HEATSINK_TEMP, MAX_HISTORY_LEN));
+    history
+}
 //----------------------------------------------------------------------------
 // **UiApp eframe::App **
 //----------------------------------------------------------------------------
@@ -122,10 +165,10 @@ impl eframe::App for UiApp {
         let mut inlet_temp = fabric_data.inlet_temp.lock().unwrap();
         let mut exhaust_temp = fabric_data.exhaust_temp.lock().unwrap();
         let mut ambient_temp = fabric_data.ambient_temp.lock().unwrap();
-        let inlet_history = fabric_data.inlet_history.lock().unwrap();
-        let exhaust_history = fabric_data.exhaust_history.lock().unwrap();
-        let ambient_history = fabric_data.ambient_history.lock().unwrap();
-        let y_bounds = fabric_data.y_bounds.lock().unwrap();
+        let inlet_history = fabric_data.inlet_history.lock();
+        let exhaust_history = fabric_data.exhaust_history.lock();
+        let ambient_history = fabric_data.ambient_history.lock();
+        let y_bounds = (fabric_data.y_bounds.0.get(), fabric_data.y_bounds.1.get());
 
         egui::CentralPanel::default().show(ui, |ui| {
             ui.heading("SiC MOSFET Half-Bridge Thermal Simulation");
@@ -223,11 +266,22 @@ impl eframe::App for UiApp {
             ui.separator();
 
             // Temperature plot with legend
-            ui.label("Temperature History");
+            ui.horizontal(|ui| {
+                ui.label("Temperature History");
+                if ui.button("💾 Export CSV").clicked() {
+                    let _ = self.command_signal.send(Command::ExportCsv);
+                }
+            });
+            ui.label(format!(
+                "Average of last {SMOOTHING_WINDOW} samples: inlet {:.1} °C, exhaust {:.1} °C, ambient {:.1} °C",
+                fabric_data.inlet_average.get(),
+                fabric_data.exhaust_average.get(),
+                fabric_data.ambient_average.get(),
+            ));
             Plot::new("temp_plot")
                 .view_aspect(2.0)
-                .include_y(y_bounds.0)
-                .include_y(y_bounds.1)
+                .include_y(y_bounds.0 - Y_MARGIN)
+                .include_y(y_bounds.1 + Y_MARGIN)
                 .legend(Legend::default())
                 .show(ui, |plot_ui| {
                     let inlet_points: PlotPoints = inlet_history
@@ -267,16 +321,11 @@ impl eframe::App for UiApp {
 // temperature data every second, and the consumer thread receives the data
 // and updates the UI.
 //-------------------------------------------------------------------------
-macro_rules! append_and_maintain_fifo {
-    ($history:expr, $new_value:expr, $max_len:expr) => {
-        $history.push($new_value);
-        if $history.len() > $max_len {
-            $history.remove(0);
-        }
-    };
-}
-// **Producer Thread: Simulates SiC MOSFET thermal behavior**
-fn producer_thread(signal: Signal<Event>, fabric_data: &Fabric) {
+// **Producer Thread: Simulates SiC MOSFET thermal behavior, once per timer tick**
+fn producer_thread(
+    signal: Signal<Event>,
+    fabric_data: &Fabric,
+) -> Result<TimerHandle, MobiusError> {
     let inlet = fabric_data.inlet_temp.clone();
     let exhaust = fabric_data.exhaust_temp.clone();
     let ambient = fabric_data.ambient_temp.clone();
@@ -284,49 +333,51 @@ fn producer_thread(signal: Signal<Event>, fabric_data: &Fabric) {
     let mut time = 0.0;
     let update_interval = 1.0; // seconds
 
-    thread::spawn(move || {
-        loop {
-            // Simulate thermal behavior
-            let ambient_val = *ambient.lock().unwrap();
-
-            // Calculate steady-state temperature based on power dissipation
-            let steady_state_temp = ambient_val + (POWER_DISSIPATION * 2.0 * THERMAL_RESISTANCE);
-
-            // Exponential approach to steady state
-            let inlet_val = ambient_val
-                + (steady_state_temp - ambient_val) * (1.0 - (-time / THERMAL_TIME_CONSTANT).exp());
-
-            // Exhaust temperature is slightly higher due to thermal gradient
-            let exhaust_val = inlet_val + (POWER_DISSIPATION * THERMAL_RESISTANCE * 0.2);
-
-            // Update shared values
-            *inlet.lock().unwrap() = inlet_val;
-            *exhaust.lock().unwrap() = exhaust_val;
-
-            if signal
-                .send(Event::DataUpdated {
-                    inlet: inlet_val,
-                    exhaust: exhaust_val,
-                    ambient: ambient_val,
-                })
-                .is_err()
-            {
-                eprintln!("Failed to send data update from producer.");
-            }
-
-            time += update_interval;
-            thread::sleep(Duration::from_secs(1));
+    let (_tick_signal, mut tick_slot, timer) =
+        factory::create_interval_signal(Duration::from_secs_f64(update_interval));
+    tick_slot.start(move |_| {
+        // Simulate thermal behavior
+        let ambient_val = *ambient.lock().unwrap();
+
+        // Calculate steady-state temperature based on power dissipation
+        let steady_state_temp = ambient_val + (POWER_DISSIPATION * 2.0 * THERMAL_RESISTANCE);
+
+        // Exponential approach to steady state
+        let inlet_val = ambient_val
+            + (steady_state_temp - ambient_val) * (1.0 - (-time / THERMAL_TIME_CONSTANT).exp());
+
+        // Exhaust temperature is slightly higher due to thermal gradient
+        let exhaust_val = inlet_val + (POWER_DISSIPATION * THERMAL_RESISTANCE * 0.2);
+
+        // Update shared values
+        *inlet.lock().unwrap() = inlet_val;
+        *exhaust.lock().unwrap() = exhaust_val;
+
+        if signal
+            .send(Event::DataUpdated {
+                inlet: inlet_val,
+                exhaust: exhaust_val,
+                ambient: ambient_val,
+            })
+            .is_err()
+        {
+            eprintln!("Failed to send data update from producer.");
         }
-    });
+
+        time += update_interval;
+    })?;
+    Ok(timer)
 }
 // **Consumer Thread: Receives updates and maintains FIFO buffer**
-fn consumer_thread(mut slot: Slot<Event>, fabric_data: &Fabric) {
+fn consumer_thread(mut slot: Slot<Event>, fabric_data: &Fabric) -> Result<(), MobiusError> {
     let inlet = fabric_data.inlet_temp.clone();
     let exhaust = fabric_data.exhaust_temp.clone();
     let ambient = fabric_data.ambient_temp.clone();
     let inlet_history = fabric_data.inlet_history.clone();
     let exhaust_history = fabric_data.exhaust_history.clone();
     let ambient_history = fabric_data.ambient_history.clone();
+    let coldest_sample = fabric_data.coldest_sample.clone();
+    let hottest_sample = fabric_data.hottest_sample.clone();
 
     slot.start(move |event| {
         let Event::DataUpdated {
@@ -340,15 +391,44 @@ fn consumer_thread(mut slot: Slot<Event>, fabric_data: &Fabric) {
         *exhaust.lock().unwrap() = new_exhaust;
         *ambient.lock().unwrap() = new_ambient;
 
-        let mut inlet_history = inlet_history.lock().unwrap();
-        let mut exhaust_history = exhaust_history.lock().unwrap();
-        let mut ambient_history = ambient_history.lock().unwrap();
+        // The history buffers drop their oldest sample once full
+        push_sample(&inlet_history, new_inlet);
+        push_sample(&exhaust_history, new_exhaust);
+        push_sample(&ambient_history, new_ambient);
 
-        // Append new values and maintain FIFO buffer using macro
-        append_and_maintain_fifo!(inlet_history, new_inlet, 300);
-        append_and_maintain_fifo!(exhaust_history, new_exhaust, 300);
-        append_and_maintain_fifo!(ambient_history, new_ambient, 300);
-    });
+        // Feed the running extremes behind the plot's y-axis bounds
+        coldest_sample.set(new_inlet.min(new_exhaust).min(new_ambient));
+        hottest_sample.set(new_inlet.max(new_exhaust).max(new_ambient));
+    })?;
+    Ok(())
+}
+
+/// Pushes through `set`, so the moving averages recompute.
+fn push_sample(history: &Dynamic<HistoryBuffer<f64>>, sample: f64) {
+    let mut samples = history.get();
+    samples.push(sample);
+    history.set(samples);
+}
+
+// **Export Thread: Writes each temperature history to its own CSV file**
+fn export_thread(mut slot: Slot<Command>, fabric_data: &Fabric) -> Result<(), MobiusError> {
+    let histories = [
+        ("inlet", fabric_data.inlet_history.clone()),
+        ("exhaust", fabric_data.exhaust_history.clone()),
+        ("ambient", fabric_data.ambient_history.clone()),
+    ];
+
+    slot.start(move |command| {
+        let Command::ExportCsv = command;
+        for (name, history) in &histories {
+            let path = format!("{name}_history.csv");
+            match history.lock().export_csv(&path) {
+                Ok(()) => println!("Exported {path}"),
+                Err(e) => eprintln!("Failed to export {path}: {e}"),
+            }
+        }
+    })?;
+    Ok(())
 }
 
 //-------------------------------------------------------------------------
@@ -365,12 +445,17 @@ fn consumer_thread(mut slot: Slot<Event>, fabric_data: &Fabric) {
 // The compactness of the code is due to the use of egui_mobius to manage
 // the shared data and the signal/slot pair.
 //-------------------------------------------------------------------------
-fn main() {
+fn main() -> Result<(), MobiusError> {
+    // Every event is a sample for the history, so they queue in order instead of
+    // replacing each other
     let (ui_signal, ui_slot) = factory::create_signal_slot();
-    let app = UiApp::new(ui_signal.clone(), ui_slot.clone());
+    let (command_signal, command_slot) = factory::create_signal_slot();
+    let app = UiApp::new(ui_signal.clone(), ui_slot.clone(), command_signal);
 
-    producer_thread(ui_signal.clone(), &app.fabric_data);
-    consumer_thread(ui_slot, &app.fabric_data);
+    // The producer runs until the timer handle is dropped at the end of main
+    let _producer_timer = producer_thread(ui_signal.clone(), &app.fabric_data)?;
+    consumer_thread(ui_slot.named("plot-consumer"), &app.fabric_data)?;
+    export_thread(command_slot.named("csv-export"), &app.fabric_data)?;
 
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
@@ -387,4 +472,5 @@ fn main() {
     ) {
         eprintln!("Failed to run eframe UiApplication: {e:?}");
     }
+    Ok(())
 }
@@ -22,7 +22,8 @@ use egui_mobius::factory;
 use egui_mobius::signals::Signal;
 use egui_mobius::slot::Slot;
 use egui_mobius::types::{Edge, Value};
-use log::{info, warn};
+use egui_mobius_widgets::CommandBar;
+use log::{debug, info, warn};
 use std::collections::VecDeque;
 use std::fmt::Debug;
 //----------------------------------------------------------------------------
@@ -63,6 +64,7 @@ struct UiApp {
     update_needed: Value<bool>,
     slider_value: Value<Edge<usize>>,
     combo_value: Value<Edge<String>>,
+    commands: CommandBar<EventType>,
 }
 //----------------------------------------------------------------------------
 // **UiApp Implementation*
@@ -77,6 +79,16 @@ impl UiApp {
         slot_on_uiapp: Slot<ProcessedType>,
         update_needed: Value<bool>,
     ) -> Self {
+        let command = |name: &'static str| (name, EventType::ApplicationCommand(name.to_string()));
+        let commands = CommandBar::new(
+            signal_to_backend.clone(),
+            vec![
+                command("Clear Logger"),
+                command("OS Info"),
+                command("Version Info"),
+                command("Shutdown"),
+            ],
+        );
         Self {
             logger_text: Value::new(
                 "**** Welcome to egui_mobius ui_refresh_events example ....\n\n".to_string(),
@@ -86,6 +98,7 @@ impl UiApp {
             update_needed,
             slider_value: Value::new(Edge::new(0)),
             combo_value: Value::new(Edge::new("Egui_with_egui_mobius".to_string())),
+            commands,
         }
     }
 }
@@ -103,7 +116,8 @@ impl eframe::App for UiApp {
     fn ui(&mut self, ui: &mut egui::Ui, _frame: &mut eframe::Frame) {
         let mut should_repaint = false;
 
-        self.slot_on_uiapp.start({
+        // Runs every frame, but the handler is only installed on the first one
+        self.slot_on_uiapp.ensure_started({
             let logger_text_clone = self.logger_text.clone();
             let update_needed_clone = self.update_needed.clone();
             move |event: ProcessedType| {
@@ -226,28 +240,7 @@ impl eframe::App for UiApp {
             ui.label("Application Commands");
             ui.add_space(5.0);
 
-            ui.horizontal(|ui| {
-                if ui.button("Clear Logger").clicked() {
-                    self.signal_to_backend
-                        .send(EventType::ApplicationCommand("Clear Logger".to_string()))
-                        .unwrap();
-                }
-                if ui.button("OS Info").clicked() {
-                    self.signal_to_backend
-                        .send(EventType::ApplicationCommand("OS Info".to_string()))
-                        .unwrap();
-                }
-                if ui.button("Version Info").clicked() {
-                    self.signal_to_backend
-                        .send(EventType::ApplicationCommand("Version Info".to_string()))
-                        .unwrap();
-                }
-                if ui.button("Shutdown").clicked() {
-                    self.signal_to_backend
-                        .send(EventType::ApplicationCommand("Shutdown".to_string()))
-                        .unwrap();
-                }
-            });
+            self.commands.show(ui);
 
             ui.add_space(10.0);
             ui.separator();
@@ -304,6 +297,8 @@ fn backend_consumer_thread(
     mut slot: Slot<EventType>,            // incoming from UiApp
     slot_on_uiapp: Signal<ProcessedType>, // outgoing to UiApp
 ) {
+    // Trace every incoming event without touching the processing closure
+    slot.inspect(|event| debug!("Backend received {event:?}"));
     slot.start({
         let messages_clone = Value::clone(&messages);
         let update_needed_clone = Value::clone(&update_needed);
@@ -429,7 +424,8 @@ fn backend_consumer_thread(
 
             *update_needed_clone.lock().unwrap() = true;
         }
-    });
+    })
+    .unwrap();
 }
 
 //-------------------------------------------------------------------------